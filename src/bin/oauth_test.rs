@@ -10,6 +10,7 @@
 //! - DISCORD_BOT_TOKEN: Your bot token (for adding users to guild)
 //! - DISCORD_GUILD_ID: The guild to add users to
 //! - WEB_BASE_URL: Base URL for OAuth redirect (default: http://localhost:3000)
+//! - DISCORD_API_BASE_URL: Discord REST API base URL (default: https://discord.com/api)
 
 use axum::{
     extract::{Path, Query, State},
@@ -22,6 +23,11 @@ use std::sync::Arc;
 use tokio::net::TcpListener;
 use tracing::{error, info};
 
+#[path = "../web/discord_api.rs"]
+mod discord_api;
+
+use discord_api::DiscordRestClient;
+
 /// Application state shared across handlers
 #[derive(Clone)]
 struct AppState {
@@ -30,7 +36,7 @@ struct AppState {
     bot_token: String,
     guild_id: String,
     base_url: String,
-    http_client: reqwest::Client,
+    discord: DiscordRestClient,
 }
 
 /// Query parameters from Discord OAuth callback
@@ -40,22 +46,6 @@ struct CallbackParams {
     state: String, // This contains the UUID
 }
 
-/// Discord OAuth token response
-#[derive(Deserialize)]
-struct TokenResponse {
-    access_token: String,
-    token_type: String,
-}
-
-/// Discord user info from /users/@me
-#[derive(Deserialize, Debug)]
-struct DiscordUser {
-    id: String,
-    username: String,
-    global_name: Option<String>,
-    discriminator: String,
-}
-
 /// Health check endpoint
 async fn health() -> &'static str {
     "OAuth Test Server Running"
@@ -68,17 +58,9 @@ async fn verify_page(State(state): State<Arc<AppState>>, Path(uuid): Path<String
     // Build redirect URI - must match EXACTLY what's registered in Discord Developer Portal
     let redirect_uri = format!("{}/callback", state.base_url);
 
-    let oauth_url = format!(
-        "https://discord.com/oauth2/authorize\
-        ?client_id={}\
-        &redirect_uri={}\
-        &response_type=code\
-        &scope=identify%20guilds.join\
-        &state={}",
-        state.client_id,
-        urlencoding::encode(&redirect_uri),
-        urlencoding::encode(&uuid)
-    );
+    let oauth_url = state
+        .discord
+        .authorize_url(&state.client_id, &redirect_uri, "identify guilds.join", &uuid);
 
     info!("Generated OAuth URL: {}", oauth_url);
     info!("Redirect URI (before encoding): {}", redirect_uri);
@@ -165,90 +147,41 @@ async fn oauth_callback(
     info!("OAuth callback received for UUID: {}", params.state);
 
     // Exchange code for access token
-    let token_response = state
-        .http_client
-        .post("https://discord.com/api/oauth2/token")
-        .form(&[
-            ("client_id", state.client_id.as_str()),
-            ("client_secret", state.client_secret.as_str()),
-            ("grant_type", "authorization_code"),
-            ("code", params.code.as_str()),
-            ("redirect_uri", &format!("{}/callback", state.base_url)),
-        ])
-        .send()
+    let token = state
+        .discord
+        .exchange_code(
+            &state.client_id,
+            &state.client_secret,
+            &params.code,
+            &format!("{}/callback", state.base_url),
+        )
         .await
         .map_err(|e| {
-            error!("Failed to exchange code: {}", e);
+            error!("Token exchange failed: {}", e);
             Html(error_page("Failed to exchange authorization code"))
         })?;
 
-    if !token_response.status().is_success() {
-        let error_text = token_response.text().await.unwrap_or_default();
-        error!("Token exchange failed: {}", error_text);
-        return Err(Html(error_page("Token exchange failed")));
-    }
-
-    let token: TokenResponse = token_response.json().await.map_err(|e| {
-        error!("Failed to parse token response: {}", e);
-        Html(error_page("Failed to parse token response"))
-    })?;
-
     info!("Got access token, fetching user info...");
 
     // Get user info
-    let user_response = state
-        .http_client
-        .get("https://discord.com/api/users/@me")
-        .header(
-            "Authorization",
-            format!("{} {}", token.token_type, token.access_token),
-        )
-        .send()
-        .await
-        .map_err(|e| {
-            error!("Failed to get user info: {}", e);
-            Html(error_page("Failed to get user info"))
-        })?;
-
-    if !user_response.status().is_success() {
-        let error_text = user_response.text().await.unwrap_or_default();
-        error!("User info request failed: {}", error_text);
-        return Err(Html(error_page("Failed to get user info")));
-    }
-
-    let user: DiscordUser = user_response.json().await.map_err(|e| {
-        error!("Failed to parse user info: {}", e);
-        Html(error_page("Failed to parse user info"))
+    let user = state.discord.get_current_user(&token).await.map_err(|e| {
+        error!("Failed to get user info: {}", e);
+        Html(error_page("Failed to get user info"))
     })?;
 
     info!("User authenticated: {} ({})", user.username, user.id);
 
     // Try to add user to guild using bot token
     let add_result = state
-        .http_client
-        .put(&format!(
-            "https://discord.com/api/guilds/{}/members/{}",
-            state.guild_id, user.id
-        ))
-        .header("Authorization", format!("Bot {}", state.bot_token))
-        .json(&serde_json::json!({
-            "access_token": token.access_token
-        }))
-        .send()
+        .discord
+        .add_guild_member(&state.bot_token, &state.guild_id, &user.id, &token.access_token, None, &[])
         .await;
 
     let guild_status = match add_result {
-        Ok(response) => {
-            if response.status().is_success() || response.status().as_u16() == 204 {
-                "Added to server!"
-            } else if response.status().as_u16() == 201 {
-                "Added to server!"
-            } else {
-                let status = response.status();
-                let text = response.text().await.unwrap_or_default();
-                info!("Guild add response: {} - {}", status, text);
-                "Already in server or couldn't add"
-            }
+        Ok(_) => "Added to server!",
+        Err(discord_api::DiscordApiError::Status { status, body }) => {
+            info!("Guild add response: {} - {}", status, body);
+            "Already in server or couldn't add"
         }
         Err(e) => {
             error!("Failed to add to guild: {}", e);
@@ -432,13 +365,16 @@ async fn main() -> anyhow::Result<()> {
     info!("Guild ID: {}", guild_id);
     info!("Base URL: {}", base_url);
 
+    let discord = DiscordRestClient::from_env();
+    info!("Discord API base URL: {}", discord.api_base_url());
+
     let state = Arc::new(AppState {
         client_id,
         client_secret,
         bot_token,
         guild_id,
         base_url: base_url.clone(),
-        http_client: reqwest::Client::new(),
+        discord,
     });
 
     let app = Router::new()
@@ -0,0 +1,157 @@
+use poise::serenity_prelude as serenity;
+use tracing::{error, info};
+
+use super::config::handle_commit_or_cancel;
+use crate::{Context, Error};
+
+/// Adopt an existing category and its channels into a season's config
+///
+/// Reads the category from Discord and stages a season.json entry describing
+/// it for review. Once committed, the category and its channels are tracked
+/// so syncs reuse them.
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn adopt_category(
+    ctx: Context<'_>,
+    #[description = "Name or ID of the existing category"] category: String,
+    #[description = "Season ID to add the category to (e.g., 2024E)"] season_id: String,
+) -> Result<(), Error> {
+    let guild_id = ctx
+        .guild_id()
+        .ok_or("This command must be used in a guild")?;
+    info!(
+        "adopt_category called by {} for '{}' into season {}",
+        ctx.author().name,
+        category,
+        season_id
+    );
+
+    if season_id.is_empty() || season_id.len() > 20 || season_id.contains(' ') {
+        ctx.send(
+            poise::CreateReply::default()
+                .content("Invalid season ID. Use a short identifier like `2024E` without spaces.")
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+    ctx.defer_ephemeral().await?;
+
+    let adopted = {
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager
+            .adopt_category(ctx.http(), guild_id, &category, &season_id)
+            .await
+    };
+    let adopted = match adopted {
+        Ok(adopted) => adopted,
+        Err(e) => {
+            error!("Failed to adopt category '{}': {}", category, e);
+            ctx.say(format!("Failed to adopt category: {}", e)).await?;
+            return Ok(());
+        }
+    };
+
+    let entry = serde_json::to_string_pretty(&adopted.definition)?;
+    let (summary, diff) = {
+        let mut config_manager = ctx.data().config_manager.write().await;
+        let summary = config_manager.stage_adopted_category(
+            &season_id,
+            adopted.definition.clone(),
+            Some(ctx.author().name.clone()),
+        );
+        let user_db = ctx.data().user_database.read().await;
+        (summary, config_manager.get_staged_diff(&user_db))
+    };
+
+    let mut description = format!(
+        "Commit to track category **{}** and {} channels.\n{}\n\n{}",
+        adopted.definition.name,
+        adopted.channels,
+        summary,
+        diff.format_truncated(1500)
+    );
+    if !adopted.warnings.is_empty() {
+        let mut warnings = adopted.warnings.join("\n- ");
+        if warnings.len() > 1500 {
+            let mut end = 1500;
+            while !warnings.is_char_boundary(end) {
+                end -= 1;
+            }
+            warnings.truncate(end);
+            warnings.push('…');
+        }
+        description.push_str(&format!("\n**Warnings:**\n- {}\n", warnings));
+    }
+    description.push_str("\nClick **Commit** to save season.json or **Cancel** to discard.");
+
+    let embed = serenity::CreateEmbed::new()
+        .title(format!(
+            "Adopted '{}' into Season {}",
+            adopted.definition.name, season_id
+        ))
+        .description(description)
+        .color(0x2ecc71);
+
+    let buttons = vec![
+        serenity::CreateButton::new("commit_config")
+            .label("Commit Changes")
+            .style(serenity::ButtonStyle::Success),
+        serenity::CreateButton::new("cancel_config")
+            .label("Cancel")
+            .style(serenity::ButtonStyle::Danger),
+    ];
+
+    let reply = ctx
+        .send(
+            poise::CreateReply::default()
+                .embed(embed)
+                .attachment(serenity::CreateAttachment::bytes(
+                    entry.into_bytes(),
+                    format!("{}.json", adopted.definition.name),
+                ))
+                .components(vec![serenity::CreateActionRow::Buttons(buttons)])
+                .ephemeral(true),
+        )
+        .await?;
+
+    let message = reply.message().await?;
+    if let Some(interaction) = message
+        .await_component_interaction(ctx.serenity_context().shard.clone())
+        .timeout(std::time::Duration::from_secs(120))
+        .await
+    {
+        if handle_commit_or_cancel(&ctx, &interaction).await {
+            let tracked = {
+                let channel_manager = ctx.data().channel_manager.read().await;
+                channel_manager
+                    .track_adopted_category(guild_id, &season_id, &adopted)
+                    .await
+            };
+            if let Err(e) = tracked {
+                error!(
+                    "Failed to track adopted category '{}': {}",
+                    adopted.definition.name, e
+                );
+                ctx.send(
+                    poise::CreateReply::default()
+                        .content(format!(
+                            "season.json was saved, but tracking the category failed: {}",
+                            e
+                        ))
+                        .ephemeral(true),
+                )
+                .await?;
+            }
+        }
+    } else {
+        let mut config_manager = ctx.data().config_manager.write().await;
+        config_manager.clear_staged();
+        info!("Adopt staging timed out for {}", ctx.author().name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use tracing::{info, warn};
+
+use crate::{Context, Error};
+
+/// Archive a finished season: make its category read-only and optionally strip the member role
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn archive_season(
+    ctx: Context<'_>,
+    #[description = "Season ID to archive (e.g., 2025E)"] season_id: String,
+    #[description = "Move the category below all other categories"] move_to_bottom: Option<bool>,
+    #[description = "Remove the season member role from everyone"] strip_member_role: Option<bool>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
+    ctx.defer().await?;
+
+    let (category_names, member_role, was_active) = {
+        let config_manager = ctx.data().config_manager.read().await;
+        match config_manager.get_season(&season_id) {
+            Some(season) => (
+                config_manager
+                    .get_season_category_names(&season_id)
+                    .unwrap_or_default(),
+                season.member_role(),
+                season.is_active(),
+            ),
+            None => {
+                ctx.say(format!("Season '{}' not found.", season_id)).await?;
+                return Ok(());
+            }
+        }
+    };
+
+    let http = ctx.serenity_context().http.as_ref();
+
+    let mut summary = {
+        let channel_manager = ctx.data().channel_manager.read().await;
+        match channel_manager
+            .archive_season_categories(
+                http,
+                guild_id,
+                &season_id,
+                &category_names,
+                &member_role,
+                move_to_bottom.unwrap_or(false),
+            )
+            .await
+        {
+            Ok(s) => s,
+            Err(e) => {
+                ctx.say(format!("Failed to archive season '{}': {}", season_id, e))
+                    .await?;
+                return Ok(());
+            }
+        }
+    };
+
+    if strip_member_role.unwrap_or(false) {
+        let role_manager = ctx.data().role_manager.read().await;
+        match role_manager
+            .remove_role_from_all_members(http, guild_id, &member_role)
+            .await
+        {
+            Ok((removed, failed)) => {
+                summary.members_stripped = Some(removed);
+                if !failed.is_empty() {
+                    summary.warnings.push(format!(
+                        "Could not remove {} from: {}",
+                        member_role,
+                        failed.join(", ")
+                    ));
+                }
+            }
+            Err(e) => summary
+                .warnings
+                .push(format!("Failed to remove {}: {}", member_role, e)),
+        }
+    }
+
+    // An archived season no longer accepts verification
+    if was_active {
+        let mut config_manager = ctx.data().config_manager.write().await;
+        match config_manager
+            .set_season_active(&season_id, false, &ctx.author().name)
+            .await
+        {
+            Ok(()) => summary.season_deactivated = true,
+            Err(e) => {
+                warn!("Failed to deactivate season '{}': {}", season_id, e);
+                summary
+                    .warnings
+                    .push(format!("Failed to set season inactive: {}", e));
+            }
+        }
+    }
+
+    info!("{} archived season '{}'", ctx.author().name, season_id);
+
+    ctx.say(format!(
+        "**Season '{}' archived:**\n{}",
+        season_id,
+        summary.format()
+    ))
+    .await?;
+
+    Ok(())
+}
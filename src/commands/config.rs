@@ -372,7 +372,7 @@ pub async fn set_config_global(
     Ok(())
 }
 
-/// Upload season configuration (users.json for a season)
+/// Upload season configuration (users.json or a CSV export for a season)
 #[poise::command(
     slash_command,
     guild_only,
@@ -382,7 +382,7 @@ pub async fn set_config_season(
     ctx: Context<'_>,
     #[description = "Season ID (e.g., 2025E, 2025F)"]
     season_id: String,
-    #[description = "The users.json file for this season"]
+    #[description = "The users.json file or a CSV export for this season"]
     file: serenity::Attachment,
 ) -> Result<(), Error> {
     info!("set_config_season called by {} for season {} with file {}",
@@ -397,10 +397,11 @@ pub async fn set_config_season(
         return Ok(());
     }
 
-    // Validate file is JSON
-    if !file.filename.ends_with(".json") {
+    // Validate file is JSON or CSV
+    let is_csv = file.filename.to_lowercase().ends_with(".csv");
+    if !is_csv && !file.filename.ends_with(".json") {
         ctx.send(poise::CreateReply::default()
-            .content("Please upload a `.json` file.")
+            .content("Please upload a `.json` or `.csv` file.")
             .ephemeral(true))
             .await?;
         return Ok(());
@@ -420,9 +421,8 @@ pub async fn set_config_season(
     };
 
     // Validate JSON
-    let json: serde_json::Value = match serde_json::from_slice(&content) {
-        Ok(j) => j,
-        Err(e) => {
+    if !is_csv {
+        if let Err(e) = serde_json::from_slice::<serde_json::Value>(&content) {
             error!("Invalid JSON in uploaded file: {}", e);
             ctx.send(poise::CreateReply::default()
                 .content(format!("Invalid JSON file: {}", e))
@@ -430,9 +430,9 @@ pub async fn set_config_season(
                 .await?;
             return Ok(());
         }
-    };
+    }
 
-    // Stage the config using the method that supports both array and object formats
+    // Stage the config, converting CSV rows to users with the season's column mapping
     let staging_result = {
         let mut config_manager = ctx.data().config_manager.write().await;
         config_manager.stage_season_from_bytes_with_id(
@@ -446,28 +446,34 @@ pub async fn set_config_season(
     // Check if staging failed
     if let Err(e) = staging_result {
         error!("Failed to stage season config: {}", e);
+        let content = if is_csv {
+            let columns = ctx.data().config_manager.read().await.csv_columns_for(&season_id);
+            format!(
+                "Failed to import {}: {}\n\nExpected columns: `{}`, `{}` and optionally `{}` (set `csv_columns` in season.json to change them).",
+                file.filename, e, columns.name, columns.id, columns.email
+            )
+        } else {
+            format!("Failed to parse users.json: {}\n\nExpected format:\n```json\n[\n  {{ \"Name\": \"...\", \"DiscordId\": \"uuid-here\" }},\n  ...\n]\n```", e)
+        };
         ctx.send(poise::CreateReply::default()
-            .content(format!("Failed to parse users.json: {}\n\nExpected format:\n```json\n[\n  {{ \"Name\": \"...\", \"DiscordId\": \"uuid-here\" }},\n  ...\n]\n```", e))
+            .content(content)
             .ephemeral(true))
             .await?;
         return Ok(());
     }
 
-    // Show preview with commit/cancel buttons
-    let preview = serde_json::to_string_pretty(&json)
-        .unwrap_or_else(|_| "Failed to format JSON".to_string());
-    let preview_truncated = if preview.len() > 1000 {
-        format!("{}...\n(truncated)", &preview[..1000])
-    } else {
-        preview
+    // Show what would change with commit/cancel buttons
+    let diff = {
+        let user_db = ctx.data().user_database.read().await;
+        ctx.data().config_manager.read().await.get_staged_diff(&user_db)
     };
 
     let embed = serenity::CreateEmbed::new()
         .title(format!("Staged users.json for Season {}", season_id))
         .description(format!(
-            "**File:** `{}`\n\n**Preview:**\n```json\n{}\n```\n\nClick **Commit** to apply or **Cancel** to discard.",
+            "**File:** `{}`\n\n{}\nClick **Commit** to apply or **Cancel** to discard.",
             file.filename,
-            preview_truncated
+            diff.format_truncated(3500)
         ))
         .color(0x2ecc71);
 
@@ -506,11 +512,14 @@ pub async fn set_config_season(
 }
 
 /// Helper to handle commit/cancel button clicks
-async fn handle_commit_or_cancel(
+///
+/// Returns whether the staged config was committed.
+pub(super) async fn handle_commit_or_cancel(
     ctx: &Context<'_>,
     interaction: &serenity::ComponentInteraction,
-) {
+) -> bool {
     let custom_id = &interaction.data.custom_id;
+    let mut committed = false;
 
     if custom_id == "commit_config" {
         let mut config_manager = ctx.data().config_manager.write().await;
@@ -578,6 +587,7 @@ async fn handle_commit_or_cancel(
                     )
                 ).await;
                 info!("Configuration committed by {}", ctx.author().name);
+                committed = true;
             }
             Err(e) => {
                 let embed = serenity::CreateEmbed::new()
@@ -615,4 +625,6 @@ async fn handle_commit_or_cancel(
         ).await;
         info!("User {} cancelled config update", ctx.author().name);
     }
+
+    committed
 }
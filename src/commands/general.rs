@@ -25,6 +25,12 @@ pub async fn help(ctx: Context<'_>) -> Result<(), Error> {
         .field("/preview-config", "Preview staged configuration changes (Admin)", false)
         .field("/commit-config", "Apply staged configuration (Admin)", false)
         .field("/get-config", "Download a configuration file (Admin)", false)
+        .field("/revoke", "Revoke a member's verification (Admin)", false)
+        .field("/unrevoke", "Reinstate a revoked member (Admin)", false)
+        .field("/archive_season", "Make a finished season's category read-only (Admin)", false)
+        .field("/prune", "Archive or delete bot-created entities no longer in the config (Admin)", false)
+        .field("/adopt_category", "Adopt an existing category and its channels into a season (Admin)", false)
+        .field("/reconcile_members", "Sync every member's season and special roles with the user database (Admin)", false)
         .field("/restart", "Restart the bot (Owner only)", false)
         .color(0x3498db);
 
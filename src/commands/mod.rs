@@ -1,9 +1,22 @@
+pub mod adopt;
+pub mod archive_season;
 pub mod config;
 pub mod general;
+mod plan;
+pub mod prune;
+pub mod reconcile_members;
+pub mod revoke;
 pub mod update_category;
 pub mod update_roles;
+pub mod verify;
 
+pub use adopt::adopt_category;
+pub use archive_season::archive_season;
 pub use config::{get_config, set_config_global, set_config_season};
 pub use general::{help, ping, restart};
+pub use prune::prune;
+pub use reconcile_members::reconcile_members;
+pub use revoke::{revoke, unrevoke};
 pub use update_category::update_category;
 pub use update_roles::update_roles;
+pub use verify::verify;
@@ -0,0 +1,93 @@
+use poise::serenity_prelude as serenity;
+use std::time::Duration;
+use tracing::info;
+
+use crate::managers::SyncPlan;
+use crate::{Context, Error};
+
+/// Longest plan shown in a reply (Discord caps messages at 2000)
+const MAX_PLAN_LEN: usize = 1800;
+
+/// Show a sync plan and wait for the author to apply or cancel it
+///
+/// Returns true once "Apply this plan" is clicked. Empty plans are reported
+/// without buttons and return false, as do cancelled and expired ones.
+pub async fn confirm_plan(ctx: Context<'_>, title: &str, plan: &SyncPlan) -> Result<bool, Error> {
+    let content = format!("**{}**\n{}", title, plan.format_truncated(MAX_PLAN_LEN));
+    if plan.is_empty() {
+        ctx.say(content).await?;
+        return Ok(false);
+    }
+
+    let buttons = vec![
+        serenity::CreateButton::new("apply_plan")
+            .label("Apply this plan")
+            .style(serenity::ButtonStyle::Success),
+        serenity::CreateButton::new("cancel_plan")
+            .label("Cancel")
+            .style(serenity::ButtonStyle::Danger),
+    ];
+    let reply = ctx
+        .send(
+            poise::CreateReply::default()
+                .content(&content)
+                .components(vec![serenity::CreateActionRow::Buttons(buttons)]),
+        )
+        .await?;
+    let message = reply.message().await?;
+
+    let Some(interaction) = message
+        .await_component_interaction(ctx.serenity_context().shard.clone())
+        .author_id(ctx.author().id)
+        .timeout(Duration::from_secs(120))
+        .await
+    else {
+        reply
+            .edit(
+                ctx,
+                poise::CreateReply::default()
+                    .content(format!("{}\n\nPlan expired; nothing was applied.", content))
+                    .components(vec![]),
+            )
+            .await?;
+        return Ok(false);
+    };
+
+    let apply = interaction.data.custom_id == "apply_plan";
+    let status = if apply { "Applying…" } else { "Plan cancelled; nothing was applied." };
+    interaction
+        .create_response(
+            ctx.http(),
+            serenity::CreateInteractionResponse::UpdateMessage(
+                serenity::CreateInteractionResponseMessage::new()
+                    .content(format!("{}\n\n{}", content, status))
+                    .components(vec![]),
+            ),
+        )
+        .await?;
+
+    info!(
+        "{} {} plan '{}' ({})",
+        ctx.author().name,
+        if apply { "applied" } else { "cancelled" },
+        title,
+        plan.fingerprint()
+    );
+    Ok(apply)
+}
+
+/// Tell the author a plan went stale between review and apply
+///
+/// Returns true if `current` still matches the reviewed `plan`.
+pub async fn ensure_plan_current(
+    ctx: Context<'_>,
+    plan: &SyncPlan,
+    current: &SyncPlan,
+) -> Result<bool, Error> {
+    if plan.fingerprint() == current.fingerprint() {
+        return Ok(true);
+    }
+    ctx.say("Discord or the config changed while the plan was open, so nothing was applied. Run the command again to review the new plan.")
+        .await?;
+    Ok(false)
+}
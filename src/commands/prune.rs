@@ -0,0 +1,66 @@
+use tracing::info;
+
+use super::plan::{confirm_plan, ensure_plan_current};
+use crate::managers::GuildSnapshot;
+use crate::{Context, Error};
+
+/// Archive or delete bot-created channels, categories and roles that are no longer in the config
+///
+/// Shows the planned changes first and only applies them once confirmed.
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn prune(
+    ctx: Context<'_>,
+    #[description = "Delete instead of hiding channels and categories (needed to prune roles)"]
+    delete: Option<bool>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
+    let delete = delete.unwrap_or(false);
+    ctx.defer().await?;
+
+    let http = ctx.serenity_context().http.as_ref();
+
+    // Plan against the current guild and let the author review it
+    let plan = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager.plan_prune(&snapshot, delete).await
+    };
+    let title = if delete { "Prune plan (delete)" } else { "Prune plan (archive)" };
+    if !confirm_plan(ctx, title, &plan).await? {
+        return Ok(());
+    }
+    let current = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager.plan_prune(&snapshot, delete).await
+    };
+    if !ensure_plan_current(ctx, &plan, &current).await? {
+        return Ok(());
+    }
+
+    let summary = {
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager.apply_prune(http, guild_id, delete).await
+    };
+    match summary {
+        Ok(summary) => {
+            info!(
+                "Prune by {}: {} archived, {} deleted",
+                ctx.author().name,
+                summary.archived.len(),
+                summary.deleted.len()
+            );
+            ctx.say(format!("**Prune complete**\n\n{}", summary.format()))
+                .await?;
+        }
+        Err(e) => {
+            ctx.say(format!("Failed to prune: {}", e)).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use poise::serenity_prelude as serenity;
+use tracing::info;
+
+use super::plan::{confirm_plan, ensure_plan_current};
+use crate::managers::member_reconciler::MemberRoleReport;
+use crate::managers::reconcile_members as reconcile;
+use crate::{Context, Error};
+
+/// Bring every member's season and special roles in line with the user database
+///
+/// Shows the planned changes first and only applies them once confirmed. The
+/// per-member report is attached as CSV.
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn reconcile_members(
+    ctx: Context<'_>,
+    #[description = "Only send the report without changing any roles"] report_only: Option<bool>,
+) -> Result<(), Error> {
+    let guild_id = ctx
+        .guild_id()
+        .ok_or("This command must be used in a guild")?;
+    ctx.defer().await?;
+
+    let http = ctx.serenity_context().http.as_ref();
+    let data = ctx.data();
+    let run = |apply| {
+        reconcile(
+            http,
+            guild_id,
+            &data.config_manager,
+            &data.channel_manager,
+            &data.user_database,
+            &data.role_manager,
+            apply,
+        )
+    };
+
+    let report = run(false).await?;
+    if report_only.unwrap_or(false) {
+        return send_report(ctx, &report).await;
+    }
+    let plan = report.plan();
+    if !confirm_plan(ctx, "Member role plan", &plan).await? {
+        return Ok(());
+    }
+    let current = run(false).await?.plan();
+    if !ensure_plan_current(ctx, &plan, &current).await? {
+        return Ok(());
+    }
+
+    let report = run(true).await?;
+    info!(
+        "Member role reconciliation by {}: {}",
+        ctx.author().name,
+        report.summary()
+    );
+    send_report(ctx, &report).await
+}
+
+/// Reply with the report's summary and the report as CSV
+async fn send_report(ctx: Context<'_>, report: &MemberRoleReport) -> Result<(), Error> {
+    let title = if report.applied {
+        "**Member roles reconciled**"
+    } else {
+        "**Member role report** (nothing changed)"
+    };
+    let mut content = format!("{}\n{}", title, report.summary());
+    for role in &report.missing_roles {
+        content.push_str(&format!("\nRole '{}' doesn't exist in Discord", role));
+    }
+    ctx.send(poise::CreateReply::default().content(content).attachment(
+        serenity::CreateAttachment::bytes(report.to_csv()?, "member_roles.csv"),
+    ))
+    .await?;
+    Ok(())
+}
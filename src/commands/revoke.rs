@@ -0,0 +1,127 @@
+use poise::serenity_prelude as serenity;
+use tracing::{error, info};
+
+use crate::state::user_database_path;
+use crate::{Context, Error};
+
+/// Revoke a member's verification and remove their season and special roles
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn revoke(
+    ctx: Context<'_>,
+    #[description = "Member to revoke"] user: serenity::User,
+    #[description = "Reason (stored in the user's notes)"] reason: String,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
+    ctx.defer_ephemeral().await?;
+
+    let verification_manager = &ctx.data().verification_manager;
+    let tracked_user = match verification_manager
+        .revoke_user(user.id, &reason, &ctx.author().name)
+        .await
+    {
+        Ok(u) => u,
+        Err(e) => {
+            ctx.say(format!("Could not revoke {}: {}", user.name, e)).await?;
+            return Ok(());
+        }
+    };
+
+    if let Err(e) = verification_manager
+        .save_database(&user_database_path())
+        .await
+    {
+        error!("Failed to save user database: {}", e);
+    }
+
+    let roles = verification_manager.get_roles_for_user(&tracked_user).await;
+    let (removed, failed) = {
+        let role_manager = ctx.data().role_manager.read().await;
+        role_manager
+            .remove_roles_from_user(ctx.http(), guild_id, user.id, &roles)
+            .await
+    };
+
+    info!(
+        "{} revoked verification for {} ({}): {}",
+        ctx.author().name, tracked_user.display_name, user.id, reason
+    );
+
+    let mut response = format!(
+        "Revoked verification for **{}** ({}).\nReason: {}\n",
+        tracked_user.display_name, user.name, reason
+    );
+    if !removed.is_empty() {
+        response.push_str(&format!("Removed roles: {}\n", removed.join(", ")));
+    }
+    if !failed.is_empty() {
+        response.push_str(&format!("Failed to remove: {}\n", failed.join(", ")));
+    }
+
+    ctx.say(response).await?;
+    Ok(())
+}
+
+/// Reinstate a revoked member and restore their roles
+#[poise::command(
+    slash_command,
+    guild_only,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn unrevoke(
+    ctx: Context<'_>,
+    #[description = "Member to reinstate"] user: serenity::User,
+    #[description = "Reason (stored in the user's notes)"] reason: Option<String>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
+    ctx.defer_ephemeral().await?;
+
+    let verification_manager = &ctx.data().verification_manager;
+    let tracked_user = match verification_manager
+        .unrevoke_user(user.id, reason.as_deref(), &ctx.author().name)
+        .await
+    {
+        Ok(u) => u,
+        Err(e) => {
+            ctx.say(format!("Could not reinstate {}: {}", user.name, e)).await?;
+            return Ok(());
+        }
+    };
+
+    if let Err(e) = verification_manager
+        .save_database(&user_database_path())
+        .await
+    {
+        error!("Failed to save user database: {}", e);
+    }
+
+    let roles = verification_manager.get_roles_for_user(&tracked_user).await;
+    let (added, failed) = {
+        let role_manager = ctx.data().role_manager.read().await;
+        role_manager
+            .sync_assignments_for_user(ctx.http(), guild_id, user.id, &user.name, &roles)
+            .await
+    };
+
+    info!(
+        "{} reinstated verification for {} ({})",
+        ctx.author().name, tracked_user.display_name, user.id
+    );
+
+    let mut response = format!(
+        "Reinstated **{}** ({}).\n",
+        tracked_user.display_name, user.name
+    );
+    if !added.is_empty() {
+        response.push_str(&format!("Restored roles: {}\n", added.join(", ")));
+    }
+    if !failed.is_empty() {
+        response.push_str(&format!("Failed to restore: {}\n", failed.join(", ")));
+    }
+
+    ctx.say(response).await?;
+    Ok(())
+}
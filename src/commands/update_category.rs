@@ -1,8 +1,12 @@
 use tracing::{error, info};
 
+use super::plan::{confirm_plan, ensure_plan_current};
+use crate::managers::GuildSnapshot;
 use crate::{Context, Error};
 
 /// Update a category structure for a specific season
+///
+/// Shows the planned changes first and only applies them once confirmed.
 #[poise::command(slash_command, guild_only)]
 pub async fn update_category(
     ctx: Context<'_>,
@@ -15,81 +19,85 @@ pub async fn update_category(
 
     let config_manager = ctx.data().config_manager.read().await;
 
-    // Get season
-    let season = match config_manager.get_season(&season_id) {
+    // Resolve the season's layout (season.json, category.json and global/structure.json)
+    let structure = match config_manager.get_season_structure(&season_id) {
         Some(s) => s,
         None => {
             ctx.say(format!("Season '{}' not found.", season_id)).await?;
             return Ok(());
         }
     };
+    drop(config_manager);
 
     // Check if season has channels defined
-    if season.channels().is_empty() {
+    if structure.channels.is_empty() {
         ctx.say(format!(
-            "Season '{}' has no channels defined in season.json.\n\nTo define channels, add them to `data/seasons/{}/season.json`",
-            season_id, season_id
+            "Season '{}' has no channels defined.\n\nTo define channels, add them to `data/seasons/{}/season.json`, `data/seasons/{}/category.json` or `data/global/structure.json`",
+            season_id, season_id, season_id
         ))
         .await?;
         return Ok(());
     }
 
-    // Clone the channels since we need to drop config_manager before using channel_manager
-    let channels = season.channels().to_vec();
-    let category_name = season.name().to_string();
-    drop(config_manager);
-
-    // Get the channel manager and HTTP client
-    let channel_manager = ctx.data().channel_manager.read().await;
     let http = ctx.serenity_context().http.as_ref();
 
-    // First, ensure the category exists (using season name as category name)
-    info!("Creating/verifying category '{}' for season '{}'", category_name, season_id);
-    let category_id = channel_manager
-        .ensure_category_exists(http, guild_id, &category_name)
-        .await?;
+    // Plan against the current guild and let the author review it
+    let plan = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager.plan_season_structure(&snapshot, &structure).await
+    };
+    let title = format!("Season '{}' structure plan", season_id);
+    if !confirm_plan(ctx, &title, &plan).await? {
+        return Ok(());
+    }
+    let current = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager.plan_season_structure(&snapshot, &structure).await
+    };
+    if !ensure_plan_current(ctx, &plan, &current).await? {
+        return Ok(());
+    }
 
-    let mut created_channels = Vec::new();
+    // Roles from the structure must exist before channel permissions can reference them
     let mut errors = Vec::new();
-
-    // Create each channel within the category
-    for channel_def in &channels {
-        info!("Creating/verifying channel '{}' in category '{}'", channel_def.name, category_name);
-
-        match channel_manager
-            .ensure_channel_exists(http, guild_id, channel_def, Some(category_id))
+    if !structure.roles.is_empty() {
+        let role_manager = ctx.data().role_manager.read().await;
+        if let Err(e) = role_manager
+            .ensure_roles_exist(http, guild_id, &structure.roles)
             .await
         {
-            Ok(_channel_id) => {
-                // Check if we created it or it already existed
-                // For now, assume it was handled (created or updated)
-                created_channels.push(channel_def.name.clone());
-            }
-            Err(e) => {
-                error!("Failed to configure channel '{}': {}", channel_def.name, e);
-                errors.push(format!("{}: {}", channel_def.name, e));
-            }
+            error!("Failed to create structure roles for season '{}': {}", season_id, e);
+            errors.push(format!("roles: {}", e));
         }
     }
 
-    drop(channel_manager);
+    // Create, permission and order every category of the season with its channels
+    info!(
+        "Syncing categories {:?} for season '{}'",
+        structure.category_names(),
+        season_id
+    );
+    let summary = {
+        let channel_manager = ctx.data().channel_manager.read().await;
+        channel_manager
+            .sync_season_structure(http, guild_id, &season_id, &structure)
+            .await
+    };
 
     // Build response message
     let mut response = format!("**Season '{}' structure updated:**\n", season_id);
-    response.push_str(&format!("Category: {}\n\n", category_name));
-
-    if !created_channels.is_empty() {
-        response.push_str(&format!(
-            "Channels configured ({}):\n",
-            created_channels.len()
-        ));
-        for ch in &created_channels {
-            response.push_str(&format!("  - #{}\n", ch));
+    match summary {
+        Ok(summary) => response.push_str(&summary.format()),
+        Err(e) => {
+            error!("Failed to sync season '{}': {}", season_id, e);
+            errors.push(e.to_string());
         }
     }
 
     if !errors.is_empty() {
-        response.push_str(&format!("\nErrors ({}):\n", errors.len()));
+        response.push_str(&format!("\n\nErrors ({}):\n", errors.len()));
         for err in &errors {
             response.push_str(&format!("  - {}\n", err));
         }
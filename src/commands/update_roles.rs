@@ -1,6 +1,8 @@
 use poise::serenity_prelude as serenity;
-use tracing::{error, info};
+use tracing::error;
 
+use super::plan::{confirm_plan, ensure_plan_current};
+use crate::managers::{GuildSnapshot, RoleSyncOutcome};
 use crate::{Context, Error};
 
 /// Handle a role operation error and return a user-friendly message
@@ -20,6 +22,8 @@ fn format_role_error(role_name: &str, e: &serenity::Error) -> String {
 }
 
 /// Sync Discord roles with the global roles configuration
+///
+/// Shows the planned changes first and only applies them once confirmed.
 #[poise::command(slash_command, guild_only)]
 pub async fn update_roles(ctx: Context<'_>) -> Result<(), Error> {
     let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
@@ -41,8 +45,26 @@ pub async fn update_roles(ctx: Context<'_>) -> Result<(), Error> {
 
     let http = ctx.serenity_context().http.as_ref();
 
-    // Get existing roles in the guild
-    let existing_roles = guild_id.roles(http).await?;
+    // Plan against the current guild and let the author review it
+    let plan = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let role_manager = ctx.data().role_manager.read().await;
+        role_manager.plan_role_sync(&snapshot, &global_roles.roles).await
+    };
+    if !confirm_plan(ctx, "Role sync plan", &plan).await? {
+        return Ok(());
+    }
+    let current = {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let role_manager = ctx.data().role_manager.read().await;
+        role_manager.plan_role_sync(&snapshot, &global_roles.roles).await
+    };
+    if !ensure_plan_current(ctx, &plan, &current).await? {
+        return Ok(());
+    }
+
+    let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+    let role_manager = ctx.data().role_manager.read().await;
 
     let mut created = Vec::new();
     let mut updated = Vec::new();
@@ -50,78 +72,26 @@ pub async fn update_roles(ctx: Context<'_>) -> Result<(), Error> {
     let mut errors = Vec::new();
 
     for role_def in &global_roles.roles {
-        // Check if role already exists
-        if let Some((role_id, existing_role)) = existing_roles.iter().find(|(_, r)| r.name == role_def.name) {
-            // Role exists - check if it needs updating
-            let target_color = role_def
-                .color
-                .as_ref()
-                .and_then(|c| {
-                    let hex = c.trim_start_matches('#');
-                    u32::from_str_radix(hex, 16).ok()
-                })
-                .unwrap_or(0);
-
-            let needs_update = existing_role.colour.0 != target_color
-                || existing_role.hoist != role_def.hoist
-                || existing_role.mentionable != role_def.mentionable;
-
-            if needs_update {
-                // Update the role using guild_id.edit_role
-                match guild_id
-                    .edit_role(
-                        http,
-                        *role_id,
-                        serenity::EditRole::new()
-                            .colour(target_color as u64)
-                            .hoist(role_def.hoist)
-                            .mentionable(role_def.mentionable),
-                    )
-                    .await
-                {
-                    Ok(_) => {
-                        info!("Updated role '{}'", role_def.name);
-                        updated.push(role_def.name.clone());
-                    }
-                    Err(e) => {
-                        errors.push(format_role_error(&role_def.name, &e));
-                    }
-                }
-            } else {
-                unchanged.push(role_def.name.clone());
-            }
-        } else {
-            // Create new role
-            let color = role_def
-                .color
-                .as_ref()
-                .and_then(|c| {
-                    let hex = c.trim_start_matches('#');
-                    u32::from_str_radix(hex, 16).ok().map(serenity::Colour::new)
-                })
-                .unwrap_or(serenity::Colour::default());
-
-            match guild_id
-                .create_role(
-                    http,
-                    serenity::EditRole::new()
-                        .name(&role_def.name)
-                        .colour(color)
-                        .hoist(role_def.hoist)
-                        .mentionable(role_def.mentionable),
-                )
-                .await
-            {
-                Ok(role) => {
-                    info!("Created role '{}' (ID: {})", role_def.name, role.id);
-                    created.push(role_def.name.clone());
-                }
-                Err(e) => {
-                    errors.push(format_role_error(&role_def.name, &e));
-                }
-            }
+        let existing = role_manager.find_role(&snapshot, role_def).await;
+        match role_manager.apply_role(http, guild_id, role_def, existing).await {
+            Ok(RoleSyncOutcome::Created) => created.push(role_def.name.clone()),
+            Ok(RoleSyncOutcome::Updated) => updated.push(role_def.name.clone()),
+            Ok(RoleSyncOutcome::Unchanged) => unchanged.push(role_def.name.clone()),
+            Err(e) => errors.push(format_role_error(&role_def.name, &e)),
         }
     }
+    let mut reordered = Vec::new();
+    match role_manager.apply_role_order(http, guild_id, &global_roles.roles).await {
+        Ok(report) => {
+            reordered = report.moved;
+            errors.extend(report.above_bot);
+        }
+        Err(e) => {
+            error!("Failed to reorder roles: {}", e);
+            errors.push(format!("Failed to reorder roles: {}", e));
+        }
+    }
+    drop(role_manager);
 
     // Build response message
     let mut response = String::from("**Role sync complete:**\n\n");
@@ -142,6 +112,14 @@ pub async fn update_roles(ctx: Context<'_>) -> Result<(), Error> {
         response.push('\n');
     }
 
+    if !reordered.is_empty() {
+        response.push_str(&format!("Reordered ({}):\n", reordered.len()));
+        for name in &reordered {
+            response.push_str(&format!("  - @{}\n", name));
+        }
+        response.push('\n');
+    }
+
     if !unchanged.is_empty() {
         response.push_str(&format!("Unchanged ({}):\n", unchanged.len()));
         for name in &unchanged {
@@ -157,7 +135,7 @@ pub async fn update_roles(ctx: Context<'_>) -> Result<(), Error> {
         }
     }
 
-    if created.is_empty() && updated.is_empty() && errors.is_empty() {
+    if created.is_empty() && updated.is_empty() && reordered.is_empty() && errors.is_empty() {
         response = String::from("All roles are already in sync with the configuration.");
     }
 
@@ -0,0 +1,100 @@
+use poise::serenity_prelude as serenity;
+use poise::Modal;
+use tracing::{error, info};
+
+use crate::state::user_database_path;
+use crate::{Data, Error};
+
+type ApplicationContext<'a> = poise::ApplicationContext<'a, Data, Error>;
+
+/// Modal asking for the verification ID
+#[derive(Debug, Modal)]
+#[name = "Verify your account"]
+struct VerifyModal {
+    #[name = "Verification ID"]
+    #[placeholder = "The ID from your registration email"]
+    #[min_length = 1]
+    #[max_length = 100]
+    verification_id: String,
+}
+
+/// Verify your account with the ID you received
+#[poise::command(slash_command, guild_only)]
+pub async fn verify(ctx: ApplicationContext<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("This command must be used in a guild")?;
+    let author = ctx.author().clone();
+
+    let modal = match VerifyModal::execute(ctx).await? {
+        Some(m) => m,
+        None => {
+            info!("Verification modal for {} timed out", author.name);
+            return Ok(());
+        }
+    };
+
+    let verification_manager = &ctx.data().verification_manager;
+    let result = verification_manager
+        .attempt_verification(author.id, &author.name, &modal.verification_id)
+        .await;
+
+    if !result.success {
+        let message = result
+            .error
+            .unwrap_or_else(|| "Verification failed".to_string());
+        ctx.send(poise::CreateReply::default()
+            .content(message)
+            .ephemeral(true))
+            .await?;
+        return Ok(());
+    }
+
+    // Save database to disk
+    if let Err(e) = verification_manager
+        .save_database(&user_database_path())
+        .await
+    {
+        error!("Failed to save user database: {}", e);
+    }
+
+    // Apply nickname and roles the same way the web callback does
+    let (added, failed) = {
+        let role_manager = ctx.data().role_manager.read().await;
+        role_manager
+            .apply_verified_member(
+                ctx.http(),
+                guild_id,
+                author.id,
+                &author.name,
+                &result.display_name,
+                &result.roles_to_assign,
+            )
+            .await
+    };
+
+    info!(
+        "User {} verified as '{}' via /verify",
+        author.id, result.display_name
+    );
+
+    let mut embed = serenity::CreateEmbed::new()
+        .title("Verification Successful!")
+        .field("Display Name", &result.display_name, true)
+        .field("Season", result.seasons.join(", "), true)
+        .field("Roles", result.roles_to_assign.join(", "), false)
+        .color(0x2ecc71);
+
+    if !failed.is_empty() {
+        embed = embed.field(
+            "Could not assign",
+            format!("{}\nPlease contact an administrator.", failed.join(", ")),
+            false,
+        );
+    } else if added.is_empty() {
+        embed = embed.description("You already had all your roles.");
+    }
+
+    ctx.send(poise::CreateReply::default().embed(embed).ephemeral(true))
+        .await?;
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
-use super::global_structure::{ChannelDefinition, RoleDefinition};
+use super::global_structure::{
+    ChannelDefinition, ChannelPermissionLevel, ChannelType, RoleDefinition,
+};
+use super::season::{rename_channel_roles, replace_season_id};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Per-season category structure configuration
 /// Overrides or extends the global structure for a specific season
@@ -51,6 +55,52 @@ impl CategoryStructureConfig {
         })
     }
 
+    /// Build the overrides for a new season from this one
+    ///
+    /// Season IDs in the category name, role names and channel permissions
+    /// are replaced as in `SeasonConfig::rolled_over`, with `old_member_role`
+    /// becoming `new_member_role`.
+    pub fn rolled_over(
+        &self,
+        old_id: &str,
+        new_id: &str,
+        old_member_role: &str,
+        new_member_role: &str,
+    ) -> Self {
+        let roles = |roles: &[RoleDefinition]| -> Vec<RoleDefinition> {
+            roles
+                .iter()
+                .map(|role| RoleDefinition {
+                    name: if role.name == old_member_role {
+                        new_member_role.to_string()
+                    } else {
+                        replace_season_id(&role.name, old_id, new_id)
+                    },
+                    ..role.clone()
+                })
+                .collect()
+        };
+        let channels = |channels: &[ChannelDefinition]| -> Vec<ChannelDefinition> {
+            channels
+                .iter()
+                .map(|c| rename_channel_roles(c, old_id, new_id, old_member_role, new_member_role))
+                .collect()
+        };
+
+        Self {
+            season_id: new_id.to_string(),
+            category_name: self
+                .category_name
+                .as_deref()
+                .map(|name| replace_season_id(name, old_id, new_id)),
+            channels: channels(&self.channels),
+            role_overrides: roles(&self.role_overrides),
+            channel_overrides: channels(&self.channel_overrides),
+            additional_channels: channels(&self.additional_channels),
+            additional_roles: roles(&self.additional_roles),
+        }
+    }
+
     /// Get the category name (falls back to season_id)
     pub fn get_category_name(&self) -> &str {
         self.category_name.as_deref().unwrap_or(&self.season_id)
@@ -129,6 +179,69 @@ pub struct MergedStructure {
     pub channels: Vec<ChannelDefinition>,
 }
 
+/// One Discord category of a season and the channels inside it
+#[derive(Debug, Clone)]
+pub struct CategoryGroup {
+    pub name: String,
+    /// Names the category had before (see `ChannelDefinition::previous_names`)
+    pub previous_names: Vec<String>,
+    /// Permissions on the category itself (empty for the season's default category)
+    pub role_permissions: HashMap<String, ChannelPermissionLevel>,
+    pub channels: Vec<ChannelDefinition>,
+}
+
+impl MergedStructure {
+    /// Split the layout into the Discord categories it needs, in order
+    ///
+    /// Top-level channels of type `category` become categories of their own
+    /// holding their `children`. Other top-level channels go into the season's
+    /// default category, which comes first and is left out when every channel
+    /// belongs to a declared category.
+    pub fn category_groups(&self) -> Vec<CategoryGroup> {
+        let (declared, loose): (Vec<_>, Vec<_>) = self
+            .channels
+            .iter()
+            .partition(|c| c.channel_type == ChannelType::Category);
+
+        let mut groups = Vec::new();
+        if !loose.is_empty() || declared.is_empty() {
+            groups.push(CategoryGroup {
+                name: self.category_name.clone(),
+                previous_names: vec![],
+                role_permissions: HashMap::new(),
+                channels: loose.into_iter().cloned().collect(),
+            });
+        }
+        for category in declared {
+            groups.push(CategoryGroup {
+                name: category.name.clone(),
+                previous_names: category.previous_names.clone(),
+                role_permissions: category.role_permissions.clone(),
+                channels: category.children.clone(),
+            });
+        }
+        groups
+    }
+
+    /// Names of all Discord categories the season uses
+    pub fn category_names(&self) -> Vec<String> {
+        self.category_groups().into_iter().map(|g| g.name).collect()
+    }
+
+    /// Point every reference to role `from` at role `to`
+    pub fn rename_role(&mut self, from: &str, to: &str) {
+        if from == to {
+            return;
+        }
+        for role in self.roles.iter_mut().filter(|r| r.name == from) {
+            role.name = to.to_string();
+        }
+        for channel in &mut self.channels {
+            channel.rename_role(from, to);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +271,60 @@ mod tests {
         // Should have global channel + additional channel
         assert!(merged.channels.len() >= 2);
     }
+
+    #[test]
+    fn test_category_groups() {
+        let channels: Vec<ChannelDefinition> = serde_json::from_str(
+            r#"[
+                { "name": "welcome", "type": "text" },
+                { "name": "2025E – Voice parts", "type": "category",
+                  "role_permissions": { "Medlem2025E": "read" },
+                  "children": [{ "name": "soprano", "type": "voice" }, { "name": "alto", "type": "voice" }] },
+                { "name": "2025E – Board", "type": "category", "children": [{ "name": "board", "type": "text" }] }
+            ]"#,
+        )
+        .unwrap();
+        let mut merged = MergedStructure {
+            category_name: "2025E".to_string(),
+            roles: vec![],
+            channels,
+        };
+
+        let groups = merged.category_groups();
+        assert_eq!(
+            merged.category_names(),
+            vec!["2025E", "2025E – Voice parts", "2025E – Board"]
+        );
+        assert_eq!(groups[0].channels.len(), 1);
+        assert_eq!(groups[1].channels.len(), 2);
+        assert!(groups[1].role_permissions.contains_key("Medlem2025E"));
+
+        // Without loose channels there is no default category
+        merged.channels.remove(0);
+        assert_eq!(merged.category_names(), vec!["2025E – Voice parts", "2025E – Board"]);
+    }
+
+    #[test]
+    fn test_rolled_over_renames_season_ids() {
+        let category: CategoryStructureConfig = serde_json::from_str(
+            r#"{
+                "season_id": "2025E",
+                "category_name": "2025E Efterår",
+                "additional_roles": [{ "name": "Tutor2025E" }],
+                "additional_channels": [
+                    { "name": "tutors", "type": "text",
+                      "role_permissions": { "Medlem2025E": "read", "Tutor2025E": "readwrite" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let rolled = category.rolled_over("2025E", "2026F", "Medlem2025E", "Members");
+        assert_eq!(rolled.season_id, "2026F");
+        assert_eq!(rolled.get_category_name(), "2026F Efterår");
+        assert_eq!(rolled.additional_roles[0].name, "Tutor2026F");
+        let permissions = &rolled.additional_channels[0].role_permissions;
+        assert_eq!(permissions.get("Members"), Some(&ChannelPermissionLevel::Read));
+        assert_eq!(permissions.get("Tutor2026F"), Some(&ChannelPermissionLevel::ReadWrite));
+    }
 }
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+
+use super::SeasonUser;
+use crate::error::{BotError, Result};
+
+/// Most row errors to list before summarising the rest
+const MAX_REPORTED_ERRORS: usize = 20;
+
+/// Which CSV columns hold the user fields (set per season in season.json)
+///
+/// Header names are matched case-insensitively after trimming.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CsvColumns {
+    /// Column with the user's display name
+    #[serde(default = "default_name_column")]
+    pub name: String,
+
+    /// Column with the verification ID
+    #[serde(default = "default_id_column")]
+    pub id: String,
+
+    /// Column with the user's email (optional; missing column means no emails)
+    #[serde(default = "default_email_column")]
+    pub email: String,
+}
+
+fn default_name_column() -> String {
+    "Name".to_string()
+}
+
+fn default_id_column() -> String {
+    "DiscordId".to_string()
+}
+
+fn default_email_column() -> String {
+    "Email".to_string()
+}
+
+impl Default for CsvColumns {
+    fn default() -> Self {
+        Self {
+            name: default_name_column(),
+            id: default_id_column(),
+            email: default_email_column(),
+        }
+    }
+}
+
+/// Convert a CSV export into season users
+///
+/// The delimiter (`,` or `;`, as spreadsheet programs in some locales export)
+/// is detected from the header line. Every bad row is reported with its line
+/// number, and nothing is returned unless all rows are valid.
+pub fn parse_users_csv(data: &[u8], columns: &CsvColumns) -> Result<Vec<SeasonUser>> {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    let header_line = data.split(|&b| b == b'\n').next().unwrap_or_default();
+    let delimiter = if header_line.iter().filter(|&&b| b == b';').count()
+        > header_line.iter().filter(|&&b| b == b',').count()
+    {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(data);
+
+    let headers = reader.headers().map_err(|e| BotError::ConfigValidation {
+        message: format!("Failed to read CSV header: {}", e),
+    })?;
+    let find = |column: &str| {
+        headers
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(column.trim()))
+    };
+
+    let (Some(name_idx), Some(id_idx)) = (find(&columns.name), find(&columns.id)) else {
+        return Err(BotError::ConfigValidation {
+            message: format!(
+                "CSV header must contain '{}' and '{}' columns (found: {})",
+                columns.name,
+                columns.id,
+                headers.iter().collect::<Vec<_>>().join(", ")
+            ),
+        });
+    };
+    let email_idx = find(&columns.email);
+
+    let mut users = Vec::new();
+    let mut errors = Vec::new();
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or_default();
+                errors.push(format!("line {}: {}", line, e));
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+
+        // Skip blank rows spreadsheets like to leave at the end
+        if record.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+
+        let field = |idx: usize| record.get(idx).map(str::trim).unwrap_or_default();
+        let name = field(name_idx);
+        let id = field(id_idx);
+
+        if name.is_empty() {
+            errors.push(format!("line {}: missing {}", line, columns.name));
+            continue;
+        }
+        if id.is_empty() {
+            errors.push(format!("line {}: missing {} for '{}'", line, columns.id, name));
+            continue;
+        }
+        if let Some(first) = users.iter().position(|u: &SeasonUser| u.id == id) {
+            errors.push(format!(
+                "line {}: duplicate {} '{}' (also used by '{}')",
+                line, columns.id, id, users[first].name
+            ));
+            continue;
+        }
+
+        let email = email_idx
+            .map(field)
+            .filter(|e| !e.is_empty())
+            .map(String::from);
+
+        users.push(SeasonUser {
+            name: name.to_string(),
+            id: id.to_string(),
+            email,
+        });
+    }
+
+    if !errors.is_empty() {
+        let total = errors.len();
+        errors.truncate(MAX_REPORTED_ERRORS);
+        if total > MAX_REPORTED_ERRORS {
+            errors.push(format!("… and {} more", total - MAX_REPORTED_ERRORS));
+        }
+        return Err(BotError::ConfigValidation {
+            message: format!("{} bad row(s) in CSV:\n{}", total, errors.join("\n")),
+        });
+    }
+
+    Ok(users)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_users_csv_with_mapping() {
+        let columns = CsvColumns {
+            name: "Fulde navn".to_string(),
+            id: "Medlemsnummer".to_string(),
+            email: "E-mail".to_string(),
+        };
+        let csv = "\u{feff}Medlemsnummer;Fulde navn;E-mail\n\
+                   1001;Anna Jensen;anna@example.com\n\
+                   1002;Bo Hansen;\n\
+                   ;;\n";
+
+        let users = parse_users_csv(csv.as_bytes(), &columns).unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].id, "1001");
+        assert_eq!(users[0].email.as_deref(), Some("anna@example.com"));
+        assert_eq!(users[1].name, "Bo Hansen");
+        assert!(users[1].email.is_none());
+    }
+
+    #[test]
+    fn test_parse_users_csv_reports_row_errors() {
+        let csv = "Name,DiscordId\nAnna,a1\n,a2\nBo,\nCarl,a1\n";
+
+        let err = parse_users_csv(csv.as_bytes(), &CsvColumns::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("3 bad row(s)"));
+        assert!(err.contains("line 3: missing Name"));
+        assert!(err.contains("line 4: missing DiscordId for 'Bo'"));
+        assert!(err.contains("line 5: duplicate DiscordId 'a1' (also used by 'Anna')"));
+    }
+}
@@ -50,6 +50,7 @@ impl Default for GlobalRolesConfig {
                 is_default_member_role: true,
                 permissions: vec![],
                 skip_permission_sync: false,
+                previous_names: vec![],
             }],
         }
     }
@@ -35,6 +35,7 @@ impl Default for GlobalStructureConfig {
                 is_default_member_role: true,
                 permissions: vec![],
                 skip_permission_sync: false,
+                previous_names: vec![],
             }],
             default_channels: vec![ChannelDefinition {
                 name: "general".to_string(),
@@ -46,6 +47,7 @@ impl Default for GlobalStructureConfig {
                     perms
                 },
                 children: vec![],
+                ..Default::default()
             }],
             permission_presets: HashMap::new(),
             permission_definitions: HashMap::new(),
@@ -109,6 +111,10 @@ pub struct RoleDefinition {
     /// If true, skip syncing permissions for this role (useful for roles managed manually in Discord)
     #[serde(default)]
     pub skip_permission_sync: bool,
+
+    /// Names the role had before, so renaming it here renames the existing Discord role
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_names: Vec<String>,
 }
 
 impl RoleDefinition {
@@ -118,59 +124,23 @@ impl RoleDefinition {
         !self.permissions.is_empty()
     }
 
+    /// Whether role syncs set this role's server permissions
+    pub fn syncs_permissions(&self) -> bool {
+        self.has_explicit_permissions() && !self.skip_permission_sync
+    }
+
     /// Parse permission strings into Discord Permissions
     pub fn get_permissions(&self) -> poise::serenity_prelude::Permissions {
         use poise::serenity_prelude::Permissions;
 
         let mut perms = Permissions::empty();
         for name in &self.permissions {
-            match name.to_uppercase().as_str() {
-                "CREATE_INSTANT_INVITE" => perms |= Permissions::CREATE_INSTANT_INVITE,
-                "KICK_MEMBERS" => perms |= Permissions::KICK_MEMBERS,
-                "BAN_MEMBERS" => perms |= Permissions::BAN_MEMBERS,
-                "ADMINISTRATOR" => perms |= Permissions::ADMINISTRATOR,
-                "MANAGE_CHANNELS" => perms |= Permissions::MANAGE_CHANNELS,
-                "MANAGE_GUILD" => perms |= Permissions::MANAGE_GUILD,
-                "ADD_REACTIONS" => perms |= Permissions::ADD_REACTIONS,
-                "VIEW_AUDIT_LOG" => perms |= Permissions::VIEW_AUDIT_LOG,
-                "PRIORITY_SPEAKER" => perms |= Permissions::PRIORITY_SPEAKER,
-                "STREAM" => perms |= Permissions::STREAM,
-                "VIEW_CHANNEL" => perms |= Permissions::VIEW_CHANNEL,
-                "SEND_MESSAGES" => perms |= Permissions::SEND_MESSAGES,
-                "SEND_TTS_MESSAGES" => perms |= Permissions::SEND_TTS_MESSAGES,
-                "MANAGE_MESSAGES" => perms |= Permissions::MANAGE_MESSAGES,
-                "EMBED_LINKS" => perms |= Permissions::EMBED_LINKS,
-                "ATTACH_FILES" => perms |= Permissions::ATTACH_FILES,
-                "READ_MESSAGE_HISTORY" => perms |= Permissions::READ_MESSAGE_HISTORY,
-                "MENTION_EVERYONE" => perms |= Permissions::MENTION_EVERYONE,
-                "USE_EXTERNAL_EMOJIS" => perms |= Permissions::USE_EXTERNAL_EMOJIS,
-                "VIEW_GUILD_INSIGHTS" => perms |= Permissions::VIEW_GUILD_INSIGHTS,
-                "CONNECT" => perms |= Permissions::CONNECT,
-                "SPEAK" => perms |= Permissions::SPEAK,
-                "MUTE_MEMBERS" => perms |= Permissions::MUTE_MEMBERS,
-                "DEAFEN_MEMBERS" => perms |= Permissions::DEAFEN_MEMBERS,
-                "MOVE_MEMBERS" => perms |= Permissions::MOVE_MEMBERS,
-                "USE_VAD" => perms |= Permissions::USE_VAD,
-                "CHANGE_NICKNAME" => perms |= Permissions::CHANGE_NICKNAME,
-                "MANAGE_NICKNAMES" => perms |= Permissions::MANAGE_NICKNAMES,
-                "MANAGE_ROLES" => perms |= Permissions::MANAGE_ROLES,
-                "MANAGE_WEBHOOKS" => perms |= Permissions::MANAGE_WEBHOOKS,
-                "MANAGE_EMOJIS" => perms |= Permissions::MANAGE_GUILD_EXPRESSIONS,
-                "USE_APPLICATION_COMMANDS" => perms |= Permissions::USE_APPLICATION_COMMANDS,
-                "REQUEST_TO_SPEAK" => perms |= Permissions::REQUEST_TO_SPEAK,
-                "MANAGE_EVENTS" => perms |= Permissions::MANAGE_EVENTS,
-                "MANAGE_THREADS" => perms |= Permissions::MANAGE_THREADS,
-                "CREATE_PUBLIC_THREADS" => perms |= Permissions::CREATE_PUBLIC_THREADS,
-                "CREATE_PRIVATE_THREADS" => perms |= Permissions::CREATE_PRIVATE_THREADS,
-                "USE_EXTERNAL_STICKERS" => perms |= Permissions::USE_EXTERNAL_STICKERS,
-                "SEND_MESSAGES_IN_THREADS" => perms |= Permissions::SEND_MESSAGES_IN_THREADS,
-                "USE_EMBEDDED_ACTIVITIES" => perms |= Permissions::USE_EMBEDDED_ACTIVITIES,
-                "MODERATE_MEMBERS" => perms |= Permissions::MODERATE_MEMBERS,
-                "MANAGE_GUILD_EXPRESSIONS" => perms |= Permissions::MANAGE_GUILD_EXPRESSIONS,
-                unknown => {
+            match parse_permission_name(name) {
+                Some(p) => perms |= p,
+                None => {
                     tracing::warn!(
                         "Unknown permission string '{}' in role '{}', ignoring",
-                        unknown,
+                        name,
                         self.name
                     );
                 }
@@ -180,13 +150,71 @@ impl RoleDefinition {
     }
 }
 
+/// Parse a Discord permission name (e.g. "SEND_MESSAGES"), case-insensitive
+/// Returns None for names we don't recognise
+pub fn parse_permission_name(name: &str) -> Option<poise::serenity_prelude::Permissions> {
+    use poise::serenity_prelude::Permissions;
+
+    let permission = match name.to_uppercase().as_str() {
+        "CREATE_INSTANT_INVITE" => Permissions::CREATE_INSTANT_INVITE,
+        "KICK_MEMBERS" => Permissions::KICK_MEMBERS,
+        "BAN_MEMBERS" => Permissions::BAN_MEMBERS,
+        "ADMINISTRATOR" => Permissions::ADMINISTRATOR,
+        "MANAGE_CHANNELS" => Permissions::MANAGE_CHANNELS,
+        "MANAGE_GUILD" => Permissions::MANAGE_GUILD,
+        "ADD_REACTIONS" => Permissions::ADD_REACTIONS,
+        "VIEW_AUDIT_LOG" => Permissions::VIEW_AUDIT_LOG,
+        "PRIORITY_SPEAKER" => Permissions::PRIORITY_SPEAKER,
+        "STREAM" => Permissions::STREAM,
+        "VIEW_CHANNEL" => Permissions::VIEW_CHANNEL,
+        "SEND_MESSAGES" => Permissions::SEND_MESSAGES,
+        "SEND_TTS_MESSAGES" => Permissions::SEND_TTS_MESSAGES,
+        "MANAGE_MESSAGES" => Permissions::MANAGE_MESSAGES,
+        "EMBED_LINKS" => Permissions::EMBED_LINKS,
+        "ATTACH_FILES" => Permissions::ATTACH_FILES,
+        "READ_MESSAGE_HISTORY" => Permissions::READ_MESSAGE_HISTORY,
+        "MENTION_EVERYONE" => Permissions::MENTION_EVERYONE,
+        "USE_EXTERNAL_EMOJIS" => Permissions::USE_EXTERNAL_EMOJIS,
+        "VIEW_GUILD_INSIGHTS" => Permissions::VIEW_GUILD_INSIGHTS,
+        "CONNECT" => Permissions::CONNECT,
+        "SPEAK" => Permissions::SPEAK,
+        "MUTE_MEMBERS" => Permissions::MUTE_MEMBERS,
+        "DEAFEN_MEMBERS" => Permissions::DEAFEN_MEMBERS,
+        "MOVE_MEMBERS" => Permissions::MOVE_MEMBERS,
+        "USE_VAD" => Permissions::USE_VAD,
+        "CHANGE_NICKNAME" => Permissions::CHANGE_NICKNAME,
+        "MANAGE_NICKNAMES" => Permissions::MANAGE_NICKNAMES,
+        "MANAGE_ROLES" => Permissions::MANAGE_ROLES,
+        "MANAGE_WEBHOOKS" => Permissions::MANAGE_WEBHOOKS,
+        "MANAGE_EMOJIS" => Permissions::MANAGE_GUILD_EXPRESSIONS,
+        "USE_APPLICATION_COMMANDS" => Permissions::USE_APPLICATION_COMMANDS,
+        "REQUEST_TO_SPEAK" => Permissions::REQUEST_TO_SPEAK,
+        "MANAGE_EVENTS" => Permissions::MANAGE_EVENTS,
+        "MANAGE_THREADS" => Permissions::MANAGE_THREADS,
+        "CREATE_PUBLIC_THREADS" => Permissions::CREATE_PUBLIC_THREADS,
+        "CREATE_PRIVATE_THREADS" => Permissions::CREATE_PRIVATE_THREADS,
+        "USE_EXTERNAL_STICKERS" => Permissions::USE_EXTERNAL_STICKERS,
+        "SEND_MESSAGES_IN_THREADS" => Permissions::SEND_MESSAGES_IN_THREADS,
+        "USE_EMBEDDED_ACTIVITIES" => Permissions::USE_EMBEDDED_ACTIVITIES,
+        "MODERATE_MEMBERS" => Permissions::MODERATE_MEMBERS,
+        "MANAGE_GUILD_EXPRESSIONS" => Permissions::MANAGE_GUILD_EXPRESSIONS,
+        _ => return None,
+    };
+    Some(permission)
+}
+
 /// Definition for a channel (or category)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ChannelDefinition {
     /// Channel name
     pub name: String,
 
+    /// Names the channel or category had before, so renaming it here renames
+    /// the existing Discord channel
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_names: Vec<String>,
+
     /// Channel type
     #[serde(rename = "type")]
     pub channel_type: ChannelType,
@@ -202,13 +230,172 @@ pub struct ChannelDefinition {
     /// Child channels (for categories only)
     #[serde(default)]
     pub children: Vec<ChannelDefinition>,
+
+    /// Channel topic (text, announcement and forum channels)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// Slowmode delay in seconds (0 turns it off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slowmode: Option<u16>,
+
+    /// Age-restricted channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+
+    /// Audio bitrate in bits per second (voice and stage channels)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u32>,
+
+    /// Maximum connected users, 0 for unlimited (voice and stage channels)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u32>,
+
+    /// Tags that can be put on posts (forum channels)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forum_tags: Vec<ForumTagDefinition>,
+
+    /// Reaction added to new posts: a unicode emoji or a custom emoji ID (forum channels)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_reaction: Option<String>,
+
+    /// Channels that follow this one, by name in the guild or by channel ID
+    /// (announcement channels)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub follow_targets: Vec<String>,
+}
+
+/// A tag on a forum channel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ForumTagDefinition {
+    pub name: String,
+
+    /// Only members with Manage Threads can add or remove the tag
+    #[serde(default)]
+    pub moderated: bool,
+
+    /// Unicode emoji or custom emoji ID shown next to the tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+impl ChannelDefinition {
+    /// Move permissions granted to role `from` over to role `to` (recursing into children)
+    pub fn rename_role(&mut self, from: &str, to: &str) {
+        if let Some(level) = self.role_permissions.remove(from) {
+            self.role_permissions.insert(to.to_string(), level);
+        }
+        for child in &mut self.children {
+            child.rename_role(from, to);
+        }
+    }
+
+    /// Permission levels used on this channel or its children that `definitions` can't resolve
+    pub fn undefined_levels(&self, definitions: &HashMap<String, PermissionSet>) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .role_permissions
+            .values()
+            .filter(|level| !level.is_defined(definitions))
+            .map(|level| level.name().to_string())
+            .collect();
+        for child in &self.children {
+            names.extend(child.undefined_levels(definitions));
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Attributes that are set but don't apply to this channel type or are out of range
+    ///
+    /// Returned as (field, message) pairs.
+    pub fn attribute_problems(&self) -> Vec<(&'static str, String)> {
+        use ChannelType::*;
+        let mut problems = Vec::new();
+        let kind = &self.channel_type;
+        let mut only_for = |field: &'static str, set: bool, types: &[ChannelType], what: &str| {
+            if set && !types.contains(kind) {
+                problems.push((
+                    field,
+                    format!("{} only applies to {} channels", field, what),
+                ));
+            }
+        };
+
+        only_for(
+            "topic",
+            self.topic.is_some(),
+            &[Text, News, Forum],
+            "text, news and forum",
+        );
+        only_for(
+            "slowmode",
+            self.slowmode.is_some(),
+            &[Text, News, Forum, Voice, Stage],
+            "non-category",
+        );
+        only_for(
+            "nsfw",
+            self.nsfw.is_some(),
+            &[Text, News, Forum, Voice, Stage],
+            "non-category",
+        );
+        only_for(
+            "bitrate",
+            self.bitrate.is_some(),
+            &[Voice, Stage],
+            "voice and stage",
+        );
+        only_for(
+            "user_limit",
+            self.user_limit.is_some(),
+            &[Voice, Stage],
+            "voice and stage",
+        );
+        only_for("forum_tags", !self.forum_tags.is_empty(), &[Forum], "forum");
+        only_for(
+            "default_reaction",
+            self.default_reaction.is_some(),
+            &[Forum],
+            "forum",
+        );
+        only_for(
+            "follow_targets",
+            !self.follow_targets.is_empty(),
+            &[News],
+            "news",
+        );
+
+        if self.slowmode.is_some_and(|s| s > 21600) {
+            problems.push((
+                "slowmode",
+                "slowmode must be at most 21600 seconds".to_string(),
+            ));
+        }
+        if self.bitrate.is_some_and(|b| !(8000..=384000).contains(&b)) {
+            problems.push((
+                "bitrate",
+                "bitrate must be between 8000 and 384000".to_string(),
+            ));
+        }
+        if self.user_limit.is_some_and(|u| u > 99) {
+            problems.push(("user_limit", "user_limit must be at most 99".to_string()));
+        }
+        if self.forum_tags.len() > 20 {
+            problems.push(("forum_tags", "a forum can have at most 20 tags".to_string()));
+        }
+
+        problems
+    }
 }
 
 /// Channel types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ChannelType {
     Category,
+    #[default]
     Text,
     Voice,
     Forum,
@@ -232,8 +419,11 @@ impl ChannelType {
 }
 
 /// Permission levels for channels
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
+///
+/// The four built-in levels work without any config. Any other name refers to
+/// a level defined in global/permissions.json (e.g. "announce", "moderator").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 pub enum ChannelPermissionLevel {
     /// Cannot view the channel
     None,
@@ -243,9 +433,112 @@ pub enum ChannelPermissionLevel {
     ReadWrite,
     /// Full channel management
     Admin,
+    /// A level defined in global/permissions.json
+    Custom(String),
+}
+
+impl From<String> for ChannelPermissionLevel {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "none" => ChannelPermissionLevel::None,
+            "read" => ChannelPermissionLevel::Read,
+            "readwrite" => ChannelPermissionLevel::ReadWrite,
+            "admin" => ChannelPermissionLevel::Admin,
+            _ => ChannelPermissionLevel::Custom(name),
+        }
+    }
+}
+
+impl From<ChannelPermissionLevel> for String {
+    fn from(level: ChannelPermissionLevel) -> Self {
+        match level {
+            ChannelPermissionLevel::Custom(name) => name,
+            other => other.name().to_string(),
+        }
+    }
 }
 
 impl ChannelPermissionLevel {
+    /// The level's name as written in config files
+    pub fn name(&self) -> &str {
+        match self {
+            ChannelPermissionLevel::None => "none",
+            ChannelPermissionLevel::Read => "read",
+            ChannelPermissionLevel::ReadWrite => "readwrite",
+            ChannelPermissionLevel::Admin => "admin",
+            ChannelPermissionLevel::Custom(name) => name,
+        }
+    }
+
+    /// Whether this level can be resolved with the given definitions
+    ///
+    /// Built-in levels always resolve; custom ones need a definition.
+    pub fn is_defined(&self, definitions: &HashMap<String, PermissionSet>) -> bool {
+        !matches!(self, ChannelPermissionLevel::Custom(name) if !definitions.contains_key(name))
+    }
+
+    /// Definition keys to try for a channel type, most specific first
+    ///
+    /// Voice channels look for `{level}_voice`, stage channels for `{level}_stage`
+    /// and then `{level}_voice`, forums for `{level}_forum`, and every type
+    /// finally falls back to the plain level name.
+    fn definition_keys(&self, channel_type: &ChannelType) -> Vec<String> {
+        let name = self.name();
+        let suffixes: &[&str] = match channel_type {
+            ChannelType::Voice => &["voice"],
+            ChannelType::Stage => &["stage", "voice"],
+            ChannelType::Forum => &["forum"],
+            _ => &[],
+        };
+        suffixes
+            .iter()
+            .map(|suffix| format!("{}_{}", name, suffix))
+            .chain(std::iter::once(name.to_string()))
+            .collect()
+    }
+
+    /// Find the level whose overwrite on this channel type is exactly `allow`/`deny`
+    ///
+    /// Built-in levels are tried first, then custom levels by name. Variant
+    /// definitions (`announce_voice`) count as the level they belong to.
+    pub fn matching(
+        allow: poise::serenity_prelude::Permissions,
+        deny: poise::serenity_prelude::Permissions,
+        channel_type: &ChannelType,
+        config: &GlobalStructureConfig,
+    ) -> Option<Self> {
+        let mut custom: Vec<&str> = config
+            .permission_definitions
+            .keys()
+            .map(|key| {
+                ["_voice", "_stage", "_forum"]
+                    .iter()
+                    .find_map(|suffix| key.strip_suffix(suffix))
+                    .unwrap_or(key)
+            })
+            .collect();
+        custom.sort_unstable();
+        custom.dedup();
+
+        [
+            ChannelPermissionLevel::None,
+            ChannelPermissionLevel::Read,
+            ChannelPermissionLevel::ReadWrite,
+            ChannelPermissionLevel::Admin,
+        ]
+        .into_iter()
+        .chain(
+            custom
+                .into_iter()
+                .map(|name| ChannelPermissionLevel::from(name.to_string()))
+                .filter(|level| matches!(level, ChannelPermissionLevel::Custom(_))),
+        )
+        .find(|level| {
+            level.is_defined(&config.permission_definitions)
+                && level.to_permissions(channel_type, config) == (allow, deny)
+        })
+    }
+
     /// Convert to Discord permission overwrites
     pub fn to_permissions(
         &self,
@@ -261,53 +554,12 @@ impl ChannelPermissionLevel {
         let parse_perms = |perm_names: &[String]| -> Permissions {
             let mut p = Permissions::empty();
             for name in perm_names {
-                match name.to_uppercase().as_str() {
-                    "CREATE_INSTANT_INVITE" => p |= Permissions::CREATE_INSTANT_INVITE,
-                    "KICK_MEMBERS" => p |= Permissions::KICK_MEMBERS,
-                    "BAN_MEMBERS" => p |= Permissions::BAN_MEMBERS,
-                    "ADMINISTRATOR" => p |= Permissions::ADMINISTRATOR,
-                    "MANAGE_CHANNELS" => p |= Permissions::MANAGE_CHANNELS,
-                    "MANAGE_GUILD" => p |= Permissions::MANAGE_GUILD,
-                    "ADD_REACTIONS" => p |= Permissions::ADD_REACTIONS,
-                    "VIEW_AUDIT_LOG" => p |= Permissions::VIEW_AUDIT_LOG,
-                    "PRIORITY_SPEAKER" => p |= Permissions::PRIORITY_SPEAKER,
-                    "STREAM" => p |= Permissions::STREAM,
-                    "VIEW_CHANNEL" => p |= Permissions::VIEW_CHANNEL,
-                    "SEND_MESSAGES" => p |= Permissions::SEND_MESSAGES,
-                    "SEND_TTS_MESSAGES" => p |= Permissions::SEND_TTS_MESSAGES,
-                    "MANAGE_MESSAGES" => p |= Permissions::MANAGE_MESSAGES,
-                    "EMBED_LINKS" => p |= Permissions::EMBED_LINKS,
-                    "ATTACH_FILES" => p |= Permissions::ATTACH_FILES,
-                    "READ_MESSAGE_HISTORY" => p |= Permissions::READ_MESSAGE_HISTORY,
-                    "MENTION_EVERYONE" => p |= Permissions::MENTION_EVERYONE,
-                    "USE_EXTERNAL_EMOJIS" => p |= Permissions::USE_EXTERNAL_EMOJIS,
-                    "VIEW_GUILD_INSIGHTS" => p |= Permissions::VIEW_GUILD_INSIGHTS,
-                    "CONNECT" => p |= Permissions::CONNECT,
-                    "SPEAK" => p |= Permissions::SPEAK,
-                    "MUTE_MEMBERS" => p |= Permissions::MUTE_MEMBERS,
-                    "DEAFEN_MEMBERS" => p |= Permissions::DEAFEN_MEMBERS,
-                    "MOVE_MEMBERS" => p |= Permissions::MOVE_MEMBERS,
-                    "USE_VAD" => p |= Permissions::USE_VAD,
-                    "CHANGE_NICKNAME" => p |= Permissions::CHANGE_NICKNAME,
-                    "MANAGE_NICKNAMES" => p |= Permissions::MANAGE_NICKNAMES,
-                    "MANAGE_ROLES" => p |= Permissions::MANAGE_ROLES,
-                    "MANAGE_WEBHOOKS" => p |= Permissions::MANAGE_WEBHOOKS,
-                    "MANAGE_EMOJIS" => p |= Permissions::MANAGE_GUILD_EXPRESSIONS,
-                    "USE_APPLICATION_COMMANDS" => p |= Permissions::USE_APPLICATION_COMMANDS,
-                    "REQUEST_TO_SPEAK" => p |= Permissions::REQUEST_TO_SPEAK,
-                    "MANAGE_EVENTS" => p |= Permissions::MANAGE_EVENTS,
-                    "MANAGE_THREADS" => p |= Permissions::MANAGE_THREADS,
-                    "CREATE_PUBLIC_THREADS" => p |= Permissions::CREATE_PUBLIC_THREADS,
-                    "CREATE_PRIVATE_THREADS" => p |= Permissions::CREATE_PRIVATE_THREADS,
-                    "USE_EXTERNAL_STICKERS" => p |= Permissions::USE_EXTERNAL_STICKERS,
-                    "SEND_MESSAGES_IN_THREADS" => p |= Permissions::SEND_MESSAGES_IN_THREADS,
-                    "USE_EMBEDDED_ACTIVITIES" => p |= Permissions::USE_EMBEDDED_ACTIVITIES,
-                    "MODERATE_MEMBERS" => p |= Permissions::MODERATE_MEMBERS,
-                    "MANAGE_GUILD_EXPRESSIONS" => p |= Permissions::MANAGE_GUILD_EXPRESSIONS,
-                    unknown => {
+                match parse_permission_name(name) {
+                    Some(perm) => p |= perm,
+                    None => {
                         tracing::warn!(
                             "Unknown permission string '{}' in permission_definitions, ignoring",
-                            unknown
+                            name
                         );
                     }
                 }
@@ -315,31 +567,16 @@ impl ChannelPermissionLevel {
             p
         };
 
-        // First check if we have a custom definition for this level
-        // For voice channels, try voice-specific keys first (e.g., "read_voice")
-        let is_voice = matches!(channel_type, ChannelType::Voice | ChannelType::Stage);
-
-        let level_name = match self {
-            ChannelPermissionLevel::None => "none",
-            ChannelPermissionLevel::Read => "read",
-            ChannelPermissionLevel::ReadWrite => "readwrite",
-            ChannelPermissionLevel::Admin => "admin",
-        };
-
-        // Try voice-specific definition first for voice channels
-        if is_voice {
-            let voice_key = format!("{}_voice", level_name);
-            if let Some(def) = config.permission_definitions.get(&voice_key) {
-                return (parse_perms(&def.allow), parse_perms(&def.deny));
-            }
-        }
-
-        // Then try the standard definition
-        if let Some(def) = config.permission_definitions.get(level_name) {
+        // Use the most specific definition for this channel type
+        if let Some(def) = self
+            .definition_keys(channel_type)
+            .iter()
+            .find_map(|key| config.permission_definitions.get(key))
+        {
             return (parse_perms(&def.allow), parse_perms(&def.deny));
         }
 
-        // Fallback to hardcoded defaults if not defined
+        // Built-in levels fall back to hardcoded defaults if not defined
         match self {
             ChannelPermissionLevel::None => (
                 Permissions::empty(),
@@ -389,6 +626,14 @@ impl ChannelPermissionLevel {
                     | Permissions::MOVE_MEMBERS,
                 Permissions::empty(),
             ),
+            // Refused by config validation and syncs, so only reachable with unvalidated config
+            ChannelPermissionLevel::Custom(name) => {
+                tracing::warn!(
+                    "Permission level '{}' is not defined, granting nothing",
+                    name
+                );
+                (Permissions::empty(), Permissions::empty())
+            }
         }
     }
 }
@@ -445,4 +690,85 @@ mod tests {
         assert_eq!(config.default_channels.len(), 1);
         assert!(config.default_roles[0].is_default_member_role);
     }
+
+    #[test]
+    fn test_custom_level_resolves_channel_variants() {
+        use poise::serenity_prelude::Permissions;
+
+        let config: GlobalStructureConfig = serde_json::from_str(
+            r#"{ "permission_definitions": {
+                "announce": { "allow": ["VIEW_CHANNEL"], "deny": ["SEND_MESSAGES"] },
+                "announce_voice": { "allow": ["CONNECT"] }
+            } }"#,
+        )
+        .unwrap();
+        let level: ChannelPermissionLevel = serde_json::from_str(r#""announce""#).unwrap();
+        assert_eq!(
+            level,
+            ChannelPermissionLevel::Custom("announce".to_string())
+        );
+        assert_eq!(serde_json::to_string(&level).unwrap(), r#""announce""#);
+
+        let text = level.to_permissions(&ChannelType::Text, &config);
+        assert_eq!(
+            text,
+            (Permissions::VIEW_CHANNEL, Permissions::SEND_MESSAGES)
+        );
+        // Stage channels fall back to the voice variant
+        let stage = level.to_permissions(&ChannelType::Stage, &config);
+        assert_eq!(stage, (Permissions::CONNECT, Permissions::empty()));
+
+        assert!(level.is_defined(&config.permission_definitions));
+        assert!(!ChannelPermissionLevel::from("moderator".to_string())
+            .is_defined(&config.permission_definitions));
+        assert!(ChannelPermissionLevel::Read.is_defined(&HashMap::new()));
+    }
+
+    #[test]
+    fn test_matching_level_for_overwrite() {
+        use poise::serenity_prelude::Permissions;
+
+        let config: GlobalStructureConfig = serde_json::from_str(
+            r#"{ "permission_definitions": {
+                "announce": { "allow": ["VIEW_CHANNEL"], "deny": ["SEND_MESSAGES"] },
+                "announce_voice": { "allow": ["CONNECT"] }
+            } }"#,
+        )
+        .unwrap();
+
+        let (allow, deny) =
+            ChannelPermissionLevel::Read.to_permissions(&ChannelType::Text, &config);
+        assert_eq!(
+            ChannelPermissionLevel::matching(allow, deny, &ChannelType::Text, &config),
+            Some(ChannelPermissionLevel::Read)
+        );
+        assert_eq!(
+            ChannelPermissionLevel::matching(
+                Permissions::VIEW_CHANNEL,
+                Permissions::SEND_MESSAGES,
+                &ChannelType::Text,
+                &config
+            ),
+            Some(ChannelPermissionLevel::Custom("announce".to_string()))
+        );
+        // The voice variant maps back to the level it belongs to
+        assert_eq!(
+            ChannelPermissionLevel::matching(
+                Permissions::CONNECT,
+                Permissions::empty(),
+                &ChannelType::Voice,
+                &config
+            ),
+            Some(ChannelPermissionLevel::Custom("announce".to_string()))
+        );
+        assert_eq!(
+            ChannelPermissionLevel::matching(
+                Permissions::MANAGE_ROLES,
+                Permissions::empty(),
+                &ChannelType::Text,
+                &config
+            ),
+            None
+        );
+    }
 }
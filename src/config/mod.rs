@@ -1,17 +1,25 @@
 pub mod category_structure;
+pub mod csv_import;
 pub mod global_permissions;
 pub mod global_roles;
 pub mod global_structure;
 pub mod season;
 pub mod special_members;
 pub mod staging;
+pub mod validation;
 
 pub use category_structure::CategoryStructureConfig;
+pub use csv_import::{parse_users_csv, CsvColumns};
 pub use global_permissions::{GlobalPermissionsConfig, PermissionSet};
 pub use global_roles::{GlobalRolesConfig, RoleDefinition};
 pub use global_structure::{
-    ChannelDefinition, ChannelPermissionLevel, ChannelType, GlobalStructureConfig,
+    ChannelDefinition, ChannelPermissionLevel, ChannelType, ForumTagDefinition,
+    GlobalStructureConfig,
+};
+pub use season::{
+    describe_user_change, diff_season_users, load_users_from_file, returning_users, Season,
+    SeasonConfig, SeasonPhase, SeasonUser,
 };
-pub use season::{load_users_from_file, Season, SeasonConfig, SeasonUser};
 pub use special_members::SpecialMembersConfig;
 pub use staging::{ConfigChange, ConfigChangeType, ConfigDiff, StagedConfig};
+pub use validation::{validate_config, ConfigTree, ValidationIssue, ValidationReport};
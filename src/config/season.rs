@@ -1,6 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::csv_import::CsvColumns;
 use super::global_structure::{ChannelDefinition, ChannelPermissionLevel, ChannelType};
 
 /// Season configuration (loaded from season.json)
@@ -21,15 +23,79 @@ pub struct SeasonConfig {
     #[serde(default)]
     pub member_role: Option<String>,
 
+    /// When the season opens for verification (RFC 3339 or "YYYY-MM-DD", UTC)
+    /// The season scheduler sets `active` to true at this time
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "season_date")]
+    pub starts_at: Option<DateTime<Utc>>,
+
+    /// When the season closes for verification (RFC 3339 or "YYYY-MM-DD", UTC)
+    /// The season scheduler sets `active` to false at this time
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "season_date")]
+    pub ends_at: Option<DateTime<Utc>>,
+
     /// Channel definitions for this season's category
     #[serde(default)]
     pub channels: Vec<ChannelDefinition>,
+
+    /// Column names to read when a users CSV is uploaded for this season
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub csv_columns: Option<CsvColumns>,
 }
 
 fn default_active() -> bool {
     true
 }
 
+/// Where a season is in its scheduled lifetime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonPhase {
+    /// `starts_at` is in the future
+    NotStarted,
+    /// Between `starts_at` and `ends_at` (or no dates set)
+    Running,
+    /// `ends_at` has passed
+    Ended,
+}
+
+/// Serde helpers for season dates
+///
+/// Accepts full RFC 3339 timestamps or plain dates (midnight UTC) and always
+/// writes RFC 3339.
+mod season_date {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(dt) => serializer.serialize_str(&dt.to_rfc3339()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+            return Ok(None);
+        };
+        parse(&raw).map(Some).map_err(serde::de::Error::custom)
+    }
+
+    pub fn parse(raw: &str) -> Result<DateTime<Utc>, String> {
+        if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+            return Ok(dt.with_timezone(&Utc));
+        }
+        NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc())
+            .ok_or_else(|| format!("invalid date '{}', expected YYYY-MM-DD or RFC 3339", raw))
+    }
+}
+
 impl SeasonConfig {
     /// Load from a JSON file
     pub fn load_from_file(path: &str) -> crate::error::Result<Self> {
@@ -44,6 +110,119 @@ impl SeasonConfig {
             source: e,
         })
     }
+
+    /// Whether the season has a start or end date
+    pub fn has_schedule(&self) -> bool {
+        self.starts_at.is_some() || self.ends_at.is_some()
+    }
+
+    /// Get the scheduled phase of the season at the given time
+    pub fn phase_at(&self, now: DateTime<Utc>) -> SeasonPhase {
+        if self.starts_at.is_some_and(|start| now < start) {
+            SeasonPhase::NotStarted
+        } else if self.ends_at.is_some_and(|end| now >= end) {
+            SeasonPhase::Ended
+        } else {
+            SeasonPhase::Running
+        }
+    }
+}
+
+impl SeasonConfig {
+    /// Build the config for a new season from this one
+    ///
+    /// Occurrences of the old season ID in the name, member role and channel
+    /// permission role names are replaced with the new ID (the template
+    /// directory uses `TEMPLATE` as a placeholder). Dates are cleared and the
+    /// new season starts active.
+    pub fn rolled_over(&self, old_id: &str, new_id: &str, name: Option<&str>) -> Self {
+        let old_member_role = self
+            .member_role
+            .clone()
+            .unwrap_or_else(|| format!("Medlem{}", old_id));
+        let new_member_role = self
+            .member_role
+            .as_ref()
+            .map(|role| replace_season_id(role, old_id, new_id))
+            .filter(|role| role != &old_member_role);
+
+        let name = match name.map(str::trim).filter(|n| !n.is_empty()) {
+            Some(name) => name.to_string(),
+            None => {
+                let renamed = replace_season_id(&self.name, old_id, new_id);
+                if renamed == self.name { new_id.to_string() } else { renamed }
+            }
+        };
+
+        let effective_new_role = new_member_role
+            .clone()
+            .unwrap_or_else(|| format!("Medlem{}", new_id));
+
+        Self {
+            name,
+            active: true,
+            member_role: new_member_role,
+            starts_at: None,
+            ends_at: None,
+            channels: self
+                .channels
+                .iter()
+                .map(|c| rename_channel_roles(c, old_id, new_id, &old_member_role, &effective_new_role))
+                .collect(),
+            csv_columns: self.csv_columns.clone(),
+        }
+    }
+}
+
+/// Replace a season ID inside a name, including the template placeholder
+pub(super) fn replace_season_id(value: &str, old_id: &str, new_id: &str) -> String {
+    if old_id.eq_ignore_ascii_case("template") {
+        value.replace("TEMPLATE", new_id).replace("template", new_id)
+    } else {
+        value.replace(old_id, new_id)
+    }
+}
+
+/// Rewrite season-specific role names (and category names, which must be
+/// unique per season) in a channel definition and its children
+pub(super) fn rename_channel_roles(
+    channel: &ChannelDefinition,
+    old_id: &str,
+    new_id: &str,
+    old_member_role: &str,
+    new_member_role: &str,
+) -> ChannelDefinition {
+    let role_permissions = channel
+        .role_permissions
+        .iter()
+        .map(|(role, level)| {
+            let role = if role == old_member_role {
+                new_member_role.to_string()
+            } else {
+                replace_season_id(role, old_id, new_id)
+            };
+            (role, level.clone())
+        })
+        .collect();
+
+    let name = if channel.channel_type == ChannelType::Category {
+        replace_season_id(&channel.name, old_id, new_id)
+    } else {
+        channel.name.clone()
+    };
+
+    // Renames recorded for the old season's channels don't carry over
+    ChannelDefinition {
+        name,
+        previous_names: vec![],
+        role_permissions,
+        children: channel
+            .children
+            .iter()
+            .map(|c| rename_channel_roles(c, old_id, new_id, old_member_role, new_member_role))
+            .collect(),
+        ..channel.clone()
+    }
 }
 
 impl Default for SeasonConfig {
@@ -52,6 +231,8 @@ impl Default for SeasonConfig {
             name: "New Season".to_string(),
             active: true,
             member_role: None,
+            starts_at: None,
+            ends_at: None,
             channels: vec![ChannelDefinition {
                 name: "general".to_string(),
                 channel_type: ChannelType::Text,
@@ -62,7 +243,9 @@ impl Default for SeasonConfig {
                     perms
                 },
                 children: vec![],
+                ..Default::default()
             }],
+            csv_columns: None,
         }
     }
 }
@@ -84,6 +267,105 @@ pub struct SeasonUser {
     pub email: Option<String>,
 }
 
+impl SeasonUser {
+    /// Whether two entries from different seasons refer to the same person
+    /// Matches on verification ID, then email, then name (case-insensitive)
+    pub fn is_same_person(&self, other: &SeasonUser) -> bool {
+        if self.id == other.id {
+            return true;
+        }
+        if let (Some(a), Some(b)) = (&self.email, &other.email) {
+            return a.trim().eq_ignore_ascii_case(b.trim());
+        }
+        self.name.trim().to_lowercase() == other.name.trim().to_lowercase()
+    }
+}
+
+/// Find the users of `current` that also appear in `previous`
+pub fn returning_users<'a>(previous: &[SeasonUser], current: &'a [SeasonUser]) -> Vec<&'a SeasonUser> {
+    current
+        .iter()
+        .filter(|user| previous.iter().any(|p| p.is_same_person(user)))
+        .collect()
+}
+
+/// Per-user differences between two versions of a season's users.json
+#[derive(Debug, Default)]
+pub struct SeasonUsersDiff<'a> {
+    pub added: Vec<&'a SeasonUser>,
+    pub removed: Vec<&'a SeasonUser>,
+    /// (old, new) entries for the same person where the name, email or ID changed
+    pub changed: Vec<(&'a SeasonUser, &'a SeasonUser)>,
+}
+
+impl SeasonUsersDiff<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Short count summary, e.g. "+2 added, -1 removed, ~3 changed"
+    pub fn summary(&self) -> String {
+        format!(
+            "+{} added, -{} removed, ~{} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+/// Compare the users of a season before and after an upload
+///
+/// Entries are paired by verification ID first. Leftover entries are then
+/// paired as the same person with a new verification ID (see `is_same_person`),
+/// and anything still unpaired is an addition or removal.
+pub fn diff_season_users<'a>(current: &'a [SeasonUser], staged: &'a [SeasonUser]) -> SeasonUsersDiff<'a> {
+    let mut diff = SeasonUsersDiff::default();
+    let mut unmatched_current: Vec<&SeasonUser> = Vec::new();
+    let mut unmatched_staged: Vec<&SeasonUser> = staged.iter().collect();
+
+    for old in current {
+        match unmatched_staged.iter().position(|new| new.id == old.id) {
+            Some(i) => {
+                let new = unmatched_staged.remove(i);
+                if old.name != new.name || old.email != new.email {
+                    diff.changed.push((old, new));
+                }
+            }
+            None => unmatched_current.push(old),
+        }
+    }
+
+    for old in unmatched_current {
+        match unmatched_staged.iter().position(|new| new.is_same_person(old)) {
+            Some(i) => diff.changed.push((old, unmatched_staged.remove(i))),
+            None => diff.removed.push(old),
+        }
+    }
+
+    diff.added = unmatched_staged;
+    diff
+}
+
+/// Describe what changed between two entries for the same person
+pub fn describe_user_change(old: &SeasonUser, new: &SeasonUser) -> String {
+    let mut parts = Vec::new();
+    if old.name != new.name {
+        parts.push(format!("name '{}' → '{}'", old.name, new.name));
+    }
+    if old.email != new.email {
+        parts.push(format!(
+            "email {} → {}",
+            old.email.as_deref().unwrap_or("(none)"),
+            new.email.as_deref().unwrap_or("(none)")
+        ));
+    }
+    if old.id != new.id {
+        parts.push(format!("verification ID {} → {}", old.id, new.id));
+    }
+    parts.join(", ")
+}
+
 /// Load users from a JSON file (simple array format)
 pub fn load_users_from_file(path: &str) -> crate::error::Result<Vec<SeasonUser>> {
     let content =
@@ -131,6 +413,12 @@ impl Season {
         self.config.active
     }
 
+    /// Check if the season accepts verification at the given time
+    /// Requires the season to be active and within its start/end dates
+    pub fn accepts_verification_at(&self, now: DateTime<Utc>) -> bool {
+        self.is_active() && self.config.phase_at(now) == SeasonPhase::Running
+    }
+
     /// Get channels
     pub fn channels(&self) -> &[ChannelDefinition] {
         &self.config.channels
@@ -190,4 +478,134 @@ mod tests {
         assert_eq!(users[0].name, "Test User");
         assert_eq!(users[0].id, "test-uuid-123");
     }
+
+    #[test]
+    fn test_rolled_over_renames_season_roles() {
+        let json = r#"{
+            "name": "2025E Efterår",
+            "member_role": "Medlem2025E",
+            "starts_at": "2025-08-25",
+            "channels": [
+                {
+                    "name": "general",
+                    "type": "text",
+                    "role_permissions": { "Medlem2025E": "readwrite", "Admin": "admin", "Tutor2025E": "read" }
+                }
+            ]
+        }"#;
+        let config: SeasonConfig = serde_json::from_str(json).unwrap();
+
+        let next = config.rolled_over("2025E", "2026F", None);
+        assert_eq!(next.name, "2026F Efterår");
+        assert_eq!(next.member_role.as_deref(), Some("Medlem2026F"));
+        assert!(next.starts_at.is_none());
+        let perms = &next.channels[0].role_permissions;
+        assert!(perms.contains_key("Medlem2026F"));
+        assert!(perms.contains_key("Tutor2026F"));
+        assert!(perms.contains_key("Admin"));
+        assert!(!perms.contains_key("Medlem2025E"));
+
+        // Template placeholder with the default member role
+        let template: SeasonConfig = serde_json::from_str(
+            r#"{ "name": "TEMPLATE", "channels": [{ "name": "general", "type": "text", "role_permissions": { "Medlemtemplate": "readwrite" } }] }"#,
+        )
+        .unwrap();
+        let next = template.rolled_over("template", "2026F", Some("Forår 2026"));
+        assert_eq!(next.name, "Forår 2026");
+        assert!(next.member_role.is_none());
+        assert!(next.channels[0].role_permissions.contains_key("Medlem2026F"));
+    }
+
+    #[test]
+    fn test_returning_users() {
+        let user = |name: &str, id: &str, email: Option<&str>| SeasonUser {
+            name: name.to_string(),
+            id: id.to_string(),
+            email: email.map(String::from),
+        };
+        let previous = vec![
+            user("Anna Jensen", "a1", Some("anna@example.com")),
+            user("Bo Hansen", "b1", None),
+        ];
+        let current = vec![
+            user("Anna J.", "a2", Some("ANNA@example.com")),
+            user("bo hansen", "b2", None),
+            user("Carl Nielsen", "c2", None),
+        ];
+
+        let names: Vec<_> = returning_users(&previous, &current)
+            .iter()
+            .map(|u| u.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Anna J.", "bo hansen"]);
+    }
+
+    #[test]
+    fn test_diff_season_users() {
+        let user = |name: &str, id: &str, email: Option<&str>| SeasonUser {
+            name: name.to_string(),
+            id: id.to_string(),
+            email: email.map(String::from),
+        };
+        let current = vec![
+            user("Anna Jensen", "a1", Some("anna@example.com")),
+            user("Bo Hansen", "b1", None),
+            user("Carl Nielsen", "c1", None),
+            user("Dorte Berg", "d1", None),
+        ];
+        let staged = vec![
+            user("Anna Jensen", "a1", Some("anna@example.com")),
+            user("Bo Hansen", "b2", None),
+            user("Carl Nielsen", "c1", Some("carl@example.com")),
+            user("Eva Holm", "e1", None),
+        ];
+
+        let diff = diff_season_users(&current, &staged);
+        assert_eq!(diff.summary(), "+1 added, -1 removed, ~2 changed");
+        assert_eq!(diff.added[0].name, "Eva Holm");
+        assert_eq!(diff.removed[0].name, "Dorte Berg");
+
+        let changes: Vec<_> = diff
+            .changed
+            .iter()
+            .map(|(old, new)| describe_user_change(old, new))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                "email (none) → carl@example.com",
+                "verification ID b1 → b2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_season_dates_and_phase() {
+        let json = r#"{
+            "name": "2025 Efterår",
+            "starts_at": "2025-08-25",
+            "ends_at": "2026-01-31T23:00:00+01:00"
+        }"#;
+
+        let config: SeasonConfig = serde_json::from_str(json).unwrap();
+        assert!(config.has_schedule());
+        assert_eq!(config.starts_at.unwrap().to_rfc3339(), "2025-08-25T00:00:00+00:00");
+        assert_eq!(config.ends_at.unwrap().to_rfc3339(), "2026-01-31T22:00:00+00:00");
+
+        let at = |s: &str| season_date::parse(s).unwrap();
+        assert_eq!(config.phase_at(at("2025-08-24T23:59:59Z")), SeasonPhase::NotStarted);
+        assert_eq!(config.phase_at(at("2025-08-25")), SeasonPhase::Running);
+        assert_eq!(config.phase_at(at("2026-01-31T22:00:00Z")), SeasonPhase::Ended);
+
+        let season = Season::new("2025E".to_string(), config.clone(), vec![]);
+        assert!(season.accepts_verification_at(at("2025-10-01")));
+        assert!(!season.accepts_verification_at(at("2026-02-01")));
+
+        // Dates round-trip and are omitted when unset
+        let reparsed: SeasonConfig =
+            serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(reparsed.ends_at, config.ends_at);
+        let plain = serde_json::to_string(&SeasonConfig::default()).unwrap();
+        assert!(!plain.contains("starts_at"));
+    }
 }
@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::{SeasonUser, SpecialMembersConfig};
+use super::{
+    CategoryStructureConfig, GlobalPermissionsConfig, GlobalRolesConfig, GlobalStructureConfig,
+    SeasonConfig, SeasonUser, SpecialMembersConfig,
+};
 
 /// Staged users for a season
 #[derive(Debug, Clone)]
@@ -20,6 +23,24 @@ pub struct StagedConfig {
     /// Staged special members config (assignments.json)
     pub special_members: Option<SpecialMembersConfig>,
 
+    /// Staged season configs (season_id -> season.json)
+    pub season_configs: HashMap<String, SeasonConfig>,
+
+    /// Staged role definitions (global/roles.json)
+    pub global_roles: Option<GlobalRolesConfig>,
+
+    /// Staged permission definitions (global/permissions.json)
+    pub global_permissions: Option<GlobalPermissionsConfig>,
+
+    /// Staged default layout (global/structure.json)
+    pub global_structure: Option<GlobalStructureConfig>,
+
+    /// Staged layout overrides (season_id -> category.json)
+    pub category_structures: HashMap<String, CategoryStructureConfig>,
+
+    /// Staged season role files (season_id -> roles.json), kept as plain JSON
+    pub season_roles: HashMap<String, serde_json::Value>,
+
     /// When the config was staged
     pub staged_at: u64,
 
@@ -37,7 +58,14 @@ impl StagedConfig {
 
     /// Check if there's anything staged
     pub fn is_empty(&self) -> bool {
-        self.seasons.is_empty() && self.special_members.is_none()
+        self.seasons.is_empty()
+            && self.special_members.is_none()
+            && self.season_configs.is_empty()
+            && self.global_roles.is_none()
+            && self.global_permissions.is_none()
+            && self.global_structure.is_none()
+            && self.category_structures.is_empty()
+            && self.season_roles.is_empty()
     }
 
     /// Stage users for a season
@@ -62,10 +90,81 @@ impl StagedConfig {
         self.staged_by = staged_by;
     }
 
+    /// Stage a season's season.json
+    pub fn stage_season_config(
+        &mut self,
+        season_id: String,
+        config: SeasonConfig,
+        staged_by: Option<String>,
+    ) {
+        self.season_configs.insert(season_id, config);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
+    /// Stage global role definitions
+    pub fn stage_global_roles(&mut self, config: GlobalRolesConfig, staged_by: Option<String>) {
+        self.global_roles = Some(config);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
+    /// Stage global permission definitions
+    pub fn stage_global_permissions(
+        &mut self,
+        config: GlobalPermissionsConfig,
+        staged_by: Option<String>,
+    ) {
+        self.global_permissions = Some(config);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
+    /// Stage the default layout
+    pub fn stage_global_structure(
+        &mut self,
+        config: GlobalStructureConfig,
+        staged_by: Option<String>,
+    ) {
+        self.global_structure = Some(config);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
+    /// Stage a season's category.json
+    pub fn stage_category_structure(
+        &mut self,
+        season_id: String,
+        config: CategoryStructureConfig,
+        staged_by: Option<String>,
+    ) {
+        self.category_structures.insert(season_id, config);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
+    /// Stage a season's roles.json
+    pub fn stage_season_roles(
+        &mut self,
+        season_id: String,
+        content: serde_json::Value,
+        staged_by: Option<String>,
+    ) {
+        self.season_roles.insert(season_id, content);
+        self.staged_at = current_timestamp();
+        self.staged_by = staged_by;
+    }
+
     /// Clear all staged configs
     pub fn clear(&mut self) {
         self.seasons.clear();
         self.special_members = None;
+        self.season_configs.clear();
+        self.global_roles = None;
+        self.global_permissions = None;
+        self.global_structure = None;
+        self.category_structures.clear();
+        self.season_roles.clear();
         self.staged_at = 0;
         self.staged_by = None;
     }
@@ -82,10 +181,40 @@ impl StagedConfig {
             parts.push(format!("Seasons: {}", season_info.join(", ")));
         }
 
+        if !self.season_configs.is_empty() {
+            let mut ids: Vec<_> = self.season_configs.keys().map(String::as_str).collect();
+            ids.sort();
+            parts.push(format!("Season configs: {}", ids.join(", ")));
+        }
+
         if self.special_members.is_some() {
             parts.push("Special Members (assignments.json)".to_string());
         }
 
+        if self.global_roles.is_some() {
+            parts.push("Roles (roles.json)".to_string());
+        }
+
+        if self.global_permissions.is_some() {
+            parts.push("Permissions (permissions.json)".to_string());
+        }
+
+        if self.global_structure.is_some() {
+            parts.push("Default layout (structure.json)".to_string());
+        }
+
+        if !self.category_structures.is_empty() {
+            let mut ids: Vec<_> = self.category_structures.keys().map(String::as_str).collect();
+            ids.sort();
+            parts.push(format!("Category overrides: {}", ids.join(", ")));
+        }
+
+        if !self.season_roles.is_empty() {
+            let mut ids: Vec<_> = self.season_roles.keys().map(String::as_str).collect();
+            ids.sort();
+            parts.push(format!("Season roles: {}", ids.join(", ")));
+        }
+
         if parts.is_empty() {
             "Nothing staged".to_string()
         } else {
@@ -100,6 +229,9 @@ pub struct ConfigDiff {
     pub additions: Vec<ConfigChange>,
     pub modifications: Vec<ConfigChange>,
     pub deletions: Vec<ConfigChange>,
+    /// Changes that need a closer look before committing
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 impl ConfigDiff {
@@ -111,6 +243,10 @@ impl ConfigDiff {
         self.additions.is_empty() && self.modifications.is_empty() && self.deletions.is_empty()
     }
 
+    pub fn add_warning(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+
     pub fn add_addition(&mut self, change: ConfigChange) {
         self.additions.push(change);
     }
@@ -127,6 +263,14 @@ impl ConfigDiff {
     pub fn format_for_display(&self) -> String {
         let mut output = String::new();
 
+        if !self.warnings.is_empty() {
+            output.push_str("**Warnings:**\n");
+            for warning in &self.warnings {
+                output.push_str(&format!("⚠️ {}\n", warning));
+            }
+            output.push('\n');
+        }
+
         if !self.additions.is_empty() {
             output.push_str("**Additions:**\n");
             for change in &self.additions {
@@ -165,6 +309,27 @@ impl ConfigDiff {
             output
         }
     }
+
+    /// Format the diff for display, cut at a line boundary to fit in `max_len` bytes
+    pub fn format_truncated(&self, max_len: usize) -> String {
+        let full = self.format_for_display();
+        if full.len() <= max_len {
+            return full;
+        }
+
+        let lines: Vec<&str> = full.lines().collect();
+        let mut output = String::new();
+        for (i, line) in lines.iter().enumerate() {
+            // Leave room for the "more lines" note
+            if output.len() + line.len() + 40 > max_len {
+                output.push_str(&format!("… and {} more line(s)\n", lines.len() - i));
+                break;
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+        output
+    }
 }
 
 /// A single configuration change
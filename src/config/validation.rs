@@ -0,0 +1,457 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use super::global_structure::parse_permission_name;
+use super::{
+    CategoryStructureConfig, ChannelDefinition, ChannelType, GlobalPermissionsConfig,
+    GlobalRolesConfig, GlobalStructureConfig, PermissionSet, Season, SpecialMembersConfig,
+};
+
+/// A single problem found in the config tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// File relative to the data directory (e.g. "seasons/2025E/season.json")
+    pub file: String,
+    /// JSON path inside the file (e.g. `$.channels[0].role_permissions["Medlem"]`)
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    pub fn new(
+        file: impl Into<String>,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: file.into(),
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.file, self.path, self.message)
+    }
+}
+
+/// All issues found by a validation run
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Issues in this report that are not in `baseline`
+    pub fn new_since(&self, baseline: &ValidationReport) -> Vec<ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| !baseline.issues.contains(issue))
+            .cloned()
+            .collect()
+    }
+
+    /// Format the report as one issue per line
+    pub fn format(&self) -> String {
+        if self.issues.is_empty() {
+            return "No problems found.".to_string();
+        }
+        self.issues
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The parts of the config tree the validator looks at
+pub struct ConfigTree<'a> {
+    pub global_roles: Option<&'a GlobalRolesConfig>,
+    pub global_permissions: Option<&'a GlobalPermissionsConfig>,
+    pub special_members: Option<&'a SpecialMembersConfig>,
+    pub global_structure: Option<&'a GlobalStructureConfig>,
+    pub category_structures: Vec<&'a CategoryStructureConfig>,
+    pub seasons: Vec<&'a Season>,
+}
+
+/// Cross-reference the config tree
+///
+/// Checks that every role referenced by assignments, member roles and channel
+/// permissions is defined in global/roles.json, that permission strings are
+/// known Discord permissions, that channel permission levels are built in or
+/// defined in global/permissions.json, and that role, channel and category
+/// names are unique where the bot tracks them by name.
+pub fn validate_config(tree: &ConfigTree<'_>) -> ValidationReport {
+    let mut issues = Vec::new();
+    let mut defined_roles = HashSet::new();
+
+    if let Some(roles) = tree.global_roles {
+        let file = "global/roles.json";
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        for (i, role) in roles.roles.iter().enumerate() {
+            defined_roles.insert(role.name.as_str());
+            if let Some(first) = seen.insert(role.name.as_str(), i) {
+                issues.push(ValidationIssue::new(
+                    file,
+                    format!("$.roles[{}].name", i),
+                    format!(
+                        "duplicate role name '{}' (also at $.roles[{}])",
+                        role.name, first
+                    ),
+                ));
+            }
+            for (j, permission) in role.permissions.iter().enumerate() {
+                if parse_permission_name(permission).is_none() {
+                    issues.push(ValidationIssue::new(
+                        file,
+                        format!("$.roles[{}].permissions[{}]", i, j),
+                        format!("unknown permission '{}'", permission),
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some(permissions) = tree.global_permissions {
+        let file = "global/permissions.json";
+        let definitions: BTreeMap<_, _> = permissions.definitions.iter().collect();
+        for (level, set) in definitions {
+            for (field, names) in [("allow", &set.allow), ("deny", &set.deny)] {
+                for (j, permission) in names.iter().enumerate() {
+                    if parse_permission_name(permission).is_none() {
+                        issues.push(ValidationIssue::new(
+                            file,
+                            format!("$.definitions[\"{}\"].{}[{}]", level, field, j),
+                            format!("unknown permission '{}'", permission),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    // Named permission levels channels may use besides the built-in ones
+    let mut levels: HashMap<String, PermissionSet> = tree
+        .global_structure
+        .map(|s| s.permission_definitions.clone())
+        .unwrap_or_default();
+    if let Some(permissions) = tree.global_permissions {
+        levels.extend(permissions.definitions.clone());
+    }
+
+    if let Some(structure) = tree.global_structure {
+        validate_levels(
+            "global/structure.json",
+            "$.default_channels",
+            &structure.default_channels,
+            &levels,
+            &mut issues,
+        );
+    }
+
+    let mut categories_by_season = tree.category_structures.clone();
+    categories_by_season.sort_by(|a, b| a.season_id.cmp(&b.season_id));
+    for category in categories_by_season {
+        let file = format!("seasons/{}/category.json", category.season_id);
+        for (path, channels) in [
+            ("$.channels", &category.channels),
+            ("$.channel_overrides", &category.channel_overrides),
+            ("$.additional_channels", &category.additional_channels),
+        ] {
+            validate_levels(&file, path, channels, &levels, &mut issues);
+        }
+    }
+
+    let undefined_role =
+        |role: &str| -> String { format!("role '{}' is not defined in global/roles.json", role) };
+
+    if let Some(special_members) = tree.special_members {
+        let assigned: BTreeMap<_, _> = special_members.discord_usernames_by_role.iter().collect();
+        for role in assigned.keys() {
+            if !defined_roles.contains(role.as_str()) {
+                issues.push(ValidationIssue::new(
+                    "global/assignments.json",
+                    format!("$.discord_usernames_by_role[\"{}\"]", role),
+                    undefined_role(role),
+                ));
+            }
+        }
+    }
+
+    let mut seasons = tree.seasons.clone();
+    seasons.sort_by(|a, b| a.season_id.cmp(&b.season_id));
+
+    let mut categories: HashMap<&str, &str> = HashMap::new();
+    for season in seasons {
+        let file = format!("seasons/{}/season.json", season.season_id);
+
+        if let Some(other) = categories.insert(season.name(), &season.season_id) {
+            issues.push(ValidationIssue::new(
+                &file,
+                "$.name",
+                format!(
+                    "season name '{}' is also used by season '{}'; both would share one category",
+                    season.name(),
+                    other
+                ),
+            ));
+        }
+
+        for (i, category) in season.config.channels.iter().enumerate() {
+            if category.channel_type != ChannelType::Category {
+                continue;
+            }
+            if let Some(other) = categories.insert(&category.name, &season.season_id) {
+                issues.push(ValidationIssue::new(
+                    &file,
+                    format!("$.channels[{}].name", i),
+                    format!(
+                        "category '{}' is also used by season '{}'",
+                        category.name, other
+                    ),
+                ));
+            }
+        }
+
+        let member_role = season.member_role();
+        if !defined_roles.contains(member_role.as_str()) {
+            let message = if season.config.member_role.is_some() {
+                format!("member {}", undefined_role(&member_role))
+            } else {
+                format!(
+                    "default member {} (set member_role to override)",
+                    undefined_role(&member_role)
+                )
+            };
+            issues.push(ValidationIssue::new(&file, "$.member_role", message));
+        }
+
+        let mut seen_channels: HashMap<&str, String> = HashMap::new();
+        validate_channels(
+            &file,
+            "$.channels",
+            &season.config.channels,
+            &defined_roles,
+            &mut seen_channels,
+            &mut issues,
+        );
+        validate_levels(
+            &file,
+            "$.channels",
+            &season.config.channels,
+            &levels,
+            &mut issues,
+        );
+    }
+
+    ValidationReport { issues }
+}
+
+/// Check role references and duplicate names in a list of channels (recursing into children)
+///
+/// Names must be unique among the loose channels and within each category.
+fn validate_channels<'a>(
+    file: &str,
+    path: &str,
+    channels: &'a [ChannelDefinition],
+    defined_roles: &HashSet<&str>,
+    seen: &mut HashMap<&'a str, String>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for (i, channel) in channels.iter().enumerate() {
+        let channel_path = format!("{}[{}]", path, i);
+
+        if let Some(first) = seen.get(channel.name.as_str()) {
+            issues.push(ValidationIssue::new(
+                file,
+                format!("{}.name", channel_path),
+                format!(
+                    "duplicate channel name '{}' (also at {})",
+                    channel.name, first
+                ),
+            ));
+        } else {
+            seen.insert(&channel.name, channel_path.clone());
+        }
+
+        for (field, message) in channel.attribute_problems() {
+            issues.push(ValidationIssue::new(
+                file,
+                format!("{}.{}", channel_path, field),
+                message,
+            ));
+        }
+
+        let mut roles: Vec<&String> = channel.role_permissions.keys().collect();
+        roles.sort();
+        for role in roles {
+            if !defined_roles.contains(role.as_str()) {
+                issues.push(ValidationIssue::new(
+                    file,
+                    format!("{}.role_permissions[\"{}\"]", channel_path, role),
+                    format!("role '{}' is not defined in global/roles.json", role),
+                ));
+            }
+        }
+
+        if !channel.children.is_empty() && channel.channel_type != ChannelType::Category {
+            issues.push(ValidationIssue::new(
+                file,
+                format!("{}.children", channel_path),
+                "only channels of type 'category' can have children",
+            ));
+        }
+        if channel.channel_type == ChannelType::Category && path.ends_with(".children") {
+            issues.push(ValidationIssue::new(
+                file,
+                format!("{}.type", channel_path),
+                "categories cannot be nested inside other categories",
+            ));
+        }
+
+        // Each category is its own Discord parent, so names only clash within it
+        let mut category_seen = HashMap::new();
+        let children_seen = if channel.channel_type == ChannelType::Category {
+            &mut category_seen
+        } else {
+            &mut *seen
+        };
+        validate_channels(
+            file,
+            &format!("{}.children", channel_path),
+            &channel.children,
+            defined_roles,
+            children_seen,
+            issues,
+        );
+    }
+}
+
+/// Check that every permission level in a list of channels can be resolved (recursing into children)
+fn validate_levels(
+    file: &str,
+    path: &str,
+    channels: &[ChannelDefinition],
+    levels: &HashMap<String, PermissionSet>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for (i, channel) in channels.iter().enumerate() {
+        let channel_path = format!("{}[{}]", path, i);
+
+        let mut permissions: Vec<_> = channel.role_permissions.iter().collect();
+        permissions.sort_by(|a, b| a.0.cmp(b.0));
+        for (role, level) in permissions {
+            if !level.is_defined(levels) {
+                issues.push(ValidationIssue::new(
+                    file,
+                    format!("{}.role_permissions[\"{}\"]", channel_path, role),
+                    format!(
+                        "unknown permission level '{}' (define it in global/permissions.json)",
+                        level.name()
+                    ),
+                ));
+            }
+        }
+
+        validate_levels(
+            file,
+            &format!("{}.children", channel_path),
+            &channel.children,
+            levels,
+            issues,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SeasonConfig;
+
+    #[test]
+    fn test_reports_dangling_references_with_paths() {
+        let roles: GlobalRolesConfig = serde_json::from_str(
+            r#"{ "roles": [
+                { "name": "Medlem2025E", "permissions": ["SEND_MESSAGES", "FLY"] },
+                { "name": "Admin" }
+            ] }"#,
+        )
+        .unwrap();
+        let special_members: SpecialMembersConfig = serde_json::from_str(
+            r#"{ "discord_usernames_by_role": { "Admin": ["alice"], "Bestyrelse": ["bob"] } }"#,
+        )
+        .unwrap();
+        let config: SeasonConfig = serde_json::from_str(
+            r#"{ "name": "2025E", "channels": [
+                { "name": "general", "type": "text", "role_permissions": { "Medlem2025E": "readwrite", "Tutor": "read" } },
+                { "name": "general", "type": "voice" },
+                { "name": "news", "type": "news", "role_permissions": { "Medlem2025E": "announce" } }
+            ] }"#,
+        )
+        .unwrap();
+        let season = Season::new("2025E".to_string(), config, vec![]);
+
+        let report = validate_config(&ConfigTree {
+            global_roles: Some(&roles),
+            global_permissions: None,
+            special_members: Some(&special_members),
+            global_structure: None,
+            category_structures: vec![],
+            seasons: vec![&season],
+        });
+
+        let lines: Vec<String> = report.issues.iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "global/roles.json $.roles[0].permissions[1]: unknown permission 'FLY'",
+                "global/assignments.json $.discord_usernames_by_role[\"Bestyrelse\"]: role 'Bestyrelse' is not defined in global/roles.json",
+                "seasons/2025E/season.json $.channels[0].role_permissions[\"Tutor\"]: role 'Tutor' is not defined in global/roles.json",
+                "seasons/2025E/season.json $.channels[1].name: duplicate channel name 'general' (also at $.channels[0])",
+                "seasons/2025E/season.json $.channels[2].role_permissions[\"Medlem2025E\"]: unknown permission level 'announce' (define it in global/permissions.json)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_channel_names_are_unique_per_category() {
+        let config: SeasonConfig = serde_json::from_str(
+            r#"{ "name": "2025E", "channels": [
+                { "name": "general", "type": "text" },
+                { "name": "2025E – Choir", "type": "category", "children": [{ "name": "general", "type": "text" }] },
+                { "name": "2025E – Board", "type": "category", "children": [
+                    { "name": "general", "type": "text" },
+                    { "name": "general", "type": "voice" }
+                ] }
+            ] }"#,
+        )
+        .unwrap();
+        let season = Season::new("2025E".to_string(), config, vec![]);
+
+        let report = validate_config(&ConfigTree {
+            global_roles: None,
+            global_permissions: None,
+            special_members: None,
+            global_structure: None,
+            category_structures: vec![],
+            seasons: vec![&season],
+        });
+
+        let duplicates: Vec<String> = report
+            .issues
+            .iter()
+            .filter(|i| i.message.starts_with("duplicate channel name"))
+            .map(|i| i.to_string())
+            .collect();
+        assert_eq!(
+            duplicates,
+            vec!["seasons/2025E/season.json $.channels[2].children[1].name: duplicate channel name 'general' (also at $.channels[2].children[0])"]
+        );
+    }
+}
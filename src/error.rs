@@ -55,6 +55,12 @@ pub enum BotError {
     #[error("Verification pending for user: {discord_id}")]
     VerificationPending { discord_id: String },
 
+    #[error("User is not verified: {discord_id}")]
+    NotVerified { discord_id: String },
+
+    #[error("User is not revoked: {discord_id}")]
+    NotRevoked { discord_id: String },
+
     // Discord errors
     #[error("Discord API error: {message}")]
     Discord { message: String },
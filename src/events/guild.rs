@@ -1,6 +1,7 @@
 use poise::serenity_prelude as serenity;
 use tracing::{debug, error, info};
 
+use crate::state::VerificationStatus;
 use crate::{Data, Error};
 
 /// Handle when the bot joins a new guild or starts up
@@ -55,9 +56,12 @@ pub async fn handle_guild_create(
 
         /*
         let channel_manager = data.channel_manager.read().await;
-        let category_structures = config_manager.get_all_category_structures();
+        let default_structure = GlobalStructureConfig::default();
+        let global_structure = config_manager
+            .get_global_structure()
+            .unwrap_or(&default_structure);
 
-        for category in category_structures {
+        for category in config_manager.get_all_category_structures() {
             match channel_manager
                 .ensure_structure_exists(&ctx.http, guild.id, global_structure, Some(category))
                 .await
@@ -97,6 +101,15 @@ pub async fn handle_member_add(
     let verification_manager = &data.verification_manager;
 
     if let Some(tracked_user) = verification_manager.get_verified_user(user_id).await {
+        // Revoked or expired users don't get their roles back
+        if tracked_user.verification_status != VerificationStatus::Verified {
+            info!(
+                "Not restoring roles for {} ({}): verification status is {:?}",
+                tracked_user.display_name, user_id, tracked_user.verification_status
+            );
+            return Ok(());
+        }
+
         // User is already verified - apply their roles and nickname
         info!(
             "Returning verified user: {} ({})",
@@ -118,22 +131,8 @@ pub async fn handle_member_add(
             );
         }
 
-        // Get roles to assign based on the seasons the user is verified for
-        let config_manager = data.config_manager.read().await;
-
-        // For each season the user is verified in, get that season's member role
-        let mut roles_to_assign: Vec<String> = Vec::new();
-        for season_id in tracked_user.verification_ids.keys() {
-            if let Some(season) = config_manager.get_season(season_id) {
-                let member_role = season.member_role();
-                if !roles_to_assign.contains(&member_role) {
-                    roles_to_assign.push(member_role);
-                }
-            }
-        }
-
-        // Add special roles
-        roles_to_assign.extend(tracked_user.special_roles.clone());
+        // Season member roles for each season the user is verified in, plus special roles
+        let roles_to_assign = verification_manager.get_roles_for_user(&tracked_user).await;
 
         // Assign roles
         let role_manager = data.role_manager.read().await;
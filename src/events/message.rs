@@ -77,8 +77,16 @@ async fn handle_maintainers_message(
         .handle_message_attachments(&msg.attachments, Some(msg.author.id.to_string()))
         .await;
 
+    // Show what the staged upload would change before anyone commits it
+    let diff = if results.iter().any(|(_, r)| r.is_ok()) {
+        let user_db = data.user_database.read().await;
+        Some(data.config_manager.read().await.get_staged_diff(&user_db))
+    } else {
+        None
+    };
+
     // Format and send response
-    let response = maintainers_manager.format_results(&results);
+    let response = maintainers_manager.format_results(&results, diff.as_ref());
 
     // React to indicate processing
     let emoji = if results.iter().all(|(_, r)| r.is_ok()) {
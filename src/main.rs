@@ -1,5 +1,5 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use dotenv::dotenv;
 use poise::serenity_prelude as serenity;
 use tracing::{error, info, warn};
@@ -19,6 +19,19 @@ struct Args {
     /// Specific guild ID to sync commands to (for testing)
     #[arg(long)]
     guild_id: Option<u64>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Validate the config tree without starting the bot (exits non-zero on problems)
+    Validate {
+        /// Data directory to validate (defaults to DATA_PATH or "data")
+        #[arg(long)]
+        data_path: Option<String>,
+    },
 }
 
 mod commands;
@@ -30,13 +43,14 @@ mod managers;
 mod state;
 mod web;
 
-use commands::{get_config, help, ping, restart, set_config_global, set_config_season, update_category, update_roles};
+use commands::{adopt_category, archive_season, get_config, help, ping, prune, reconcile_members, restart, revoke, set_config_global, set_config_season, unrevoke, update_category, update_roles, verify};
 use events::message::handle_message;
 use events::{handle_guild_create, handle_member_add};
 use managers::{
     check_role_permission_management, create_shared_channel_manager, create_shared_config_manager,
-    create_shared_maintainers_manager, create_shared_role_manager, create_shared_verification_manager,
-    log_role_permission_management_check, run_startup_permission_check, SharedChannelManager,
+    create_shared_drift_detector, create_shared_maintainers_manager, create_shared_role_manager, create_shared_verification_manager,
+    log_role_permission_management_check, run_startup_permission_check, spawn_config_watcher,
+    spawn_drift_detector, spawn_season_scheduler, SharedChannelManager,
     SharedConfigManager, SharedMaintainersManager, SharedRoleManager, SharedVerificationManager,
 };
 use state::{
@@ -110,6 +124,32 @@ async fn clear_all_commands(
     Ok(())
 }
 
+/// Run `eventy validate`: load the config tree offline and print every problem found
+async fn run_validate(data_path: Option<String>) -> Result<()> {
+    let data_path = data_path
+        .or_else(|| std::env::var("DATA_PATH").ok())
+        .unwrap_or_else(|| "data".to_string());
+
+    if !std::path::Path::new(&data_path).is_dir() {
+        anyhow::bail!("Data directory '{}' not found", data_path);
+    }
+
+    let mut config_manager = managers::ConfigManager::new(&data_path);
+    config_manager.load_all().await?;
+
+    let report = config_manager.validate();
+    if report.is_ok() {
+        println!("{}: no problems found", data_path);
+        return Ok(());
+    }
+
+    for issue in &report.issues {
+        println!("{}", issue);
+    }
+    eprintln!("{} problem(s) found in {}", report.issues.len(), data_path);
+    std::process::exit(1);
+}
+
 async fn event_handler(
     ctx: &serenity::Context,
     event: &serenity::FullEvent,
@@ -142,6 +182,10 @@ async fn main() -> Result<()> {
     dotenv().ok();
     let args = Args::parse();
 
+    if let Some(Command::Validate { data_path }) = &args.command {
+        return run_validate(data_path.clone()).await;
+    }
+
     // Create log buffer for web admin panel
     let log_buffer = logging::create_log_buffer(1000);
 
@@ -192,7 +236,7 @@ async fn main() -> Result<()> {
 
     // Load state
     info!("Loading channel state...");
-    let channel_state_path = format!("{}/channel_state.json", state_path);
+    let channel_state_path = state::channel_state_path();
     let channel_state = ChannelState::load(&channel_state_path)
         .await
         .unwrap_or_else(|e| {
@@ -202,7 +246,7 @@ async fn main() -> Result<()> {
     let shared_channel_state = create_shared_channel_state(channel_state);
 
     info!("Loading user database...");
-    let user_db_path = format!("{}/user_database.json", state_path);
+    let user_db_path = state::user_database_path();
     let user_database = UserDatabase::load(&user_db_path).await.unwrap_or_else(|e| {
         warn!("Could not load user database: {}, using empty database", e);
         UserDatabase::new()
@@ -231,6 +275,12 @@ async fn main() -> Result<()> {
         create_shared_verification_manager(shared_user_database.clone(), config_manager.clone());
     let maintainers_manager =
         create_shared_maintainers_manager(config_manager.clone(), channel_manager.clone());
+    let drift_detector = create_shared_drift_detector(
+        config_manager.clone(),
+        role_manager.clone(),
+        channel_manager.clone(),
+        shared_channel_state.clone(),
+    );
 
     // Extract CLI flags for use in setup
     let sync_commands = args.sync_commands;
@@ -261,6 +311,13 @@ async fn main() -> Result<()> {
                 set_config_season(),
                 update_category(),
                 update_roles(),
+                verify(),
+                revoke(),
+                unrevoke(),
+                archive_season(),
+                prune(),
+                adopt_category(),
+                reconcile_members(),
             ],
             event_handler: |ctx, event, framework, data| {
                 Box::pin(event_handler(ctx, event, framework, data))
@@ -324,6 +381,7 @@ async fn main() -> Result<()> {
             let channel_manager = channel_manager.clone();
             let verification_manager = verification_manager.clone();
             let maintainers_manager = maintainers_manager.clone();
+            let drift_detector = drift_detector.clone();
             let log_buffer = log_buffer.clone();
 
             Box::pin(async move {
@@ -408,6 +466,41 @@ async fn main() -> Result<()> {
                     }
                 }
 
+                // Guild used for admin checks and maintainer notices
+                let primary_guild_id = std::env::var("DISCORD_GUILD_ID")
+                    .ok()
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .map(serenity::GuildId::new)
+                    .or_else(|| {
+                        // Default to first guild the bot is in
+                        ready.guilds.first().map(|g| g.id)
+                    });
+
+                // Start the season scheduler (flips seasons by starts_at/ends_at)
+                spawn_season_scheduler(
+                    config_manager.clone(),
+                    maintainers_manager.clone(),
+                    shared_user_database.clone(),
+                    ctx.http.clone(),
+                    primary_guild_id,
+                );
+
+                // Compare Discord with the config every few hours
+                spawn_drift_detector(
+                    drift_detector.clone(),
+                    maintainers_manager.clone(),
+                    ctx.http.clone(),
+                    primary_guild_id,
+                );
+
+                // Reload data/ when files change on disk
+                spawn_config_watcher(
+                    config_manager.clone(),
+                    maintainers_manager.clone(),
+                    ctx.http.clone(),
+                    primary_guild_id,
+                );
+
                 // Start web server for OAuth verification and admin panel if configured
                 if let Some(oauth_state) = web::OAuthState::from_env() {
                     let web_config = web::WebServerConfig::from_env();
@@ -419,19 +512,13 @@ async fn main() -> Result<()> {
                     let web_channel_manager = channel_manager.clone();
                     let web_log_buffer = log_buffer.clone();
                     let web_user_database = shared_user_database.clone();
+                    let web_maintainers_manager = maintainers_manager.clone();
 
                     // Create session store for admin panel
                     let session_store = web::create_session_store();
 
                     // Get guild ID for admin permission checks
-                    let admin_guild_id = std::env::var("DISCORD_GUILD_ID")
-                        .ok()
-                        .and_then(|s| s.parse::<u64>().ok())
-                        .map(serenity::GuildId::new)
-                        .unwrap_or_else(|| {
-                            // Default to first guild the bot is in
-                            ready.guilds.first().map(|g| g.id).unwrap_or(serenity::GuildId::new(0))
-                        });
+                    let admin_guild_id = primary_guild_id.unwrap_or(serenity::GuildId::new(0));
 
                     tokio::spawn(async move {
                         info!("Starting OAuth web server on HTTPS port {}...", web_config.https_port);
@@ -446,6 +533,8 @@ async fn main() -> Result<()> {
                             serenity_http,
                             session_store,
                             web_log_buffer,
+                            drift_detector,
+                            web_maintainers_manager,
                             admin_guild_id,
                         ).await {
                             error!("Web server error: {}", e);
@@ -1,27 +1,33 @@
 use poise::serenity_prelude::{self as serenity, ChannelId, GuildId, Http, Permissions};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use super::config_manager::SharedConfigManager;
 use super::role_manager::SharedRoleManager;
+use super::sync_plan::{
+    overwrite_changes, ChannelTarget, GuildSnapshot, OverwriteSpec, PlanAction, SyncPlan,
+};
+use crate::config::category_structure::{CategoryGroup, MergedStructure};
 use crate::config::{
     CategoryStructureConfig, ChannelDefinition, ChannelPermissionLevel, ChannelType,
-    GlobalStructureConfig,
+    ForumTagDefinition, GlobalStructureConfig, PermissionSet,
 };
-use crate::error::Result;
-use crate::state::{ChannelState, SharedChannelState};
+use crate::error::{BotError, Result};
+use crate::state::channel_state::GuildChannelState;
+use crate::state::{channel_state_path, ChannelState, EntityType, SharedChannelState};
 
 /// Summary of changes made during a structure update
 #[derive(Debug, Default, Clone)]
 pub struct UpdateSummary {
     pub roles_created: Vec<String>,
     pub roles_existing: Vec<String>,
-    pub category_created: Option<String>,
-    pub category_existing: Option<String>,
+    pub categories_created: Vec<String>,
+    pub categories_existing: Vec<String>,
     pub channels_created: Vec<String>,
     pub channels_updated: Vec<String>,
     pub channels_reordered: Vec<String>,
+    pub attributes_changed: Vec<(String, Vec<String>)>, // (channel, attributes)
     pub permissions_applied: Vec<(String, String, String)>, // (channel, role, level)
     pub missing_roles: Vec<String>,
     pub warnings: Vec<String>,
@@ -41,11 +47,11 @@ impl UpdateSummary {
         }
 
         // Category
-        if let Some(cat) = &self.category_created {
-            lines.push(format!("**Category created:** {}", cat));
+        if !self.categories_created.is_empty() {
+            lines.push(format!("**Categories created:** {}", self.categories_created.join(", ")));
         }
-        if let Some(cat) = &self.category_existing {
-            lines.push(format!("**Category verified:** {}", cat));
+        if !self.categories_existing.is_empty() {
+            lines.push(format!("**Categories verified:** {}", self.categories_existing.join(", ")));
         }
 
         // Channels
@@ -59,6 +65,10 @@ impl UpdateSummary {
             lines.push(format!("**Channels reordered:** {}", self.channels_reordered.join(", ")));
         }
 
+        if !self.attributes_changed.is_empty() {
+            lines.push(format!("**Channel settings updated:** {}", self.format_attributes()));
+        }
+
         // Permissions summary
         if !self.permissions_applied.is_empty() {
             lines.push(format!("**Permissions configured:** {} role/channel pairs", self.permissions_applied.len()));
@@ -80,6 +90,134 @@ impl UpdateSummary {
             lines.join("\n")
         }
     }
+
+    /// Changed channel attributes as "channel (attr, attr), ..."
+    pub fn format_attributes(&self) -> String {
+        self.attributes_changed
+            .iter()
+            .map(|(channel, attributes)| format!("{} ({})", channel, attributes.join(", ")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Summary of a season archival
+#[derive(Debug, Default, Clone)]
+pub struct ArchiveSummary {
+    pub categories: Vec<String>,
+    pub member_role: String,
+    pub channels_made_read_only: Vec<String>,
+    pub moved_to_bottom: bool,
+    /// Number of members the season role was removed from (None if not requested)
+    pub members_stripped: Option<usize>,
+    pub season_deactivated: bool,
+    pub warnings: Vec<String>,
+}
+
+impl ArchiveSummary {
+    /// Format the summary as a human-readable string
+    pub fn format(&self) -> String {
+        let mut lines = vec![format!("**Categories archived:** {}", self.categories.join(", "))];
+
+        if self.channels_made_read_only.is_empty() {
+            lines.push(format!(
+                "**Read-only:** no overwrites for {} needed changing",
+                self.member_role
+            ));
+        } else {
+            lines.push(format!(
+                "**Read-only for {}:** {}",
+                self.member_role,
+                self.channels_made_read_only.join(", ")
+            ));
+        }
+        if self.moved_to_bottom {
+            lines.push("**Moved to bottom:** yes".to_string());
+        }
+        if let Some(count) = self.members_stripped {
+            lines.push(format!("**{} removed from:** {} members", self.member_role, count));
+        }
+        if self.season_deactivated {
+            lines.push("**Season set inactive**".to_string());
+        }
+        if !self.warnings.is_empty() {
+            lines.push(format!("\n**Warnings:**\n- {}", self.warnings.join("\n- ")));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Summary of a prune of bot-created entities that are no longer declared
+#[derive(Debug, Default, Clone)]
+pub struct PruneSummary {
+    pub archived: Vec<String>,
+    pub deleted: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl PruneSummary {
+    /// Format the summary as a human-readable string
+    pub fn format(&self) -> String {
+        let mut lines = Vec::new();
+        if !self.archived.is_empty() {
+            lines.push(format!("**Archived:** {}", self.archived.join(", ")));
+        }
+        if !self.deleted.is_empty() {
+            lines.push(format!("**Deleted:** {}", self.deleted.join(", ")));
+        }
+        if lines.is_empty() {
+            lines.push("Nothing was pruned.".to_string());
+        }
+        if !self.warnings.is_empty() {
+            lines.push(format!("\n**Warnings:**\n- {}", self.warnings.join("\n- ")));
+        }
+        lines.join("\n")
+    }
+}
+
+/// An existing category read back into config by `adopt_category`
+#[derive(Debug, Default, Clone)]
+pub struct AdoptedCategory {
+    /// season.json entry for the category, holding its channels as children
+    pub definition: ChannelDefinition,
+    /// Number of channels tracked once the entry is committed
+    pub channels: usize,
+    pub warnings: Vec<String>,
+    /// What `track_adopted_category` records in the channel state
+    tracking: AdoptedTracking,
+}
+
+/// IDs of an adopted category, its channels and the roles they reference
+#[derive(Debug, Default, Clone)]
+struct AdoptedTracking {
+    category_id: String,
+    position: u16,
+    /// (name, ID, type) of each channel
+    channels: Vec<(String, String, String)>,
+    /// (name, ID, color) of each role
+    roles: Vec<(String, String, Option<String>)>,
+}
+
+/// Names of the roles, categories and channels the config declares
+#[derive(Debug, Default)]
+struct DeclaredEntities {
+    roles: HashSet<String>,
+    categories: HashSet<String>,
+    /// (category, channel) pairs
+    channels: HashSet<(String, String)>,
+}
+
+/// One bot-created entity a prune archives or deletes
+#[derive(Debug, Clone)]
+struct PruneStep {
+    kind: EntityType,
+    /// Name (for channels, `channel_key`) the entity is tracked under in the channel state
+    name: String,
+    /// e.g. "channel #old (2024E)"
+    target: String,
+    id: u64,
+    delete: bool,
 }
 
 /// Manages Discord channel and category creation
@@ -184,6 +322,7 @@ impl ChannelManager {
                 &channel.id.to_string(),
                 None,
                 "text",
+                true,
             );
         }
 
@@ -229,7 +368,7 @@ impl ChannelManager {
                 let mut state = self.state.write().await;
                 let guild_name = "";
                 let guild = state.get_guild_mut(&guild_id.to_string(), &guild_name);
-                guild.add_category(name, &channel_id.to_string(), channel.position as u16);
+                guild.add_category(name, &channel_id.to_string(), channel.position as u16, false);
 
                 return Ok(*channel_id);
             }
@@ -248,7 +387,7 @@ impl ChannelManager {
             let mut state = self.state.write().await;
             let guild_name = "";
             let guild = state.get_guild_mut(&guild_id.to_string(), &guild_name);
-            guild.add_category(name, &channel.id.to_string(), 0);
+            guild.add_category(name, &channel.id.to_string(), 0, true);
         }
 
         info!("Created category '{}'", name);
@@ -299,9 +438,20 @@ impl ChannelManager {
                         &channel_id.to_string(),
                         parent_name.as_deref(),
                         &format!("{:?}", channel_def.channel_type),
+                        false,
                     );
                 }
 
+                let (changed, warnings) = self
+                    .reconcile_channel_attributes(http, channel, channel_def, &channels)
+                    .await;
+                if !changed.is_empty() {
+                    info!("Updated {} on channel '{}'", changed.join(", "), channel_def.name);
+                }
+                for warning in warnings {
+                    warn!("{}", warning);
+                }
+
                 // Ensure permissions are up to date
                 let permission_overwrites = self
                     .build_permission_overwrites(
@@ -355,6 +505,17 @@ impl ChannelManager {
 
         let channel = guild_id.create_channel(http, create_channel).await?;
 
+        // Attributes and follows are applied the same way as for existing channels
+        let (changed, warnings) = self
+            .reconcile_channel_attributes(http, &channel, channel_def, &channels)
+            .await;
+        if !changed.is_empty() {
+            info!("Set {} on new channel '{}'", changed.join(", "), channel_def.name);
+        }
+        for warning in warnings {
+            warn!("{}", warning);
+        }
+
         // Update cache
         {
             let mut state = self.state.write().await;
@@ -366,6 +527,7 @@ impl ChannelManager {
                 &channel.id.to_string(),
                 parent_name.as_deref(),
                 &format!("{:?}", channel_def.channel_type),
+                true,
             );
         }
 
@@ -386,10 +548,7 @@ impl ChannelManager {
 
         // Get permission definitions from config
         let config = self.config_manager.read().await;
-        let permission_definitions = config
-            .get_global_permissions()
-            .map(|p| p.definitions.clone())
-            .unwrap_or_default();
+        let permission_definitions = config.get_permission_definitions();
         drop(config);
 
         // Build a GlobalStructureConfig with the loaded permission definitions
@@ -429,6 +588,9 @@ impl ChannelManager {
         global: &GlobalStructureConfig,
         category_override: Option<&CategoryStructureConfig>,
     ) -> Result<UpdateSummary> {
+        let merged = category_override.map(|override_config| override_config.merge_with_global(global));
+        let layout = merged.as_ref().map_or(&global.default_channels, |m| &m.channels);
+        self.check_permission_levels(layout).await?;
         let mut summary = UpdateSummary::default();
 
         // First, ensure all roles from global config exist
@@ -477,19 +639,17 @@ impl ChannelManager {
         drop(role_manager);
 
         // Merge configurations
-        let channels = if let Some(override_config) = category_override {
-            let merged = override_config.merge_with_global(global);
-
+        let channels = if let Some(merged) = merged {
             // Create category first
             info!("Ensuring category '{}' exists...", merged.category_name);
             let (category_id, cat_created) = self
-                .ensure_category_exists_tracked(http, guild_id, &merged.category_name)
+                .ensure_category_exists_tracked(http, guild_id, &merged.category_name, &[])
                 .await?;
 
             if cat_created {
-                summary.category_created = Some(merged.category_name.clone());
+                summary.categories_created.push(merged.category_name.clone());
             } else {
-                summary.category_existing = Some(merged.category_name.clone());
+                summary.categories_existing.push(merged.category_name.clone());
             }
 
             // Create channels within category
@@ -538,21 +698,31 @@ impl ChannelManager {
     }
 
     /// Ensure a category exists, returning whether it was created
+    ///
+    /// A category tracked or found under one of `previous_names` is reused
+    /// and from then on tracked under `name`.
     async fn ensure_category_exists_tracked(
         &self,
         http: &Http,
         guild_id: GuildId,
         name: &str,
+        previous_names: &[String],
     ) -> Result<(ChannelId, bool)> {
         // Check cache
         {
-            let state = self.state.read().await;
-            if let Some(guild) = state.get_guild(&guild_id.to_string()) {
-                if let Some(id_str) = guild.get_category_id(name) {
-                    if let Ok(id) = id_str.parse::<u64>() {
-                        debug!("Category '{}' found in cache", name);
-                        return Ok((ChannelId::new(id), false));
-                    }
+            let mut state = self.state.write().await;
+            let guild = state.get_guild_mut(&guild_id.to_string(), "");
+            let cached = std::iter::once(name)
+                .chain(previous_names.iter().map(String::as_str))
+                .find_map(|n| {
+                    let category = guild.categories.get(n)?;
+                    Some((category.discord_id.clone(), category.position))
+                });
+            if let Some((id_str, position)) = cached {
+                if let Ok(id) = id_str.parse::<u64>() {
+                    debug!("Category '{}' found in cache", name);
+                    guild.add_category(name, &id_str, position, false);
+                    return Ok((ChannelId::new(id), false));
                 }
             }
         }
@@ -564,13 +734,15 @@ impl ChannelManager {
         // Check Discord
         let channels = guild_id.channels(http).await?;
         for (channel_id, channel) in &channels {
-            if channel.kind == serenity::ChannelType::Category && channel.name == name {
+            if channel.kind == serenity::ChannelType::Category
+                && (channel.name == name || previous_names.contains(&channel.name))
+            {
                 // Update cache
                 {
                     let mut state = self.state.write().await;
                     let guild_name = "";
                     let guild = state.get_guild_mut(&guild_id.to_string(), guild_name);
-                    guild.add_category(name, &channel_id.to_string(), channel.position as u16);
+                    guild.add_category(name, &channel_id.to_string(), channel.position as u16, false);
                 }
 
                 // Ensure bot has permission on existing category
@@ -630,7 +802,7 @@ impl ChannelManager {
             let mut state = self.state.write().await;
             let guild_name = "";
             let guild = state.get_guild_mut(&guild_id.to_string(), guild_name);
-            guild.add_category(name, &channel.id.to_string(), 0);
+            guild.add_category(name, &channel.id.to_string(), 0, true);
         }
 
         info!("Created category '{}' with bot permission", name);
@@ -668,9 +840,13 @@ impl ChannelManager {
                         &channel_id.to_string(),
                         parent_name.as_deref(),
                         &format!("{:?}", channel_def.channel_type),
+                        false,
                     );
                 }
 
+                self.reconcile_tracked(http, channel, channel_def, &channels, summary)
+                    .await;
+
                 // Build and apply permissions
                 let permission_overwrites = self
                     .build_permission_overwrites_tracked(
@@ -729,6 +905,8 @@ impl ChannelManager {
         }
 
         let channel = guild_id.create_channel(http, create_channel).await?;
+        self.reconcile_tracked(http, &channel, channel_def, &channels, summary)
+            .await;
 
         // Update cache
         {
@@ -741,6 +919,7 @@ impl ChannelManager {
                 &channel.id.to_string(),
                 parent_name.as_deref(),
                 &format!("{:?}", channel_def.channel_type),
+                true,
             );
         }
 
@@ -766,10 +945,7 @@ impl ChannelManager {
 
         // Get permission definitions from config
         let config = self.config_manager.read().await;
-        let permission_definitions = config
-            .get_global_permissions()
-            .map(|p| p.definitions.clone())
-            .unwrap_or_default();
+        let permission_definitions = config.get_permission_definitions();
         drop(config);
 
         // Build a GlobalStructureConfig with the loaded permission definitions
@@ -814,11 +990,21 @@ impl ChannelManager {
         Ok(overwrites)
     }
 
-    /// Get channel ID by name
-    pub async fn get_channel_id(&self, guild_id: GuildId, name: &str) -> Option<ChannelId> {
+    /// Get channel ID by name within a category (by category name), or
+    /// outside any category if `category` is None
+    pub async fn get_channel_id(
+        &self,
+        guild_id: GuildId,
+        category: Option<&str>,
+        name: &str,
+    ) -> Option<ChannelId> {
         let state: tokio::sync::RwLockReadGuard<'_, ChannelState> = self.state.read().await;
         if let Some(guild) = state.get_guild(&guild_id.to_string()) {
-            if let Some(id_str) = guild.get_channel_id(name) {
+            let parent = match category {
+                Some(category) => Some(guild.get_category_id(category)?),
+                None => None,
+            };
+            if let Some(id_str) = guild.get_channel_id(parent, name) {
                 if let Ok(id) = id_str.parse::<u64>() {
                     return Some(ChannelId::new(id));
                 }
@@ -840,49 +1026,124 @@ impl ChannelManager {
         None
     }
 
-    /// Sync a season's channels to Discord
+    /// Sync a season's whole layout to Discord
     ///
-    /// Creates/updates a category with the given name and syncs all channels within it.
-    /// Automatically denies @everyone access to ensure season isolation.
-    /// Returns an UpdateSummary with details of what was created/updated.
-    pub async fn sync_season_channels(
+    /// Every category of the layout (see `MergedStructure::category_groups`)
+    /// is created, permissioned and filled with its channels. The categories
+    /// are kept next to each other in layout order and recorded in
+    /// ChannelState as one season. @everyone is denied everywhere to keep
+    /// seasons isolated. Archived categories are skipped so they stay
+    /// read-only.
+    pub async fn sync_season_structure(
         &self,
         http: &Http,
         guild_id: GuildId,
-        category_name: &str,
-        channels: &[ChannelDefinition],
+        season_id: &str,
+        structure: &MergedStructure,
     ) -> Result<UpdateSummary> {
+        self.check_permission_levels(&structure.channels).await?;
         let mut summary = UpdateSummary::default();
+        let groups = structure.category_groups();
+        let archived: Vec<bool> = {
+            let state = self.state.read().await;
+            let guild = state.get_guild(&guild_id.to_string());
+            groups.iter().map(|group| is_group_archived(guild, group)).collect()
+        };
+
+        let mut categories = Vec::new();
+        let mut names = Vec::new();
+        for (group, archived) in groups.iter().zip(archived) {
+            names.push(group.name.clone());
+            if archived {
+                info!("Skipping archived category '{}'", group.name);
+                summary.warnings.push(archived_warning(&group.name));
+                continue;
+            }
+            let category_id = self.sync_category(http, guild_id, group, &mut summary).await?;
+            categories.push((category_id, group.name.clone()));
+        }
+
+        if categories.len() > 1 {
+            match self.order_categories(http, guild_id, &categories).await {
+                Ok(moved) => summary.channels_reordered.extend(moved),
+                Err(e) => {
+                    warn!("Failed to order categories of season '{}': {}", season_id, e);
+                    summary.warnings.push(format!("Failed to order categories: {}", e));
+                }
+            }
+        }
+
+        {
+            let mut state = self.state.write().await;
+            let guild = state.get_guild_mut(&guild_id.to_string(), "");
+            guild.set_season_categories(season_id, names);
+            if let Err(e) = state.save(&channel_state_path()).await {
+                warn!("Failed to save channel state: {}", e);
+            }
+        }
+
+        info!(
+            "Season sync complete: season={}, categories={}, created={}, updated={}, missing_roles={}",
+            season_id,
+            groups.len(),
+            summary.channels_created.len(),
+            summary.channels_updated.len(),
+            summary.missing_roles.len()
+        );
+
+        Ok(summary)
+    }
+
+    /// Refuse a layout that uses permission levels without a definition
+    ///
+    /// They would otherwise be synced as overwrites that grant nothing.
+    async fn check_permission_levels(&self, channels: &[ChannelDefinition]) -> Result<()> {
+        let definitions = self.config_manager.read().await.get_permission_definitions();
+        let undefined = undefined_levels(channels, &definitions);
+        if undefined.is_empty() {
+            return Ok(());
+        }
+        Err(BotError::ConfigValidation {
+            message: undefined_levels_message(&undefined),
+        })
+    }
+
+    /// Sync one category of a season and the channels inside it
+    async fn sync_category(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        group: &CategoryGroup,
+        summary: &mut UpdateSummary,
+    ) -> Result<ChannelId> {
+        let category_name = &group.name;
 
         // Ensure category exists
-        info!("Syncing season channels: category='{}', {} channels", category_name, channels.len());
+        info!("Syncing category '{}' with {} channels", category_name, group.channels.len());
         let (category_id, cat_created) = self
-            .ensure_category_exists_tracked(http, guild_id, category_name)
+            .ensure_category_exists_tracked(http, guild_id, category_name, &group.previous_names)
             .await?;
 
         if cat_created {
-            summary.category_created = Some(category_name.to_string());
+            summary.categories_created.push(category_name.clone());
         } else {
-            summary.category_existing = Some(category_name.to_string());
+            summary.categories_existing.push(category_name.clone());
+            self.restore_category_name(http, category_id, category_name, summary)
+                .await;
         }
 
-        // Set @everyone deny on the category itself for season isolation
-        if let Err(e) = self.deny_everyone_on_channel(http, guild_id, category_id).await {
-            warn!("Failed to set @everyone deny on category '{}': {}", category_name, e);
-            summary.warnings.push(format!("Failed to deny @everyone on category: {}", e));
-        } else {
-            info!("Set @everyone deny on category '{}'", category_name);
-        }
+        self.apply_category_permissions(http, guild_id, group, category_id, summary)
+            .await;
 
         // Create/update each channel
-        for channel_def in channels {
-            let (channel_id, created, updated) = self
+        for channel_def in &group.channels {
+            let (_, created, updated) = self
                 .ensure_channel_exists_tracked_with_everyone_deny(
                     http,
                     guild_id,
                     channel_def,
                     Some(category_id),
-                    &mut summary,
+                    summary,
                 )
                 .await?;
 
@@ -894,11 +1155,14 @@ impl ChannelManager {
         }
 
         // Reorder channels based on their position field
-        match self.reorder_channels_in_category(http, guild_id, category_id, channels).await {
+        match self
+            .reorder_channels_in_category(http, guild_id, category_id, &group.channels)
+            .await
+        {
             Ok(reordered) => {
                 if !reordered.is_empty() {
                     info!("Reordered {} channels in category '{}'", reordered.len(), category_name);
-                    summary.channels_reordered = reordered;
+                    summary.channels_reordered.extend(reordered);
                 }
             }
             Err(e) => {
@@ -907,110 +1171,1219 @@ impl ChannelManager {
             }
         }
 
-        info!(
-            "Season sync complete: category={}, created={}, updated={}, missing_roles={}",
-            category_name,
-            summary.channels_created.len(),
-            summary.channels_updated.len(),
-            summary.missing_roles.len()
-        );
-
-        Ok(summary)
+        Ok(category_id)
     }
 
-    /// Reorder channels within a category based on their position field
-    /// Returns a list of channel names that were reordered
-    async fn reorder_channels_in_category(
+    /// Sync one channel of a season's layout, or the category itself
+    ///
+    /// `sync_season_structure` limited to one entity: a missing channel is
+    /// created, an existing one gets its configured name, settings and
+    /// permission overwrites. Without `channel` the category's name and
+    /// overwrites are restored. Nothing is reordered.
+    pub async fn sync_season_channel(
         &self,
         http: &Http,
         guild_id: GuildId,
-        category_id: ChannelId,
-        channel_defs: &[ChannelDefinition],
-    ) -> Result<Vec<String>> {
-        // Get all channels in the guild
-        let guild_channels = guild_id.channels(http).await?;
-
-        // Find channels that belong to this category and have position defined
-        let mut channels_to_reorder: Vec<(ChannelId, String, u16)> = Vec::new();
-
-        for channel_def in channel_defs {
-            // Only process channels that have a position defined
-            if let Some(position) = channel_def.position {
-                // Find the channel ID by name within this category
-                for (channel_id, channel) in &guild_channels {
-                    if channel.name == channel_def.name && channel.parent_id == Some(category_id) {
-                        channels_to_reorder.push((*channel_id, channel_def.name.clone(), position));
-                        break;
-                    }
-                }
+        structure: &MergedStructure,
+        category: &str,
+        channel: Option<&str>,
+    ) -> Result<UpdateSummary> {
+        self.check_permission_levels(&structure.channels).await?;
+        let groups = structure.category_groups();
+        let group = groups
+            .iter()
+            .find(|g| g.name == category)
+            .ok_or_else(|| BotError::ConfigNotFound {
+                config_type: "category".to_string(),
+                name: category.to_string(),
+            })?;
+        let channel_def = match channel {
+            Some(name) => Some(
+                group
+                    .channels
+                    .iter()
+                    .find(|c| c.name == name)
+                    .ok_or_else(|| BotError::ConfigNotFound {
+                        config_type: "channel".to_string(),
+                        name: name.to_string(),
+                    })?,
+            ),
+            None => None,
+        };
+        {
+            let state = self.state.read().await;
+            if is_group_archived(state.get_guild(&guild_id.to_string()), group) {
+                return Err(BotError::ConfigValidation {
+                    message: archived_warning(&group.name),
+                });
             }
         }
 
-        if channels_to_reorder.is_empty() {
-            debug!("No channels with position field to reorder");
-            return Ok(Vec::new());
+        let mut summary = UpdateSummary::default();
+        let (category_id, cat_created) = self
+            .ensure_category_exists_tracked(http, guild_id, &group.name, &group.previous_names)
+            .await?;
+        if cat_created {
+            summary.categories_created.push(group.name.clone());
+        } else {
+            summary.categories_existing.push(group.name.clone());
+        }
+        if channel_def.is_none() && !cat_created {
+            self.restore_category_name(http, category_id, &group.name, &mut summary)
+                .await;
+        }
+        if channel_def.is_none() || cat_created {
+            self.apply_category_permissions(http, guild_id, group, category_id, &mut summary)
+                .await;
         }
 
-        // Sort by position
-        channels_to_reorder.sort_by_key(|(_, _, pos)| *pos);
+        if let Some(channel_def) = channel_def {
+            let (_, created, updated) = self
+                .ensure_channel_exists_tracked_with_everyone_deny(
+                    http,
+                    guild_id,
+                    channel_def,
+                    Some(category_id),
+                    &mut summary,
+                )
+                .await?;
+            if created {
+                summary.channels_created.push(channel_def.name.clone());
+            } else if updated {
+                summary.channels_updated.push(channel_def.name.clone());
+            }
+        }
 
-        info!(
-            "Reordering {} channels in category",
-            channels_to_reorder.len()
-        );
+        if let Err(e) = self.state.read().await.save(&channel_state_path()).await {
+            warn!("Failed to save channel state: {}", e);
+        }
+        Ok(summary)
+    }
 
-        let mut reordered_names = Vec::new();
+    /// Set a season category's permission overwrites
+    ///
+    /// Failures are added to the summary's warnings.
+    async fn apply_category_permissions(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        group: &CategoryGroup,
+        category_id: ChannelId,
+        summary: &mut UpdateSummary,
+    ) {
+        let category_name = &group.name;
 
-        // Use Discord's edit channel endpoint to set positions
-        // We need to set positions relative to each other within the category
-        for (channel_id, channel_name, position) in &channels_to_reorder {
-            if let Err(e) = channel_id
-                .edit(http, serenity::EditChannel::new().position(*position))
+        // Set @everyone deny on the category itself for season isolation,
+        // together with the category's own role permissions if it has any
+        let result = if group.role_permissions.is_empty() {
+            self.deny_everyone_on_channel(http, guild_id, category_id).await
+        } else {
+            match self
+                .build_permission_overwrites_with_everyone_deny(
+                    http,
+                    guild_id,
+                    &group.role_permissions,
+                    &ChannelType::Category,
+                    category_name,
+                    summary,
+                )
                 .await
             {
-                warn!(
-                    "Failed to set position {} for channel '{}': {}",
-                    position, channel_name, e
-                );
-            } else {
-                debug!("Set channel '{}' position to {}", channel_name, position);
-                reordered_names.push(channel_name.clone());
+                Ok(overwrites) => category_id
+                    .edit(http, serenity::EditChannel::new().permissions(overwrites))
+                    .await
+                    .map(|_| ())
+                    .map_err(Into::into),
+                Err(e) => Err(e),
             }
+        };
+        if let Err(e) = result {
+            warn!("Failed to set @everyone deny on category '{}': {}", category_name, e);
+            summary.warnings.push(format!(
+                "Failed to set permissions on category '{}': {}",
+                category_name, e
+            ));
+        } else {
+            info!("Set @everyone deny on category '{}'", category_name);
         }
-
-        Ok(reordered_names)
     }
 
-    /// Deny @everyone access to a channel/category while ensuring bot keeps access
-    async fn deny_everyone_on_channel(
+    /// Rename a category recorded in ChannelState back to its configured name
+    /// if it was renamed in Discord
+    async fn restore_category_name(
         &self,
         http: &Http,
-        guild_id: GuildId,
-        channel_id: ChannelId,
-    ) -> Result<()> {
-        let guild = guild_id.to_partial_guild(http).await?;
-        let everyone_role_id = guild.id.everyone_role();
+        category_id: ChannelId,
+        name: &str,
+        summary: &mut UpdateSummary,
+    ) {
+        let current = match category_id.to_channel(http).await {
+            Ok(serenity::Channel::Guild(channel)) => channel.name,
+            _ => return,
+        };
+        if current == name {
+            return;
+        }
 
-        // Get the bot's user ID to add explicit permission for it
-        let bot_user = http.get_current_user().await?;
-        let bot_user_id = bot_user.id;
+        match category_id
+            .edit(http, serenity::EditChannel::new().name(name))
+            .await
+        {
+            Ok(_) => {
+                info!("Renamed category '{}' back to '{}'", current, name);
+                summary
+                    .attributes_changed
+                    .push((name.to_string(), vec![format!("renamed from '{}'", current)]));
+            }
+            Err(e) => {
+                let msg = format!("Failed to rename category '{}' to '{}': {}", current, name, e);
+                warn!("{}", msg);
+                summary.warnings.push(msg);
+            }
+        }
+    }
 
-        // Get existing permission overwrites to preserve them
-        let channel = channel_id.to_channel(http).await?;
-        let existing_overwrites = match &channel {
-            serenity::Channel::Guild(gc) => gc.permission_overwrites.clone(),
-            _ => vec![],
+    /// Plan a season sync without applying it
+    ///
+    /// Mirrors `sync_season_structure`, and the creation of structure roles
+    /// that precedes it, against a snapshot of the guild: missing roles,
+    /// categories and channels, renamed categories, permission overwrite and
+    /// setting changes, and channels or categories out of order.
+    pub async fn plan_season_structure(
+        &self,
+        snapshot: &GuildSnapshot,
+        structure: &MergedStructure,
+    ) -> SyncPlan {
+        // Structure roles are only created or renamed before a season sync, never edited
+        let mut plan = self
+            .role_manager
+            .read()
+            .await
+            .plan_roles(snapshot, &structure.roles)
+            .await;
+        plan.changes.retain(|c| matches!(c.action, PlanAction::Create | PlanAction::Rename));
+        for change in &mut plan.changes {
+            if change.action == PlanAction::Rename {
+                // The rename comes first; the other edits are not applied
+                change.details.truncate(1);
+            }
+        }
+        let new_roles: Vec<&str> = structure
+            .roles
+            .iter()
+            .filter(|r| snapshot.role_by_name(&r.name).is_none())
+            .map(|r| r.name.as_str())
+            .collect();
+
+        let global_config = GlobalStructureConfig {
+            permission_definitions: self.config_manager.read().await.get_permission_definitions(),
+            ..Default::default()
         };
+        let undefined = undefined_levels(&structure.channels, &global_config.permission_definitions);
+        if !undefined.is_empty() {
+            plan.warnings.push(undefined_levels_message(&undefined));
+        }
 
-        // Build new overwrites, updating @everyone if it exists or adding it
-        // Also remove any existing bot user overwrite so we can add a fresh one
-        let mut new_overwrites: Vec<serenity::PermissionOverwrite> = existing_overwrites
-            .into_iter()
-            .filter(|ow| {
-                // Remove existing @everyone overwrite, we'll add our own
-                if matches!(ow.kind, serenity::PermissionOverwriteType::Role(r) if r == everyone_role_id) {
-                    return false;
-                }
+        let groups = structure.category_groups();
+        let state = self.state.read().await;
+        let guild = state.get_guild(&snapshot.guild_id.to_string());
+        let cached_ids: Vec<Option<ChannelId>> = groups
+            .iter()
+            .map(|group| {
+                std::iter::once(&group.name)
+                    .chain(&group.previous_names)
+                    .find_map(|name| guild?.get_category_id(name))
+                    .and_then(|id| id.parse::<u64>().ok())
+                    .map(ChannelId::new)
+            })
+            .collect();
+
+        let mut positions = Vec::new();
+        let mut categories_created = false;
+        let mut unarchived = 0;
+        for (group, cached_id) in groups.iter().zip(cached_ids) {
+            if is_group_archived(guild, group) {
+                plan.warnings.push(archived_warning(&group.name));
+                continue;
+            }
+            unarchived += 1;
+            let category_target = format!("category {}", group.name);
+            let category_only = ChannelTarget {
+                category: group.name.clone(),
+                channel: None,
+            };
+            let category = match cached_id {
+                Some(id) => match snapshot.channels.get(&id) {
+                    Some(category) => {
+                        if category.name != group.name {
+                            plan.push_channel(
+                                category_only.clone(),
+                                PlanAction::Rename,
+                                format!("category {}", category.name),
+                                vec![format!("rename to '{}'", group.name)],
+                            );
+                        }
+                        Some(category)
+                    }
+                    None => {
+                        plan.warnings.push(format!(
+                            "Category '{}' is recorded with ID {}, which no longer exists in Discord",
+                            group.name, id
+                        ));
+                        continue;
+                    }
+                },
+                None => std::iter::once(&group.name)
+                    .chain(&group.previous_names)
+                    .find_map(|name| {
+                        snapshot.channels.values().find(|c| {
+                            c.kind == serenity::ChannelType::Category && c.name == *name
+                        })
+                    }),
+            };
+
+            let Some(category) = category else {
+                categories_created = true;
+                plan.push(
+                    PlanAction::Create,
+                    category_target,
+                    vec![format!("{} channel(s)", group.channels.len())],
+                );
+                for channel_def in &group.channels {
+                    plan.push(
+                        PlanAction::Create,
+                        format!("channel #{} ({})", channel_def.name, group.name),
+                        vec![format!("{:?}", channel_def.channel_type).to_lowercase()],
+                    );
+                }
+                continue;
+            };
+            positions.push((group.name.as_str(), category.position));
+
+            // Without role permissions only the bot and @everyone overwrites are set
+            let desired = desired_overwrites(
+                snapshot,
+                &group.role_permissions,
+                &ChannelType::Category,
+                &global_config,
+                &new_roles,
+                &group.name,
+                &mut plan,
+            );
+            let changes = overwrite_changes(
+                &live_overwrites(snapshot, category),
+                &desired,
+                group.role_permissions.is_empty(),
+            );
+            if !changes.is_empty() {
+                plan.push_channel(
+                    category_only,
+                    PlanAction::Update,
+                    category_target,
+                    vec![format!("permissions {}", changes.join(", "))],
+                );
+            }
+
+            for channel_def in &group.channels {
+                let target = format!("channel #{} ({})", channel_def.name, group.name);
+                let channel_only = ChannelTarget {
+                    category: group.name.clone(),
+                    channel: Some(channel_def.name.clone()),
+                };
+                let Some(channel) =
+                    resolve_channel(guild, &snapshot.channels, channel_def, Some(category.id))
+                else {
+                    plan.push_channel(
+                        channel_only,
+                        PlanAction::Create,
+                        target,
+                        vec![format!("{:?}", channel_def.channel_type).to_lowercase()],
+                    );
+                    continue;
+                };
+
+                if channel.name != channel_def.name {
+                    plan.push_channel(
+                        channel_only.clone(),
+                        PlanAction::Rename,
+                        format!("channel #{} ({})", channel.name, group.name),
+                        vec![format!("rename to '{}'", channel_def.name)],
+                    );
+                }
+
+                let mut details = Vec::new();
+                let (_, fields) = attribute_edit(channel, channel_def);
+                if !fields.is_empty() {
+                    details.push(format!("settings {}", fields.join(", ")));
+                }
+                let desired = desired_overwrites(
+                    snapshot,
+                    &channel_def.role_permissions,
+                    &channel_def.channel_type,
+                    &global_config,
+                    &new_roles,
+                    &channel_def.name,
+                    &mut plan,
+                );
+                let changes = overwrite_changes(&live_overwrites(snapshot, channel), &desired, false);
+                if !changes.is_empty() {
+                    details.push(format!("permissions {}", changes.join(", ")));
+                }
+                if !details.is_empty() {
+                    plan.push_channel(channel_only, PlanAction::Update, target.clone(), details);
+                }
+
+                if let Some(position) = channel_def.position {
+                    if channel.position != position {
+                        plan.push(
+                            PlanAction::Reorder,
+                            target,
+                            vec![format!("position {} → {}", channel.position, position)],
+                        );
+                    }
+                }
+            }
+        }
+
+        // New categories are put in layout order once they exist
+        if unarchived > 1 {
+            if categories_created {
+                plan.push(
+                    PlanAction::Reorder,
+                    "categories",
+                    vec!["put in layout order after creation".to_string()],
+                );
+            } else {
+                let current: Vec<u16> = positions.iter().map(|(_, p)| *p).collect();
+                for ((name, position), slot) in positions.iter().zip(category_slots(&current)) {
+                    if *position != slot {
+                        plan.push(
+                            PlanAction::Reorder,
+                            format!("category {}", name),
+                            vec![format!("position {} → {}", position, slot)],
+                        );
+                    }
+                }
+            }
+        }
+
+        plan
+    }
+
+    /// Put a season's categories in layout order
+    ///
+    /// The categories swap between the positions they already occupy, so
+    /// other categories in the guild are not moved. Returns the names of the
+    /// categories that were moved.
+    async fn order_categories(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        categories: &[(ChannelId, String)],
+    ) -> Result<Vec<String>> {
+        let guild_channels = guild_id.channels(http).await?;
+        let current: Vec<u16> = categories
+            .iter()
+            .filter_map(|(id, _)| guild_channels.get(id).map(|c| c.position))
+            .collect();
+        if current.len() != categories.len() {
+            return Ok(Vec::new());
+        }
+
+        let mut moved = Vec::new();
+        for ((id, name), position) in categories.iter().zip(category_slots(&current)) {
+            if guild_channels[id].position != position {
+                id.edit(http, serenity::EditChannel::new().position(position))
+                    .await?;
+                moved.push(name.clone());
+            }
+        }
+        Ok(moved)
+    }
+
+    /// Archive a season's categories
+    ///
+    /// Rewrites the member role's overwrites on each category and its channels
+    /// from their current level to `read`, optionally moves the categories below
+    /// all others (keeping their order), and records the archived state in
+    /// ChannelState. Categories the season was last synced into are archived
+    /// too, even if they have since been dropped from the config.
+    pub async fn archive_season_categories(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        season_id: &str,
+        category_names: &[String],
+        member_role: &str,
+        move_to_bottom: bool,
+    ) -> Result<ArchiveSummary> {
+        let mut category_names = category_names.to_vec();
+        {
+            let state = self.state.read().await;
+            if let Some(guild) = state.get_guild(&guild_id.to_string()) {
+                for name in guild.season_categories(season_id) {
+                    if !category_names.contains(name) {
+                        category_names.push(name.clone());
+                    }
+                }
+            }
+        }
+
+        let mut summary = ArchiveSummary {
+            categories: category_names.clone(),
+            member_role: member_role.to_string(),
+            ..Default::default()
+        };
+
+        let guild_channels = guild_id.channels(http).await?;
+        let mut categories = Vec::new();
+        for name in &category_names {
+            match guild_channels
+                .values()
+                .find(|c| c.kind == serenity::ChannelType::Category && c.name == *name)
+            {
+                Some(category) => categories.push(category),
+                None => summary
+                    .warnings
+                    .push(format!("Category '{}' not found", name)),
+            }
+        }
+        if categories.is_empty() {
+            return Err(BotError::ChannelNotFound {
+                name: category_names.join(", "),
+            });
+        }
+
+        let role_id = {
+            let role_manager = self.role_manager.read().await;
+            role_manager.get_role_id(http, guild_id, member_role).await?
+        };
+
+        // Get permission definitions from config
+        let config = self.config_manager.read().await;
+        let permission_definitions = config.get_permission_definitions();
+        drop(config);
+
+        let global_config = GlobalStructureConfig {
+            permission_definitions,
+            ..Default::default()
+        };
+
+        let mut bottom = guild_channels
+            .values()
+            .filter(|c| {
+                c.kind == serenity::ChannelType::Category
+                    && !categories.iter().any(|own| own.id == c.id)
+            })
+            .map(|c| c.position)
+            .max()
+            .unwrap_or(0);
+        let mut archived = Vec::new();
+
+        for category in categories {
+            // The category itself first, then every channel inside it
+            let targets = std::iter::once(category).chain(
+                guild_channels
+                    .values()
+                    .filter(|c| c.parent_id == Some(category.id)),
+            );
+
+            for channel in targets {
+                let has_role_overwrite = channel.permission_overwrites.iter().any(|ow| {
+                    matches!(ow.kind, serenity::PermissionOverwriteType::Role(r) if r == role_id)
+                });
+                if !has_role_overwrite {
+                    continue;
+                }
+
+                let (allow, deny) = ChannelPermissionLevel::Read
+                    .to_permissions(&config_channel_type(channel.kind), &global_config);
+                let overwrites: Vec<serenity::PermissionOverwrite> = channel
+                    .permission_overwrites
+                    .iter()
+                    .cloned()
+                    .map(|ow| match ow.kind {
+                        serenity::PermissionOverwriteType::Role(r) if r == role_id => {
+                            serenity::PermissionOverwrite { allow, deny, kind: ow.kind }
+                        }
+                        _ => ow,
+                    })
+                    .collect();
+
+                match channel
+                    .id
+                    .edit(http, serenity::EditChannel::new().permissions(overwrites))
+                    .await
+                {
+                    Ok(_) => {
+                        info!("Set '{}' to read for role '{}'", channel.name, member_role);
+                        summary.channels_made_read_only.push(channel.name.clone());
+                    }
+                    Err(e) => {
+                        let msg = format!("Failed to make '{}' read-only: {}", channel.name, e);
+                        warn!("{}", msg);
+                        summary.warnings.push(msg);
+                    }
+                }
+            }
+
+            // Each category goes just below the previous one, keeping the season's order
+            let mut position = category.position;
+            if move_to_bottom && category.position <= bottom {
+                match category
+                    .id
+                    .edit(http, serenity::EditChannel::new().position(bottom + 1))
+                    .await
+                {
+                    Ok(_) => {
+                        position = bottom + 1;
+                        summary.moved_to_bottom = true;
+                    }
+                    Err(e) => {
+                        let msg = format!(
+                            "Failed to move category '{}' to the bottom: {}",
+                            category.name, e
+                        );
+                        warn!("{}", msg);
+                        summary.warnings.push(msg);
+                    }
+                }
+            }
+            bottom = bottom.max(position);
+            archived.push((category, position));
+        }
+
+        // Record the archived state
+        let mut state = self.state.write().await;
+        let guild = state.get_guild_mut(&guild_id.to_string(), "");
+        for (category, position) in archived {
+            if !guild.categories.contains_key(&category.name) {
+                guild.add_category(&category.name, &category.id.to_string(), position, false);
+            }
+            guild.mark_category_archived(&category.name);
+        }
+        if let Err(e) = state.save(&channel_state_path()).await {
+            warn!("Failed to save channel state: {}", e);
+        }
+
+        info!(
+            "Archived categories '{}': {} channels read-only, moved_to_bottom={}",
+            summary.categories.join("', '"),
+            summary.channels_made_read_only.len(),
+            summary.moved_to_bottom
+        );
+
+        Ok(summary)
+    }
+
+    /// Check if every one of a season's categories has been archived
+    pub async fn are_categories_archived(&self, guild_id: GuildId, names: &[String]) -> bool {
+        let state = self.state.read().await;
+        !names.is_empty()
+            && state
+                .get_guild(&guild_id.to_string())
+                .is_some_and(|g| names.iter().all(|name| g.is_category_archived(name)))
+    }
+
+    /// Plan a prune of bot-created entities that are no longer declared
+    ///
+    /// Channels and categories are hidden from members, or deleted with
+    /// `delete`; roles are only ever deleted. Entities the bot did not create
+    /// are never included.
+    pub async fn plan_prune(&self, snapshot: &GuildSnapshot, delete: bool) -> SyncPlan {
+        let (steps, warnings) = self.prune_steps(snapshot, delete).await;
+        let mut plan = SyncPlan {
+            warnings,
+            ..Default::default()
+        };
+        for step in steps {
+            if step.delete {
+                plan.push(PlanAction::Delete, step.target, vec![]);
+            } else {
+                plan.push(
+                    PlanAction::Update,
+                    step.target,
+                    vec!["archive: hide from members".to_string()],
+                );
+            }
+        }
+        plan
+    }
+
+    /// Apply a prune as planned by `plan_prune`
+    pub async fn apply_prune(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        delete: bool,
+    ) -> Result<PruneSummary> {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let (steps, warnings) = self.prune_steps(&snapshot, delete).await;
+        let mut summary = PruneSummary {
+            warnings,
+            ..Default::default()
+        };
+
+        let mut pruned = Vec::new();
+        for step in steps {
+            let result = match (step.kind, step.delete) {
+                (EntityType::Role, _) => guild_id
+                    .delete_role(http, serenity::RoleId::new(step.id))
+                    .await
+                    .map(|_| ()),
+                (_, true) => ChannelId::new(step.id).delete(http).await.map(|_| ()),
+                (_, false) => ChannelId::new(step.id)
+                    .edit(
+                        http,
+                        serenity::EditChannel::new().permissions(hidden_overwrites(&snapshot)),
+                    )
+                    .await
+                    .map(|_| ()),
+            };
+            match result {
+                Ok(()) if step.delete => {
+                    info!("Pruned {}", step.target);
+                    summary.deleted.push(step.target);
+                    pruned.push((step.kind, step.name));
+                }
+                Ok(()) => {
+                    info!("Archived {}", step.target);
+                    summary.archived.push(step.target);
+                }
+                Err(e) => summary
+                    .warnings
+                    .push(format!("Failed to prune {}: {}", step.target, e)),
+            }
+        }
+
+        if !pruned.is_empty() {
+            let mut state = self.state.write().await;
+            let guild = state.get_guild_mut(&guild_id.to_string(), "");
+            for (kind, name) in &pruned {
+                guild.forget(*kind, name);
+            }
+            if let Err(e) = state.save(&channel_state_path()).await {
+                warn!("Failed to save channel state after prune: {}", e);
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Work out what a prune would do, with warnings for what it leaves alone
+    ///
+    /// Channels come first so categories are emptied before they are deleted.
+    async fn prune_steps(
+        &self,
+        snapshot: &GuildSnapshot,
+        delete: bool,
+    ) -> (Vec<PruneStep>, Vec<String>) {
+        let declared = self.declared_entities().await;
+        let state = self.state.read().await;
+        let Some(guild) = state.get_guild(&snapshot.guild_id.to_string()) else {
+            return (Vec::new(), Vec::new());
+        };
+        let live = |id: &str| {
+            id.parse::<u64>()
+                .ok()
+                .and_then(|id| snapshot.channels.get(&ChannelId::new(id)))
+        };
+        let hidden = |channel: &serenity::GuildChannel| {
+            let want = overwrite_specs(snapshot, &hidden_overwrites(snapshot));
+            overwrite_changes(&live_overwrites(snapshot, channel), &want, false).is_empty()
+        };
+
+        let mut steps = Vec::new();
+        let mut warnings = Vec::new();
+
+        let mut channels: Vec<_> = guild
+            .channels
+            .iter()
+            .filter(|(_, c)| c.created_by_bot)
+            .collect();
+        channels.sort_by_key(|(key, c)| (c.name.as_str(), key.as_str()));
+        for (key, entry) in channels {
+            if guild.maintainers_channel_id.as_deref() == Some(entry.discord_id.as_str()) {
+                continue;
+            }
+            let Some(channel) = live(&entry.discord_id) else {
+                continue;
+            };
+            let parent = entry
+                .parent_category
+                .as_deref()
+                .and_then(|id| category_name(guild, snapshot, id));
+            let is_declared = match &parent {
+                Some(parent) => declared
+                    .channels
+                    .contains(&(parent.clone(), entry.name.clone())),
+                None => declared.channels.iter().any(|(_, name)| *name == entry.name),
+            };
+            if is_declared || (!delete && hidden(channel)) {
+                continue;
+            }
+            steps.push(PruneStep {
+                kind: EntityType::Channel,
+                name: key.clone(),
+                target: match &parent {
+                    Some(parent) => format!("channel #{} ({})", entry.name, parent),
+                    None => format!("channel #{}", entry.name),
+                },
+                id: channel.id.get(),
+                delete,
+            });
+        }
+
+        let mut categories: Vec<_> = guild
+            .categories
+            .values()
+            .filter(|c| c.created_by_bot && !declared.categories.contains(&c.name))
+            .collect();
+        categories.sort_by(|a, b| a.name.cmp(&b.name));
+        for entry in categories {
+            let Some(category) = live(&entry.discord_id) else {
+                continue;
+            };
+            if delete {
+                let foreign = snapshot.channels.values().any(|c| {
+                    c.parent_id == Some(category.id)
+                        && !steps.iter().any(|s| s.id == c.id.get())
+                });
+                if foreign {
+                    warnings.push(format!(
+                        "Category '{}' still holds channels the bot did not create or that are still declared, so it is kept",
+                        entry.name
+                    ));
+                    continue;
+                }
+            } else if hidden(category) {
+                continue;
+            }
+            steps.push(PruneStep {
+                kind: EntityType::Category,
+                name: entry.name.clone(),
+                target: format!("category {}", entry.name),
+                id: category.id.get(),
+                delete,
+            });
+        }
+
+        let mut roles: Vec<_> = guild
+            .roles
+            .values()
+            .filter(|r| r.created_by_bot && !declared.roles.contains(&r.name))
+            .collect();
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        for entry in roles {
+            let Some(role) = entry
+                .discord_id
+                .parse::<u64>()
+                .ok()
+                .and_then(|id| snapshot.roles.get(&serenity::RoleId::new(id)))
+            else {
+                continue;
+            };
+            if delete {
+                steps.push(PruneStep {
+                    kind: EntityType::Role,
+                    name: entry.name.clone(),
+                    target: format!("role {}", entry.name),
+                    id: role.id.get(),
+                    delete,
+                });
+            } else {
+                warnings.push(format!(
+                    "Role '{}' is no longer in the config; roles can only be pruned by deleting them",
+                    entry.name
+                ));
+            }
+        }
+
+        (steps, warnings)
+    }
+
+    /// Collect every role, category and channel declared by the config
+    ///
+    /// Previous names count as declared, so entities still tracked under the
+    /// name they had before a rename in the config are not pruned.
+    async fn declared_entities(&self) -> DeclaredEntities {
+        let config = self.config_manager.read().await;
+        let mut declared = DeclaredEntities::default();
+        let role_names = |roles: &[crate::config::RoleDefinition]| -> Vec<String> {
+            roles
+                .iter()
+                .flat_map(|r| std::iter::once(&r.name).chain(&r.previous_names))
+                .cloned()
+                .collect()
+        };
+        if let Some(global_roles) = config.get_global_roles() {
+            declared.roles.extend(role_names(&global_roles.roles));
+        }
+        for (season_id, season) in config.get_seasons() {
+            declared.roles.insert(season.member_role());
+            let Some(structure) = config.get_season_structure(season_id) else {
+                continue;
+            };
+            declared.roles.extend(role_names(&structure.roles));
+            for group in structure.category_groups() {
+                let categories: Vec<&String> =
+                    std::iter::once(&group.name).chain(&group.previous_names).collect();
+                for channel in &group.channels {
+                    for name in std::iter::once(&channel.name).chain(&channel.previous_names) {
+                        for category in &categories {
+                            declared
+                                .channels
+                                .insert(((*category).clone(), name.clone()));
+                        }
+                    }
+                }
+                declared.categories.extend(categories.into_iter().cloned());
+            }
+        }
+        declared
+    }
+
+    /// Adopt an existing category, given by name or ID, into a season
+    ///
+    /// Reads the category and its channels back into a season.json category
+    /// entry, mapping each role overwrite to the permission level that
+    /// produces it. Nothing is recorded until the entry is committed; see
+    /// `track_adopted_category`.
+    pub async fn adopt_category(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        category: &str,
+        season_id: &str,
+    ) -> Result<AdoptedCategory> {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let mut matches: Vec<&serenity::GuildChannel> = snapshot
+            .channels
+            .values()
+            .filter(|c| {
+                c.kind == serenity::ChannelType::Category
+                    && (c.name == category || c.id.to_string() == category)
+            })
+            .collect();
+        matches.sort_by_key(|c| (c.position, c.id));
+        let Some(&found) = matches.first() else {
+            return Err(BotError::ChannelNotFound {
+                name: category.to_string(),
+            });
+        };
+
+        let mut adopted = AdoptedCategory::default();
+        if matches.len() > 1 {
+            adopted.warnings.push(format!(
+                "{} categories are named '{}'; adopted the topmost ({}). Pass an ID to pick another",
+                matches.len(),
+                found.name,
+                found.id
+            ));
+        }
+
+        let global_config = GlobalStructureConfig {
+            permission_definitions: self.config_manager.read().await.get_permission_definitions(),
+            ..Default::default()
+        };
+        let mut roles = HashSet::new();
+
+        let mut children: Vec<&serenity::GuildChannel> = snapshot
+            .channels
+            .values()
+            .filter(|c| c.parent_id == Some(found.id))
+            .collect();
+        children.sort_by_key(|c| (c.position, c.id));
+
+        adopted.definition = ChannelDefinition {
+            name: found.name.clone(),
+            channel_type: ChannelType::Category,
+            role_permissions: adopted_permissions(
+                &snapshot,
+                found,
+                &global_config,
+                &mut roles,
+                &mut adopted.warnings,
+            ),
+            ..Default::default()
+        };
+        for channel in &children {
+            let channel_type = config_channel_type(channel.kind);
+            adopted.definition.children.push(ChannelDefinition {
+                name: channel.name.clone(),
+                position: Some(channel.position),
+                role_permissions: adopted_permissions(
+                    &snapshot,
+                    channel,
+                    &global_config,
+                    &mut roles,
+                    &mut adopted.warnings,
+                ),
+                topic: channel.topic.clone().filter(|t| !t.is_empty()),
+                slowmode: channel.rate_limit_per_user.filter(|s| *s > 0),
+                nsfw: channel.nsfw.then_some(true),
+                bitrate: channel.bitrate,
+                user_limit: channel.user_limit.filter(|u| *u > 0),
+                forum_tags: channel
+                    .available_tags
+                    .iter()
+                    .map(|tag| ForumTagDefinition {
+                        name: tag.name.clone(),
+                        moderated: tag.moderated,
+                        emoji: tag.emoji.as_ref().and_then(forum_emoji_name),
+                    })
+                    .collect(),
+                default_reaction: channel
+                    .default_reaction_emoji
+                    .as_ref()
+                    .and_then(forum_emoji_name),
+                channel_type,
+                ..Default::default()
+            });
+        }
+
+        adopted.tracking = AdoptedTracking {
+            category_id: found.id.to_string(),
+            position: found.position,
+            channels: children
+                .iter()
+                .map(|channel| {
+                    let channel_type = format!("{:?}", config_channel_type(channel.kind));
+                    (channel.name.clone(), channel.id.to_string(), channel_type)
+                })
+                .collect(),
+            roles: roles
+                .iter()
+                .filter_map(|role_id| {
+                    let role = snapshot.roles.get(role_id)?;
+                    let color = (role.colour.0 != 0).then(|| format!("#{}", role.colour.hex()));
+                    Some((role.name.clone(), role_id.to_string(), color))
+                })
+                .collect(),
+        };
+        adopted.channels = children.len();
+
+        info!(
+            "Read category '{}' with {} channels for season {}",
+            found.name,
+            children.len(),
+            season_id
+        );
+        Ok(adopted)
+    }
+
+    /// Track a category adopted by `adopt_category` once its entry is committed
+    ///
+    /// The category, its channels and the roles they reference are recorded
+    /// in the channel state as not created by the bot, so later syncs reuse
+    /// them instead of creating duplicates.
+    pub async fn track_adopted_category(
+        &self,
+        guild_id: GuildId,
+        season_id: &str,
+        adopted: &AdoptedCategory,
+    ) -> Result<()> {
+        let mut state = self.state.write().await;
+        track_adopted(state.get_guild_mut(&guild_id.to_string(), ""), season_id, adopted);
+        state.save(&channel_state_path()).await?;
+
+        info!(
+            "Adopted category '{}' with {} channels into season {}",
+            adopted.definition.name, adopted.channels, season_id
+        );
+        Ok(())
+    }
+
+    /// Reorder channels within a category based on their position field
+    /// Returns a list of channel names that were reordered
+    async fn reorder_channels_in_category(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        category_id: ChannelId,
+        channel_defs: &[ChannelDefinition],
+    ) -> Result<Vec<String>> {
+        // Get all channels in the guild
+        let guild_channels = guild_id.channels(http).await?;
+
+        // Find channels that belong to this category and have position defined
+        let mut channels_to_reorder: Vec<(ChannelId, String, u16)> = Vec::new();
+
+        for channel_def in channel_defs {
+            // Only process channels that have a position defined
+            if let Some(position) = channel_def.position {
+                // Find the channel ID by name within this category
+                for (channel_id, channel) in &guild_channels {
+                    if channel.name == channel_def.name && channel.parent_id == Some(category_id) {
+                        channels_to_reorder.push((*channel_id, channel_def.name.clone(), position));
+                        break;
+                    }
+                }
+            }
+        }
+
+        if channels_to_reorder.is_empty() {
+            debug!("No channels with position field to reorder");
+            return Ok(Vec::new());
+        }
+
+        // Sort by position
+        channels_to_reorder.sort_by_key(|(_, _, pos)| *pos);
+
+        info!(
+            "Reordering {} channels in category",
+            channels_to_reorder.len()
+        );
+
+        let mut reordered_names = Vec::new();
+
+        // Use Discord's edit channel endpoint to set positions
+        // We need to set positions relative to each other within the category
+        for (channel_id, channel_name, position) in &channels_to_reorder {
+            if let Err(e) = channel_id
+                .edit(http, serenity::EditChannel::new().position(*position))
+                .await
+            {
+                warn!(
+                    "Failed to set position {} for channel '{}': {}",
+                    position, channel_name, e
+                );
+            } else {
+                debug!("Set channel '{}' position to {}", channel_name, position);
+                reordered_names.push(channel_name.clone());
+            }
+        }
+
+        Ok(reordered_names)
+    }
+
+    /// Bring a channel's attributes (topic, slowmode, ...) and announcement
+    /// follows in line with its definition
+    ///
+    /// Attributes the definition leaves unset are not touched. Returns the
+    /// attributes that were changed and any warnings.
+    async fn reconcile_channel_attributes(
+        &self,
+        http: &Http,
+        channel: &serenity::GuildChannel,
+        channel_def: &ChannelDefinition,
+        guild_channels: &HashMap<ChannelId, serenity::GuildChannel>,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut changed = Vec::new();
+        let mut warnings = Vec::new();
+
+        let (edit, fields) = attribute_edit(channel, channel_def);
+        if !fields.is_empty() {
+            match channel.id.edit(http, edit).await {
+                Ok(_) => changed.extend(fields.iter().map(|f| f.to_string())),
+                Err(e) => warnings.push(format!(
+                    "Failed to update {} for channel '{}': {}",
+                    fields.join(", "),
+                    channel_def.name,
+                    e
+                )),
+            }
+        }
+
+        for target in &channel_def.follow_targets {
+            let target_id = target.parse::<u64>().ok().map(ChannelId::new).or_else(|| {
+                guild_channels
+                    .values()
+                    .find(|c| c.name == *target && c.kind == serenity::ChannelType::Text)
+                    .map(|c| c.id)
+            });
+            let Some(target_id) = target_id else {
+                warnings.push(format!(
+                    "Follow target '{}' for channel '{}' not found",
+                    target, channel_def.name
+                ));
+                continue;
+            };
+
+            // Following twice would create a second webhook, so check first
+            let already_following = match target_id.webhooks(http).await {
+                Ok(webhooks) => webhooks.iter().any(|w| {
+                    matches!(w.kind, serenity::WebhookType::ChannelFollower)
+                        && w.source_channel.as_ref().is_some_and(|s| s.id == channel.id)
+                }),
+                Err(e) => {
+                    warnings.push(format!(
+                        "Could not check followers of '{}' for channel '{}': {}",
+                        target, channel_def.name, e
+                    ));
+                    continue;
+                }
+            };
+            if already_following {
+                continue;
+            }
+
+            match channel.id.follow(http, target_id).await {
+                Ok(_) => changed.push(format!("follow → {}", target)),
+                Err(e) => warnings.push(format!(
+                    "Failed to follow channel '{}' into '{}': {}",
+                    channel_def.name, target, e
+                )),
+            }
+        }
+
+        (changed, warnings)
+    }
+
+    /// Reconcile a channel's attributes and record the outcome in the summary
+    async fn reconcile_tracked(
+        &self,
+        http: &Http,
+        channel: &serenity::GuildChannel,
+        channel_def: &ChannelDefinition,
+        guild_channels: &HashMap<ChannelId, serenity::GuildChannel>,
+        summary: &mut UpdateSummary,
+    ) {
+        let (changed, warnings) = self
+            .reconcile_channel_attributes(http, channel, channel_def, guild_channels)
+            .await;
+        for warning in &warnings {
+            warn!("{}", warning);
+        }
+        summary.warnings.extend(warnings);
+        if !changed.is_empty() {
+            info!("Updated {} on channel '{}'", changed.join(", "), channel_def.name);
+            summary
+                .attributes_changed
+                .push((channel_def.name.clone(), changed));
+        }
+    }
+
+    /// Deny @everyone access to a channel/category while ensuring bot keeps access
+    async fn deny_everyone_on_channel(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> Result<()> {
+        let guild = guild_id.to_partial_guild(http).await?;
+        let everyone_role_id = guild.id.everyone_role();
+
+        // Get the bot's user ID to add explicit permission for it
+        let bot_user = http.get_current_user().await?;
+        let bot_user_id = bot_user.id;
+
+        // Get existing permission overwrites to preserve them
+        let channel = channel_id.to_channel(http).await?;
+        let existing_overwrites = match &channel {
+            serenity::Channel::Guild(gc) => gc.permission_overwrites.clone(),
+            _ => vec![],
+        };
+
+        // Build new overwrites, updating @everyone if it exists or adding it
+        // Also remove any existing bot user overwrite so we can add a fresh one
+        let mut new_overwrites: Vec<serenity::PermissionOverwrite> = existing_overwrites
+            .into_iter()
+            .filter(|ow| {
+                // Remove existing @everyone overwrite, we'll add our own
+                if matches!(ow.kind, serenity::PermissionOverwriteType::Role(r) if r == everyone_role_id) {
+                    return false;
+                }
                 // Remove existing bot user overwrite, we'll add our own
                 if matches!(ow.kind, serenity::PermissionOverwriteType::Member(m) if m == bot_user_id) {
                     return false;
@@ -1055,65 +2428,76 @@ impl ChannelManager {
         parent_id: Option<ChannelId>,
         summary: &mut UpdateSummary,
     ) -> Result<(ChannelId, bool, bool)> {
-        // Check Discord for existing channel
+        // Check Discord for existing channel, which may have been renamed
         let channels = guild_id.channels(http).await?;
-        for (channel_id, channel) in &channels {
-            if channel.name == channel_def.name {
-                // Check if parent matches (if specified)
-                if let Some(parent) = parent_id {
-                    if channel.parent_id != Some(parent) {
-                        continue;
-                    }
-                }
+        let existing = {
+            let state = self.state.read().await;
+            resolve_channel(
+                state.get_guild(&guild_id.to_string()),
+                &channels,
+                channel_def,
+                parent_id,
+            )
+        };
+        if let Some(channel) = existing {
+            let channel_id = &channel.id;
 
-                // Update cache
-                {
-                    let mut state = self.state.write().await;
-                    let guild_name = "";
-                    let guild = state.get_guild_mut(&guild_id.to_string(), guild_name);
-                    let parent_name = parent_id.map(|id| id.to_string());
-                    guild.add_channel(
-                        &channel_def.name,
-                        &channel_id.to_string(),
-                        parent_name.as_deref(),
-                        &format!("{:?}", channel_def.channel_type),
-                    );
-                }
+            // Update cache
+            {
+                let mut state = self.state.write().await;
+                let guild_name = "";
+                let guild = state.get_guild_mut(&guild_id.to_string(), guild_name);
+                let parent_name = parent_id.map(|id| id.to_string());
+                guild.add_channel(
+                    &channel_def.name,
+                    &channel_id.to_string(),
+                    parent_name.as_deref(),
+                    &format!("{:?}", channel_def.channel_type),
+                    false,
+                );
+            }
 
-                // Build permissions WITH @everyone deny
-                let permission_overwrites = self
-                    .build_permission_overwrites_with_everyone_deny(
-                        http,
-                        guild_id,
-                        &channel_def.role_permissions,
-                        &channel_def.channel_type,
-                        &channel_def.name,
-                        summary,
-                    )
-                    .await?;
+            self.reconcile_tracked(http, channel, channel_def, &channels, summary)
+                .await;
 
-                if let Err(e) = channel_id
-                    .edit(
-                        http,
-                        serenity::EditChannel::new().permissions(permission_overwrites),
-                    )
-                    .await
-                {
-                    let msg = format!(
-                        "Failed to update permissions for channel '{}': {}",
-                        channel_def.name, e
-                    );
-                    warn!("{}", msg);
-                    summary.warnings.push(msg);
-                    return Ok((*channel_id, false, false));
-                }
+            // Build permissions WITH @everyone deny
+            let permission_overwrites = self
+                .build_permission_overwrites_with_everyone_deny(
+                    http,
+                    guild_id,
+                    &channel_def.role_permissions,
+                    &channel_def.channel_type,
+                    &channel_def.name,
+                    summary,
+                )
+                .await?;
 
-                info!(
-                    "Updated permissions for existing channel '{}' (with @everyone deny)",
-                    channel_def.name
+            let mut edit = serenity::EditChannel::new().permissions(permission_overwrites);
+            if channel.name != channel_def.name {
+                edit = edit.name(&channel_def.name);
+            }
+            if let Err(e) = channel_id.edit(http, edit).await {
+                let msg = format!(
+                    "Failed to update permissions for channel '{}': {}",
+                    channel_def.name, e
                 );
-                return Ok((*channel_id, false, true));
+                warn!("{}", msg);
+                summary.warnings.push(msg);
+                return Ok((*channel_id, false, false));
+            }
+
+            if channel.name != channel_def.name {
+                info!("Renamed channel '{}' to '{}'", channel.name, channel_def.name);
+                summary.attributes_changed.push((
+                    channel_def.name.clone(),
+                    vec![format!("renamed from '{}'", channel.name)],
+                ));
             }
+            info!(
+                "Updated permissions for existing channel '{}' (with @everyone deny)",
+                channel_def.name
+            );
+            return Ok((*channel_id, false, true));
         }
 
         // Build permission overwrites for new channel WITH @everyone deny
@@ -1138,6 +2522,8 @@ impl ChannelManager {
         }
 
         let channel = guild_id.create_channel(http, create_channel).await?;
+        self.reconcile_tracked(http, &channel, channel_def, &channels, summary)
+            .await;
 
         // Update cache
         {
@@ -1150,6 +2536,7 @@ impl ChannelManager {
                 &channel.id.to_string(),
                 parent_name.as_deref(),
                 &format!("{:?}", channel_def.channel_type),
+                true,
             );
         }
 
@@ -1211,10 +2598,7 @@ impl ChannelManager {
 
         // Get permission definitions from config
         let config = self.config_manager.read().await;
-        let permission_definitions = config
-            .get_global_permissions()
-            .map(|p| p.definitions.clone())
-            .unwrap_or_default();
+        let permission_definitions = config.get_permission_definitions();
         drop(config);
 
         // Build a GlobalStructureConfig with the loaded permission definitions
@@ -1260,6 +2644,381 @@ impl ChannelManager {
     }
 }
 
+/// Build an edit for the attributes of `channel` that differ from its definition
+///
+/// Returns the edit and the names of the attributes it changes. Forum tags are
+/// only rewritten when they differ, since Discord gives rewritten tags new IDs.
+fn attribute_edit(
+    channel: &serenity::GuildChannel,
+    channel_def: &ChannelDefinition,
+) -> (serenity::EditChannel<'static>, Vec<&'static str>) {
+    let mut edit = serenity::EditChannel::new();
+    let mut fields = Vec::new();
+
+    if let Some(topic) = &channel_def.topic {
+        if channel.topic.as_deref().unwrap_or_default() != topic {
+            edit = edit.topic(topic);
+            fields.push("topic");
+        }
+    }
+    if let Some(slowmode) = channel_def.slowmode {
+        if channel.rate_limit_per_user.unwrap_or(0) != slowmode {
+            edit = edit.rate_limit_per_user(slowmode);
+            fields.push("slowmode");
+        }
+    }
+    if let Some(nsfw) = channel_def.nsfw {
+        if channel.nsfw != nsfw {
+            edit = edit.nsfw(nsfw);
+            fields.push("nsfw");
+        }
+    }
+    if let Some(bitrate) = channel_def.bitrate {
+        if channel.bitrate != Some(bitrate) {
+            edit = edit.bitrate(bitrate);
+            fields.push("bitrate");
+        }
+    }
+    if let Some(user_limit) = channel_def.user_limit {
+        if channel.user_limit.unwrap_or(0) != user_limit {
+            edit = edit.user_limit(user_limit);
+            fields.push("user_limit");
+        }
+    }
+    if !channel_def.forum_tags.is_empty() {
+        let current: Vec<ForumTagDefinition> = channel
+            .available_tags
+            .iter()
+            .map(|tag| ForumTagDefinition {
+                name: tag.name.clone(),
+                moderated: tag.moderated,
+                emoji: tag.emoji.as_ref().and_then(forum_emoji_name),
+            })
+            .collect();
+        if current != channel_def.forum_tags {
+            edit = edit.available_tags(channel_def.forum_tags.iter().map(|tag| {
+                let mut create = serenity::CreateForumTag::new(&tag.name).moderated(tag.moderated);
+                if let Some(emoji) = &tag.emoji {
+                    create = create.emoji(match emoji.parse::<u64>() {
+                        Ok(id) => serenity::ReactionType::Custom {
+                            animated: false,
+                            id: serenity::EmojiId::new(id),
+                            name: None,
+                        },
+                        Err(_) => serenity::ReactionType::Unicode(emoji.clone()),
+                    });
+                }
+                create
+            }));
+            fields.push("forum_tags");
+        }
+    }
+    if let Some(reaction) = &channel_def.default_reaction {
+        let current = channel
+            .default_reaction_emoji
+            .as_ref()
+            .and_then(forum_emoji_name);
+        if current.as_deref() != Some(reaction.as_str()) {
+            let emoji = match reaction.parse::<u64>() {
+                Ok(id) => serenity::ForumEmoji::Id(serenity::EmojiId::new(id)),
+                Err(_) => serenity::ForumEmoji::Name(reaction.clone()),
+            };
+            edit = edit.default_reaction_emoji(Some(emoji));
+            fields.push("default_reaction");
+        }
+    }
+
+    (edit, fields)
+}
+
+/// A forum emoji as written in config: custom emoji ID or the unicode emoji
+fn forum_emoji_name(emoji: &serenity::ForumEmoji) -> Option<String> {
+    match emoji {
+        serenity::ForumEmoji::Id(id) => Some(id.to_string()),
+        serenity::ForumEmoji::Name(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// Record an adopted category, its channels and roles in a guild's state
+///
+/// Everything adopted is marked as not created by the bot, even if the bot
+/// tracked an entity with the same ID before, so pruning never touches it.
+fn track_adopted(guild: &mut GuildChannelState, season_id: &str, adopted: &AdoptedCategory) {
+    let tracking = &adopted.tracking;
+    let name = &adopted.definition.name;
+    guild.add_category(name, &tracking.category_id, tracking.position, false);
+    guild.disown(&tracking.category_id);
+    for (channel, id, channel_type) in &tracking.channels {
+        guild.add_channel(channel, id, Some(&tracking.category_id), channel_type, false);
+        guild.disown(id);
+    }
+    for (role, id, color) in &tracking.roles {
+        guild.add_role(role, id, color.as_deref(), false);
+        guild.disown(id);
+    }
+    let mut categories = guild.season_categories(season_id).to_vec();
+    if !categories.contains(name) {
+        categories.push(name.clone());
+        guild.set_season_categories(season_id, categories);
+    }
+}
+
+/// Whether a season category was archived, under its name or a previous one
+fn is_group_archived(guild: Option<&GuildChannelState>, group: &CategoryGroup) -> bool {
+    guild.is_some_and(|g| {
+        std::iter::once(&group.name)
+            .chain(&group.previous_names)
+            .any(|name| g.is_category_archived(name))
+    })
+}
+
+/// Permission levels used anywhere in `channels` that `definitions` can't resolve
+fn undefined_levels(
+    channels: &[ChannelDefinition],
+    definitions: &HashMap<String, PermissionSet>,
+) -> Vec<String> {
+    let mut names: Vec<String> = channels
+        .iter()
+        .flat_map(|channel| channel.undefined_levels(definitions))
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Why a layout using undefined permission levels is not synced
+fn undefined_levels_message(names: &[String]) -> String {
+    format!(
+        "Permission level(s) {} are not defined in global/permissions.json; the layout is not synced",
+        names.iter().map(|n| format!("'{}'", n)).collect::<Vec<_>>().join(", ")
+    )
+}
+
+/// Shown when a sync leaves an archived category alone
+fn archived_warning(name: &str) -> String {
+    format!(
+        "Category '{}' is archived and was left read-only; it is not synced",
+        name
+    )
+}
+
+/// Positions for categories currently at `current`, in the order they should appear
+///
+/// Reuses the occupied positions in ascending order, spreading out ties so
+/// the order is unambiguous.
+fn category_slots(current: &[u16]) -> Vec<u16> {
+    let mut slots = current.to_vec();
+    slots.sort_unstable();
+    for i in 1..slots.len() {
+        if slots[i] <= slots[i - 1] {
+            slots[i] = slots[i - 1] + 1;
+        }
+    }
+    slots
+}
+
+/// The permission overwrites a season sync gives a channel
+///
+/// The bot and an @everyone deny come first, then one overwrite per role.
+/// Roles that neither exist nor are about to be created are skipped with a
+/// warning, as the sync does.
+fn desired_overwrites(
+    snapshot: &GuildSnapshot,
+    role_permissions: &HashMap<String, ChannelPermissionLevel>,
+    channel_type: &ChannelType,
+    global_config: &GlobalStructureConfig,
+    new_roles: &[&str],
+    channel_name: &str,
+    plan: &mut SyncPlan,
+) -> Vec<OverwriteSpec> {
+    let mut overwrites = vec![
+        OverwriteSpec {
+            target: "bot".to_string(),
+            level: None,
+            allow: Permissions::VIEW_CHANNEL
+                | Permissions::MANAGE_CHANNELS
+                | Permissions::SEND_MESSAGES
+                | Permissions::CONNECT,
+            deny: Permissions::empty(),
+        },
+        OverwriteSpec {
+            target: "@everyone".to_string(),
+            level: None,
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL | Permissions::CONNECT,
+        },
+    ];
+
+    for (role_name, level) in role_permissions {
+        if snapshot.role_by_name(role_name).is_none() && !new_roles.contains(&role_name.as_str()) {
+            plan.warnings.push(format!(
+                "Role '{}' for '{}' not found; its permissions will not be set",
+                role_name, channel_name
+            ));
+            continue;
+        }
+        let (allow, deny) = level.to_permissions(channel_type, global_config);
+        overwrites.push(OverwriteSpec {
+            target: role_name.clone(),
+            level: Some(level.name().to_string()),
+            allow,
+            deny,
+        });
+    }
+
+    overwrites
+}
+
+/// A channel's current permission overwrites, keyed like `desired_overwrites`
+fn live_overwrites(snapshot: &GuildSnapshot, channel: &serenity::GuildChannel) -> Vec<OverwriteSpec> {
+    overwrite_specs(snapshot, &channel.permission_overwrites)
+}
+
+/// Permission overwrites keyed like `desired_overwrites`
+fn overwrite_specs(
+    snapshot: &GuildSnapshot,
+    overwrites: &[serenity::PermissionOverwrite],
+) -> Vec<OverwriteSpec> {
+    overwrites
+        .iter()
+        .map(|ow| OverwriteSpec {
+            target: snapshot.overwrite_target(&ow.kind),
+            level: None,
+            allow: ow.allow,
+            deny: ow.deny,
+        })
+        .collect()
+}
+
+/// Overwrites of a pruned channel: only the bot (and admins) can still see it
+fn hidden_overwrites(snapshot: &GuildSnapshot) -> Vec<serenity::PermissionOverwrite> {
+    vec![
+        serenity::PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL
+                | Permissions::MANAGE_CHANNELS
+                | Permissions::SEND_MESSAGES
+                | Permissions::CONNECT,
+            deny: Permissions::empty(),
+            kind: serenity::PermissionOverwriteType::Member(snapshot.bot_user),
+        },
+        serenity::PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::VIEW_CHANNEL | Permissions::CONNECT,
+            kind: serenity::PermissionOverwriteType::Role(snapshot.everyone_role),
+        },
+    ]
+}
+
+/// Permission levels that reproduce a channel's role overwrites
+///
+/// The bot's own overwrite is skipped. Everything a season sync would not
+/// recreate (member overwrites, overwrites no level matches, an @everyone
+/// overwrite other than the usual deny) is reported in `warnings`.
+fn adopted_permissions(
+    snapshot: &GuildSnapshot,
+    channel: &serenity::GuildChannel,
+    global_config: &GlobalStructureConfig,
+    roles: &mut HashSet<serenity::RoleId>,
+    warnings: &mut Vec<String>,
+) -> HashMap<String, ChannelPermissionLevel> {
+    let channel_type = config_channel_type(channel.kind);
+    let everyone_deny = Permissions::VIEW_CHANNEL | Permissions::CONNECT;
+    let mut levels = HashMap::new();
+    let mut everyone_hidden = false;
+
+    for overwrite in &channel.permission_overwrites {
+        let target = snapshot.overwrite_target(&overwrite.kind);
+        match overwrite.kind {
+            serenity::PermissionOverwriteType::Member(id) if id == snapshot.bot_user => {}
+            serenity::PermissionOverwriteType::Role(id) if id == snapshot.everyone_role => {
+                everyone_hidden = overwrite.allow.is_empty() && overwrite.deny == everyone_deny;
+            }
+            serenity::PermissionOverwriteType::Role(id) => {
+                match ChannelPermissionLevel::matching(
+                    overwrite.allow,
+                    overwrite.deny,
+                    &channel_type,
+                    global_config,
+                ) {
+                    Some(level) => {
+                        roles.insert(id);
+                        levels.insert(target, level);
+                    }
+                    None => warnings.push(format!(
+                        "'{}': the overwrite for {} (allow {}, deny {}) matches no permission level and was left out",
+                        channel.name, target, overwrite.allow, overwrite.deny
+                    )),
+                }
+            }
+            _ => warnings.push(format!(
+                "'{}': the overwrite for {} was left out; only role overwrites are adopted",
+                channel.name, target
+            )),
+        }
+    }
+
+    if !everyone_hidden {
+        warnings.push(format!(
+            "'{}' does not hide itself from @everyone; a season sync will deny @everyone viewing it",
+            channel.name
+        ));
+    }
+
+    levels
+}
+
+/// Name of a tracked channel's parent category, from the state or else Discord
+fn category_name(guild: &GuildChannelState, snapshot: &GuildSnapshot, id: &str) -> Option<String> {
+    guild
+        .categories
+        .values()
+        .find(|c| c.discord_id == id)
+        .map(|c| c.name.clone())
+        .or_else(|| {
+            let id = id.parse::<u64>().ok()?;
+            snapshot.channels.get(&ChannelId::new(id)).map(|c| c.name.clone())
+        })
+}
+
+/// Find the live channel for a definition inside category `parent` (or anywhere if None)
+///
+/// Tries the definition's name and then each of its `previous_names`: first
+/// the channel whose ID the channel state recorded under that name, so
+/// channels renamed in Discord are found, then a channel with that name.
+fn resolve_channel<'a>(
+    guild: Option<&GuildChannelState>,
+    channels: &'a HashMap<ChannelId, serenity::GuildChannel>,
+    channel_def: &ChannelDefinition,
+    parent: Option<ChannelId>,
+) -> Option<&'a serenity::GuildChannel> {
+    let parent_key = parent.map(|id| id.to_string());
+    let in_parent = |c: &serenity::GuildChannel| parent.is_none() || c.parent_id == parent;
+    let by_id = |name: &String| {
+        let id = guild?
+            .get_channel_id(parent_key.as_deref(), name)?
+            .parse::<u64>()
+            .ok()?;
+        channels.get(&ChannelId::new(id)).filter(|c| in_parent(c))
+    };
+    let by_name = |name: &String| channels.values().find(|c| c.name == *name && in_parent(c));
+    std::iter::once(&channel_def.name)
+        .chain(&channel_def.previous_names)
+        .find_map(|name| by_id(name).or_else(|| by_name(name)))
+}
+
+/// Map a Discord channel kind to the config channel type used for permission levels
+fn config_channel_type(kind: serenity::ChannelType) -> ChannelType {
+    match kind {
+        serenity::ChannelType::Category => ChannelType::Category,
+        serenity::ChannelType::Voice => ChannelType::Voice,
+        serenity::ChannelType::Stage => ChannelType::Stage,
+        serenity::ChannelType::Forum => ChannelType::Forum,
+        serenity::ChannelType::News => ChannelType::News,
+        _ => ChannelType::Text,
+    }
+}
+
 /// Shared channel manager type
 pub type SharedChannelManager = Arc<tokio::sync::RwLock<ChannelManager>>;
 
@@ -1274,3 +3033,153 @@ pub fn create_shared_channel_manager(
         config_manager,
     )))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_edit_only_touches_differing_attributes() {
+        let channel: serenity::GuildChannel = serde_json::from_value(serde_json::json!({
+            "id": "2",
+            "guild_id": "1",
+            "type": 15,
+            "name": "questions",
+            "position": 0,
+            "topic": "Ask here",
+            "rate_limit_per_user": 0,
+            "nsfw": false,
+            "available_tags": [{ "id": "3", "name": "solved", "moderated": false, "emoji_id": null, "emoji_name": "✅" }]
+        }))
+        .unwrap();
+        let definition: ChannelDefinition = serde_json::from_str(
+            r#"{ "name": "questions", "type": "forum", "topic": "Ask here", "slowmode": 30,
+                 "forum_tags": [{ "name": "solved", "emoji": "✅" }], "default_reaction": "👍" }"#,
+        )
+        .unwrap();
+
+        let (_, fields) = attribute_edit(&channel, &definition);
+        assert_eq!(fields, vec!["slowmode", "default_reaction"]);
+    }
+
+    #[test]
+    fn test_category_slots_reuse_positions() {
+        assert_eq!(category_slots(&[7, 2, 4]), vec![2, 4, 7]);
+        assert_eq!(category_slots(&[3, 3, 3]), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_adopted_entities_are_not_bot_created() {
+        let mut guild = GuildChannelState::new("1", "Test");
+        // The bot created a duplicate "Board" category before; 20 is the hand-built one
+        guild.add_category("Board", "10", 0, true);
+        guild.add_channel("general", "21", Some("20"), "Text", true);
+        guild.add_role("Styret", "30", None, true);
+
+        let adopted = AdoptedCategory {
+            definition: ChannelDefinition {
+                name: "Board".to_string(),
+                channel_type: ChannelType::Category,
+                ..Default::default()
+            },
+            channels: 1,
+            tracking: AdoptedTracking {
+                category_id: "20".to_string(),
+                position: 3,
+                channels: vec![("general".to_string(), "21".to_string(), "Text".to_string())],
+                roles: vec![("Styret".to_string(), "30".to_string(), None)],
+            },
+            ..Default::default()
+        };
+        track_adopted(&mut guild, "2025E", &adopted);
+
+        assert_eq!(guild.get_category_id("Board"), Some("20"));
+        assert!(!guild.categories["Board"].created_by_bot);
+        assert!(!guild.channels["20/general"].created_by_bot);
+        assert!(!guild.roles["Styret"].created_by_bot);
+        assert_eq!(guild.season_categories("2025E"), ["Board".to_string()]);
+    }
+
+    #[test]
+    fn test_undefined_levels_include_children() {
+        let channels: Vec<ChannelDefinition> = serde_json::from_str(
+            r#"[{ "name": "Staff", "type": "category", "role_permissions": { "Styret": "admin" },
+                  "children": [{ "name": "drift", "type": "text", "role_permissions": { "Styret": "moderator" } }] },
+                { "name": "general", "type": "text", "role_permissions": { "Medlem": "moderator", "Gjest": "guest" } }]"#,
+        )
+        .unwrap();
+        let mut definitions = HashMap::new();
+        assert_eq!(undefined_levels(&channels, &definitions), vec!["guest", "moderator"]);
+
+        definitions.insert(
+            "moderator".to_string(),
+            PermissionSet { allow: vec![], deny: vec![] },
+        );
+        assert_eq!(undefined_levels(&channels, &definitions), vec!["guest"]);
+    }
+
+    #[tokio::test]
+    async fn test_plan_skips_archived_categories() {
+        let channel = |id: &str, kind: u8, name: &str, parent: Option<&str>| {
+            let channel: serenity::GuildChannel = serde_json::from_value(serde_json::json!({
+                "id": id, "guild_id": "1", "type": kind, "name": name, "position": 0,
+                "parent_id": parent, "permission_overwrites": []
+            }))
+            .unwrap();
+            (channel.id, channel)
+        };
+        let snapshot = GuildSnapshot {
+            guild_id: GuildId::new(1),
+            channels: HashMap::from([
+                channel("20", 4, "2024E", None),
+                channel("21", 0, "general", Some("20")),
+            ]),
+            roles: HashMap::new(),
+            everyone_role: serenity::RoleId::new(1),
+            bot_user: serenity::UserId::new(2),
+            bot_roles: vec![],
+        };
+        let structure = MergedStructure {
+            category_name: "2024E".to_string(),
+            roles: vec![],
+            channels: vec![serde_json::from_str(r#"{ "name": "general", "type": "text" }"#).unwrap()],
+        };
+
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("1", "Test");
+        guild.add_category("2024E", "20", 0, true);
+        guild.add_channel("general", "21", Some("20"), "text", true);
+        let state = crate::state::create_shared_channel_state(state);
+        let manager = ChannelManager::new(
+            state.clone(),
+            crate::managers::create_shared_role_manager(state.clone()),
+            crate::managers::create_shared_config_manager("data"),
+        );
+
+        // The live category lacks the @everyone deny, so a sync would change it
+        let plan = manager.plan_season_structure(&snapshot, &structure).await;
+        assert!(!plan.is_empty());
+
+        // Each change is limited to the category or channel it was found on
+        let targets: Vec<_> = plan.changes.iter().map(|c| c.channel.clone()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                Some(ChannelTarget {
+                    category: "2024E".to_string(),
+                    channel: None,
+                }),
+                Some(ChannelTarget {
+                    category: "2024E".to_string(),
+                    channel: Some("general".to_string()),
+                }),
+            ]
+        );
+
+        // Once archived, the category is left alone
+        state.write().await.get_guild_mut("1", "Test").mark_category_archived("2024E");
+        let plan = manager.plan_season_structure(&snapshot, &structure).await;
+        assert!(plan.is_empty());
+        assert_eq!(plan.warnings, vec![archived_warning("2024E")]);
+    }
+}
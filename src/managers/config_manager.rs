@@ -1,12 +1,33 @@
-use std::collections::HashMap;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{info, warn};
 
+use crate::config::category_structure::MergedStructure;
 use crate::config::{
-    ConfigChange, ConfigChangeType, ConfigDiff, GlobalPermissionsConfig, GlobalRolesConfig,
-    Season, SeasonConfig, SeasonUser, SpecialMembersConfig, StagedConfig, load_users_from_file,
+    CategoryStructureConfig, ChannelDefinition, ChannelType, ConfigChange, ConfigChangeType, ConfigDiff, GlobalPermissionsConfig,
+    GlobalRolesConfig, GlobalStructureConfig, PermissionSet, Season, SeasonConfig, SeasonPhase,
+    SeasonUser, SpecialMembersConfig, StagedConfig,
+    describe_user_change, diff_season_users, load_users_from_file, parse_users_csv,
+    returning_users, validate_config, CsvColumns, ConfigTree, ValidationIssue, ValidationReport,
 };
 use crate::error::{BotError, Result};
+use crate::state::{config_history_path, ConfigHistory, UserDatabase, VerificationStatus};
+
+/// Outcome of rolling an existing season (or the template) over into a new one
+#[derive(Debug, Clone)]
+pub struct RolloverReport {
+    pub season_id: String,
+    pub source: String,
+    pub name: String,
+    pub member_role: String,
+    pub channels_copied: usize,
+    pub user_count: usize,
+    /// Users of the new season that were also in the source season's users.json
+    pub returning_users: Vec<SeasonUser>,
+    /// Optional files carried over besides season.json (category.json, roles.json)
+    pub files_copied: Vec<String>,
+}
 
 /// Manages all configuration loading, staging, and committing
 pub struct ConfigManager {
@@ -22,9 +43,21 @@ pub struct ConfigManager {
     /// Global permissions config (from global/permissions.json)
     global_permissions: Option<GlobalPermissionsConfig>,
 
+    /// Default channel layout every season inherits (from global/structure.json)
+    global_structure: Option<GlobalStructureConfig>,
+
+    /// Per-season structure overrides (season_id -> seasons/{id}/category.json)
+    category_structures: HashMap<String, CategoryStructureConfig>,
+
     /// Staged configuration waiting to be committed
     staged: StagedConfig,
 
+    /// Files that failed to load during the last load_all
+    load_issues: Vec<ValidationIssue>,
+
+    /// Snapshots of every config file written through the manager
+    history: ConfigHistory,
+
     /// Base path for data files
     data_path: String,
 }
@@ -37,7 +70,11 @@ impl ConfigManager {
             special_members: None,
             global_roles: None,
             global_permissions: None,
+            global_structure: None,
+            category_structures: HashMap::new(),
             staged: StagedConfig::new(),
+            load_issues: Vec::new(),
+            history: ConfigHistory::new(config_history_path()),
             data_path: data_path.to_string(),
         }
     }
@@ -49,12 +86,15 @@ impl ConfigManager {
     /// ├── global/
     /// │   ├── roles.json        # Role definitions
     /// │   ├── assignments.json  # Who has which special role
-    /// │   └── permissions.json  # Permission definitions
+    /// │   ├── permissions.json  # Permission definitions
+    /// │   └── structure.json    # Default channel layout for every season
     /// └── seasons/
     ///     └── {season_id}/
     ///         ├── season.json   # Season config (name, active, channels)
+    ///         ├── category.json # Optional overrides of the default layout
     ///         └── users.json    # Users array (externally generated)
     pub async fn load_all(&mut self) -> Result<()> {
+        self.load_issues.clear();
         self.load_global_config().await;
         self.load_seasons().await;
 
@@ -66,9 +106,24 @@ impl ConfigManager {
             self.global_permissions.is_some(),
         );
 
+        let report = self.validate();
+        if !report.is_ok() {
+            warn!("Config validation found {} problem(s):", report.issues.len());
+            for issue in &report.issues {
+                warn!("  {}", issue);
+            }
+        }
+
         Ok(())
     }
 
+    /// Record a file that failed to load so validation reports it
+    fn record_load_failure(&mut self, file: &str, error: &BotError) {
+        warn!("Failed to load {}: {}", file, error);
+        self.load_issues
+            .push(ValidationIssue::new(file, "$", error.to_string()));
+    }
+
     /// Load global config from data/global/ directory
     async fn load_global_config(&mut self) {
         let global_dir = format!("{}/global", self.data_path);
@@ -81,7 +136,7 @@ impl ConfigManager {
                     info!("Loaded {} roles from global/roles.json", config.roles.len());
                     self.global_roles = Some(config);
                 }
-                Err(e) => warn!("Failed to load global/roles.json: {}", e),
+                Err(e) => self.record_load_failure("global/roles.json", &e),
             }
         }
 
@@ -96,7 +151,7 @@ impl ConfigManager {
                     );
                     self.special_members = Some(config);
                 }
-                Err(e) => warn!("Failed to load global/assignments.json: {}", e),
+                Err(e) => self.record_load_failure("global/assignments.json", &e),
             }
         }
 
@@ -111,7 +166,22 @@ impl ConfigManager {
                     );
                     self.global_permissions = Some(config);
                 }
-                Err(e) => warn!("Failed to load global/permissions.json: {}", e),
+                Err(e) => self.record_load_failure("global/permissions.json", &e),
+            }
+        }
+
+        // Load structure.json
+        let structure_path = format!("{}/structure.json", global_dir);
+        if std::path::Path::new(&structure_path).exists() {
+            match GlobalStructureConfig::load_from_file(&structure_path) {
+                Ok(config) => {
+                    info!(
+                        "Loaded {} default channels from global/structure.json",
+                        config.default_channels.len()
+                    );
+                    self.global_structure = Some(config);
+                }
+                Err(e) => self.record_load_failure("global/structure.json", &e),
             }
         }
     }
@@ -153,7 +223,7 @@ impl ConfigManager {
                         config
                     }
                     Err(e) => {
-                        warn!("Failed to load season.json for {}: {}", season_id, e);
+                        self.record_load_failure(&format!("seasons/{}/season.json", season_id), &e);
                         continue;
                     }
                 }
@@ -163,7 +233,10 @@ impl ConfigManager {
                     name: season_id.clone(),
                     active: true,
                     member_role: None, // Will fallback to "Medlem{season_id}"
+                    starts_at: None,
+                    ends_at: None,
                     channels: vec![],
+                    csv_columns: None,
                 }
             };
 
@@ -176,7 +249,7 @@ impl ConfigManager {
                         users
                     }
                     Err(e) => {
-                        warn!("Failed to load users.json for {}: {}", season_id, e);
+                        self.record_load_failure(&format!("seasons/{}/users.json", season_id), &e);
                         vec![]
                     }
                 }
@@ -184,12 +257,433 @@ impl ConfigManager {
                 vec![]
             };
 
+            // Load category.json (optional structure overrides)
+            let category_path = path.join("category.json");
+            if category_path.exists() {
+                match CategoryStructureConfig::load_from_file(
+                    category_path.to_str().unwrap_or_default(),
+                ) {
+                    Ok(mut category) => {
+                        if category.season_id.is_empty() {
+                            category.season_id = season_id.clone();
+                        }
+                        info!("Loaded category structure for season '{}'", season_id);
+                        self.category_structures.insert(season_id.clone(), category);
+                    }
+                    Err(e) => {
+                        self.record_load_failure(&format!("seasons/{}/category.json", season_id), &e)
+                    }
+                }
+            }
+
             // Create Season combining config + users
             let season = Season::new(season_id.clone(), config, users);
             self.seasons.insert(season_id, season);
         }
     }
 
+    // ========== Validation ==========
+
+    /// Cross-reference the loaded config tree
+    /// Files that failed to load are reported first
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = validate_config(&ConfigTree {
+            global_roles: self.global_roles.as_ref(),
+            global_permissions: self.global_permissions.as_ref(),
+            special_members: self.special_members.as_ref(),
+            global_structure: self.global_structure.as_ref(),
+            category_structures: self.category_structures.values().collect(),
+            seasons: self.seasons.values().collect(),
+        });
+        report.issues.splice(0..0, self.load_issues.iter().cloned());
+        report
+    }
+
+    /// Problems a new season (and its category.json) would add to the loaded config
+    fn validate_with_season(
+        &self,
+        season: &Season,
+        category: Option<&CategoryStructureConfig>,
+    ) -> Vec<ValidationIssue> {
+        let report = validate_config(&ConfigTree {
+            global_roles: self.global_roles.as_ref(),
+            global_permissions: self.global_permissions.as_ref(),
+            special_members: self.special_members.as_ref(),
+            global_structure: self.global_structure.as_ref(),
+            category_structures: self.category_structures.values().chain(category).collect(),
+            seasons: self.seasons.values().chain(std::iter::once(season)).collect(),
+        });
+        report.new_since(&self.validate())
+    }
+
+    /// Validate the config tree as it would look after committing the staged config
+    fn validate_with_staged(&self) -> ValidationReport {
+        let staged_ids: HashSet<&String> = self
+            .staged
+            .seasons
+            .keys()
+            .chain(self.staged.season_configs.keys())
+            .collect();
+
+        let staged_seasons: Vec<Season> = staged_ids
+            .iter()
+            .map(|&season_id| {
+                let current = self.seasons.get(season_id);
+                let config = self
+                    .staged
+                    .season_configs
+                    .get(season_id)
+                    .or(current.map(|s| &s.config))
+                    .cloned()
+                    .unwrap_or_else(|| SeasonConfig {
+                        name: season_id.clone(),
+                        active: true,
+                        member_role: None,
+                        starts_at: None,
+                        ends_at: None,
+                        channels: vec![],
+                        csv_columns: None,
+                    });
+                let users = self
+                    .staged
+                    .seasons
+                    .get(season_id)
+                    .map(|s| &s.users)
+                    .or(current.map(|s| &s.users))
+                    .cloned()
+                    .unwrap_or_default();
+                Season::new(season_id.clone(), config, users)
+            })
+            .collect();
+
+        let seasons = self
+            .seasons
+            .values()
+            .filter(|s| !staged_ids.contains(&s.season_id))
+            .chain(staged_seasons.iter())
+            .collect();
+
+        let mut report = validate_config(&ConfigTree {
+            global_roles: self
+                .staged
+                .global_roles
+                .as_ref()
+                .or(self.global_roles.as_ref()),
+            global_permissions: self
+                .staged
+                .global_permissions
+                .as_ref()
+                .or(self.global_permissions.as_ref()),
+            special_members: self
+                .staged
+                .special_members
+                .as_ref()
+                .or(self.special_members.as_ref()),
+            global_structure: self
+                .staged
+                .global_structure
+                .as_ref()
+                .or(self.global_structure.as_ref()),
+            category_structures: self
+                .category_structures
+                .iter()
+                .filter(|(id, _)| !self.staged.category_structures.contains_key(*id))
+                .map(|(_, category)| category)
+                .chain(self.staged.category_structures.values())
+                .collect(),
+            seasons,
+        });
+        report.issues.splice(0..0, self.load_issues.iter().cloned());
+        report
+    }
+
+    // ========== Reloading ==========
+
+    /// Replace the loaded config with one freshly loaded from disk
+    /// Staged config is kept.
+    pub fn replace_loaded(&mut self, fresh: ConfigManager) {
+        self.seasons = fresh.seasons;
+        self.special_members = fresh.special_members;
+        self.global_roles = fresh.global_roles;
+        self.global_permissions = fresh.global_permissions;
+        self.global_structure = fresh.global_structure;
+        self.category_structures = fresh.category_structures;
+        self.load_issues = fresh.load_issues;
+    }
+
+    /// Describe how a freshly loaded config differs from this one, one line per change
+    pub fn diff_loaded(&self, fresh: &ConfigManager) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        // Roles
+        let role_names = |config: &Option<GlobalRolesConfig>| -> Vec<String> {
+            config
+                .iter()
+                .flat_map(|c| c.roles.iter().map(|r| r.name.clone()))
+                .collect()
+        };
+        let old_roles = role_names(&self.global_roles);
+        let new_roles = role_names(&fresh.global_roles);
+        for role in new_roles.iter().filter(|r| !old_roles.contains(r)) {
+            lines.push(format!("+ role {}", role));
+        }
+        for role in old_roles.iter().filter(|r| !new_roles.contains(r)) {
+            lines.push(format!("- role {}", role));
+        }
+        if old_roles == new_roles && !json_eq(&self.global_roles, &fresh.global_roles) {
+            lines.push("~ global/roles.json changed".to_string());
+        }
+
+        if !json_eq(&self.special_members, &fresh.special_members) {
+            lines.push("~ global/assignments.json changed".to_string());
+        }
+        if !json_eq(&self.global_permissions, &fresh.global_permissions) {
+            lines.push("~ global/permissions.json changed".to_string());
+        }
+        if !json_eq(&self.global_structure, &fresh.global_structure) {
+            lines.push("~ global/structure.json changed".to_string());
+        }
+
+        // Seasons
+        let mut season_ids: Vec<&String> = self.seasons.keys().chain(fresh.seasons.keys()).collect();
+        season_ids.sort();
+        season_ids.dedup();
+
+        for season_id in season_ids {
+            match (self.seasons.get(season_id), fresh.seasons.get(season_id)) {
+                (None, Some(new)) => lines.push(format!(
+                    "+ season {} ({} users, {} channels)",
+                    season_id,
+                    new.user_count(),
+                    new.channels().len()
+                )),
+                (Some(_), None) => lines.push(format!("- season {}", season_id)),
+                (Some(old), Some(new)) => {
+                    let mut changes = Vec::new();
+                    if old.name() != new.name() {
+                        changes.push(format!("name '{}' → '{}'", old.name(), new.name()));
+                    }
+                    if old.is_active() != new.is_active() {
+                        changes.push(format!("active {} → {}", old.is_active(), new.is_active()));
+                    }
+                    if old.member_role() != new.member_role() {
+                        changes.push(format!(
+                            "member role {} → {}",
+                            old.member_role(),
+                            new.member_role()
+                        ));
+                    }
+                    if old.config.starts_at != new.config.starts_at
+                        || old.config.ends_at != new.config.ends_at
+                    {
+                        changes.push("dates changed".to_string());
+                    }
+                    if !json_eq(&old.config.channels, &new.config.channels) {
+                        changes.push(format!(
+                            "channels changed ({} → {})",
+                            old.channels().len(),
+                            new.channels().len()
+                        ));
+                    }
+                    if !json_eq(
+                        &self.category_structures.get(season_id),
+                        &fresh.category_structures.get(season_id),
+                    ) {
+                        changes.push("category.json changed".to_string());
+                    }
+                    if old.user_count() != new.user_count() {
+                        changes.push(format!("users {} → {}", old.user_count(), new.user_count()));
+                    } else if !json_eq(&old.users, &new.users) {
+                        changes.push("users changed".to_string());
+                    }
+                    if !changes.is_empty() {
+                        lines.push(format!("~ season {}: {}", season_id, changes.join(", ")));
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        lines
+    }
+
+    // ========== Season Lifecycle ==========
+
+    /// Set a season's `active` flag and write it back to its season.json
+    ///
+    /// The write is recorded in the config history under `author`.
+    pub async fn set_season_active(
+        &mut self,
+        season_id: &str,
+        active: bool,
+        author: &str,
+    ) -> Result<()> {
+        let season = self
+            .seasons
+            .get(season_id)
+            .ok_or_else(|| BotError::ConfigNotFound {
+                config_type: "season".to_string(),
+                name: season_id.to_string(),
+            })?;
+
+        let mut config = season.config.clone();
+        config.active = active;
+        let content = serde_json::to_string_pretty(&config)?;
+        let action = if active { "activate" } else { "deactivate" };
+        self.write_config_file(
+            &format!("seasons/{}/season.json", season_id),
+            &content,
+            author,
+            action,
+        )
+        .await?;
+        if let Some(season) = self.seasons.get_mut(season_id) {
+            season.config.active = active;
+        }
+
+        info!("Season '{}' set to active={}", season_id, active);
+        Ok(())
+    }
+
+    /// Create a new season by cloning an existing season or the `template` directory
+    ///
+    /// The season config is rewritten for the new ID (see `SeasonConfig::rolled_over`)
+    /// and written together with `users` to data/seasons/{new_season_id}/. The
+    /// source's category.json is carried over the same way, and its roles.json
+    /// is copied as is. Every file is recorded in the config history under `author`.
+    /// A season that would introduce new validation problems is refused.
+    pub async fn rollover_season(
+        &mut self,
+        source_id: &str,
+        new_season_id: &str,
+        name: Option<&str>,
+        users: Vec<SeasonUser>,
+        author: &str,
+    ) -> Result<RolloverReport> {
+        if new_season_id.eq_ignore_ascii_case("template") {
+            return Err(BotError::ConfigValidation {
+                message: "Cannot create a season named 'template'".to_string(),
+            });
+        }
+        if self.seasons.contains_key(new_season_id) {
+            return Err(BotError::ConfigValidation {
+                message: format!("Season '{}' already exists", new_season_id),
+            });
+        }
+
+        let (source_config, previous_users) = if source_id == "template" {
+            let path = format!("{}/seasons/template/season.json", self.data_path);
+            let config = if std::path::Path::new(&path).exists() {
+                SeasonConfig::load_from_file(&path)?
+            } else {
+                warn!("No seasons/template/season.json found, using default season config");
+                SeasonConfig::default()
+            };
+            (config, vec![])
+        } else {
+            let season = self
+                .seasons
+                .get(source_id)
+                .ok_or_else(|| BotError::ConfigNotFound {
+                    config_type: "season".to_string(),
+                    name: source_id.to_string(),
+                })?;
+            (season.config.clone(), season.users.clone())
+        };
+
+        let config = source_config.rolled_over(source_id, new_season_id, name);
+        let source_dir = format!("{}/seasons/{}", self.data_path, source_id);
+        let category_path = format!("{}/category.json", source_dir);
+        let category = if std::path::Path::new(&category_path).exists() {
+            let old_member_role = source_config
+                .member_role
+                .clone()
+                .unwrap_or_else(|| format!("Medlem{}", source_id));
+            let new_member_role = config
+                .member_role
+                .clone()
+                .unwrap_or_else(|| format!("Medlem{}", new_season_id));
+            Some(CategoryStructureConfig::load_from_file(&category_path)?.rolled_over(
+                source_id,
+                new_season_id,
+                &old_member_role,
+                &new_member_role,
+            ))
+        } else {
+            None
+        };
+        let roles_path = format!("{}/roles.json", source_dir);
+        let roles = if std::path::Path::new(&roles_path).exists() {
+            Some(
+                tokio::fs::read_to_string(&roles_path)
+                    .await
+                    .map_err(|e| BotError::ConfigLoad {
+                        path: roles_path.clone(),
+                        source: e,
+                    })?,
+            )
+        } else {
+            None
+        };
+        let returning: Vec<SeasonUser> = returning_users(&previous_users, &users)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let season = Season::new(new_season_id.to_string(), config, users);
+        let new_issues = self.validate_with_season(&season, category.as_ref());
+        if !new_issues.is_empty() {
+            return Err(new_issues_error(
+                &format!("season {}", new_season_id),
+                &new_issues,
+            ));
+        }
+
+        let mut files = vec![
+            ("season.json", serde_json::to_string_pretty(&season.config)?),
+            ("users.json", serde_json::to_string_pretty(&season.users)?),
+        ];
+        let mut files_copied = Vec::new();
+        if let Some(category) = &category {
+            files.push(("category.json", serde_json::to_string_pretty(category)?));
+            files_copied.push("category.json".to_string());
+        }
+        if let Some(roles) = roles {
+            files.push(("roles.json", roles));
+            files_copied.push("roles.json".to_string());
+        }
+        for (filename, content) in files {
+            let file = format!("seasons/{}/{}", new_season_id, filename);
+            self.write_config_file(&file, &content, author, "rollover").await?;
+        }
+
+        let report = RolloverReport {
+            season_id: new_season_id.to_string(),
+            source: source_id.to_string(),
+            name: season.name().to_string(),
+            member_role: season.member_role(),
+            channels_copied: season.channels().len(),
+            user_count: season.user_count(),
+            returning_users: returning,
+            files_copied,
+        };
+        self.seasons.insert(new_season_id.to_string(), season);
+        if let Some(category) = category {
+            self.category_structures.insert(new_season_id.to_string(), category);
+        }
+
+        info!(
+            "Rolled season '{}' over into '{}' ({} channels, {} of {} users returning)",
+            source_id,
+            new_season_id,
+            report.channels_copied,
+            report.returning_users.len(),
+            report.user_count
+        );
+
+        Ok(report)
+    }
+
     // ========== Query Operations ==========
 
     /// Get a season by ID
@@ -207,13 +701,16 @@ impl ConfigManager {
         &self.seasons
     }
 
-    /// Find a user in any active season by their verification ID
+    /// Find a user by their verification ID in any season currently accepting verification
+    ///
+    /// Seasons that are inactive, or outside their `starts_at`/`ends_at` window, are skipped.
     pub fn find_user_by_verification_id(
         &self,
         verification_id: &str,
     ) -> Option<(&Season, &SeasonUser)> {
+        let now = Utc::now();
         for season in self.seasons.values() {
-            if season.is_active() {
+            if season.accepts_verification_at(now) {
                 if let Some(user) = season.find_user_by_id(verification_id) {
                     return Some((season, user));
                 }
@@ -222,6 +719,38 @@ impl ConfigManager {
         None
     }
 
+    /// Explain why a verification ID is not currently accepted
+    ///
+    /// Returns None if the ID is unknown or its season is open for verification.
+    pub fn verification_closed_reason(&self, verification_id: &str) -> Option<String> {
+        let now = Utc::now();
+        let season = self
+            .seasons
+            .values()
+            .find(|s| s.find_user_by_id(verification_id).is_some())?;
+
+        match season.config.phase_at(now) {
+            SeasonPhase::NotStarted => Some(format!(
+                "Verification for {} has not opened yet. It opens on {}.",
+                season.name(),
+                season
+                    .config
+                    .starts_at
+                    .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+                    .unwrap_or_default()
+            )),
+            SeasonPhase::Ended => Some(format!(
+                "Verification for {} has closed.",
+                season.name()
+            )),
+            SeasonPhase::Running if !season.is_active() => Some(format!(
+                "Verification for {} is currently closed.",
+                season.name()
+            )),
+            SeasonPhase::Running => None,
+        }
+    }
+
     /// Get special roles for a user by their Discord username
     pub fn get_special_roles_for_user(&self, discord_username: &str) -> Vec<String> {
         let roles = self.special_members
@@ -258,6 +787,101 @@ impl ConfigManager {
         self.global_permissions.as_ref()
     }
 
+    /// All named permission levels, from global/structure.json and global/permissions.json
+    /// (permissions.json wins when both define a level)
+    pub fn get_permission_definitions(&self) -> HashMap<String, PermissionSet> {
+        let mut definitions = self
+            .global_structure
+            .as_ref()
+            .map(|s| s.permission_definitions.clone())
+            .unwrap_or_default();
+        if let Some(permissions) = &self.global_permissions {
+            definitions.extend(permissions.definitions.clone());
+        }
+        definitions
+    }
+
+    /// Get the global structure config (default channel layout)
+    pub fn get_global_structure(&self) -> Option<&GlobalStructureConfig> {
+        self.global_structure.as_ref()
+    }
+
+    /// Get every loaded category.json, sorted by season ID
+    pub fn get_all_category_structures(&self) -> Vec<&CategoryStructureConfig> {
+        let mut structures: Vec<_> = self.category_structures.values().collect();
+        structures.sort_by(|a, b| a.season_id.cmp(&b.season_id));
+        structures
+    }
+
+    /// Get a season's category.json, if it has one
+    pub fn get_category_structure(&self, season_id: &str) -> Option<&CategoryStructureConfig> {
+        self.category_structures.get(season_id)
+    }
+
+    /// Resolve the full category layout for a season
+    ///
+    /// Channels come from category.json's `channels` if set, then season.json's
+    /// `channels`, and otherwise the global default layout. category.json's
+    /// overrides and additions apply on top of either of the latter two.
+    /// Inherited channels that grant the global default member role are
+    /// pointed at the season's own member role instead.
+    pub fn get_season_structure(&self, season_id: &str) -> Option<MergedStructure> {
+        let season = self.seasons.get(season_id)?;
+        let empty = GlobalStructureConfig {
+            default_roles: vec![],
+            default_channels: vec![],
+            permission_presets: HashMap::new(),
+            permission_definitions: HashMap::new(),
+        };
+        let global = self.global_structure.as_ref().unwrap_or(&empty);
+        let category = self.category_structures.get(season_id);
+
+        let mut merged = match category {
+            Some(category) => category.merge_with_global(global),
+            None => MergedStructure {
+                category_name: String::new(),
+                roles: global.default_roles.clone(),
+                channels: global.default_channels.clone(),
+            },
+        };
+
+        if let Some(generic) = global.get_default_member_role() {
+            merged.rename_role(&generic.name.clone(), &season.member_role());
+        }
+
+        let explicit_channels = category.is_some_and(|c| !c.channels.is_empty());
+        if !explicit_channels && !season.channels().is_empty() {
+            let mut channels = season.channels().to_vec();
+            if let Some(category) = category {
+                for channel in &mut channels {
+                    if let Some(replacement) =
+                        category.channel_overrides.iter().find(|c| c.name == channel.name)
+                    {
+                        *channel = replacement.clone();
+                    }
+                }
+                for channel in &category.additional_channels {
+                    if !channels.iter().any(|c| c.name == channel.name) {
+                        channels.push(channel.clone());
+                    }
+                }
+            }
+            merged.channels = channels;
+        }
+
+        merged.category_name = category
+            .and_then(|c| c.category_name.clone())
+            .unwrap_or_else(|| season.name().to_string());
+
+        Some(merged)
+    }
+
+    /// Names of the Discord categories a season's channels live in
+    pub fn get_season_category_names(&self, season_id: &str) -> Option<Vec<String>> {
+        self.get_season_structure(season_id)
+            .map(|structure| structure.category_names())
+    }
+
     /// Get the special members (assignments) config
     pub fn get_special_members(&self) -> Option<&SpecialMembersConfig> {
         self.special_members.as_ref()
@@ -308,20 +932,77 @@ impl ConfigManager {
     }
 
     /// Generate diff between staged and current config
-    pub fn get_staged_diff(&self) -> ConfigDiff {
+    ///
+    /// Staged users are compared entry by entry against the loaded season.
+    /// `user_db` is used to flag verified users whose users.json entry would
+    /// be removed or get a new verification ID.
+    pub fn get_staged_diff(&self, user_db: &UserDatabase) -> ConfigDiff {
         let mut diff = ConfigDiff::new();
 
+        let verified_as = |verification_id: &str| {
+            user_db
+                .find_by_verification_id(verification_id)
+                .filter(|u| u.verification_status == VerificationStatus::Verified)
+                .map(|u| format!("{} (Discord ID {})", u.display_name, u.discord_id))
+        };
+
         // Compare seasons
-        for (season_id, staged) in &self.staged.seasons {
+        let mut season_ids: Vec<&String> = self.staged.seasons.keys().collect();
+        season_ids.sort();
+        for season_id in season_ids {
+            let staged = &self.staged.seasons[season_id];
             if let Some(current_season) = self.seasons.get(season_id) {
-                let added = staged.users.len() as i64 - current_season.user_count() as i64;
-                if added != 0 {
+                let users_diff = diff_season_users(&current_season.users, &staged.users);
+                if users_diff.is_empty() {
+                    continue;
+                }
+
+                diff.add_modification(ConfigChange::new(
+                    ConfigChangeType::Modify,
+                    "season",
+                    season_id,
+                    &format!("{} users ({})", staged.users.len(), users_diff.summary()),
+                ));
+
+                for user in &users_diff.added {
+                    diff.add_addition(ConfigChange::new(
+                        ConfigChangeType::Add,
+                        "user",
+                        &user.name,
+                        &format!("season {}, ID {}", season_id, user.id),
+                    ));
+                }
+
+                for (old, new) in &users_diff.changed {
                     diff.add_modification(ConfigChange::new(
                         ConfigChangeType::Modify,
-                        "season",
-                        season_id,
-                        &format!("{} users ({:+} change)", staged.users.len(), added),
+                        "user",
+                        &new.name,
+                        &format!("season {}: {}", season_id, describe_user_change(old, new)),
                     ));
+                    if old.id != new.id {
+                        if let Some(verified) = verified_as(&old.id) {
+                            diff.add_warning(format!(
+                                "{} in season {} is verified as {}; their verification ID would change from {} to {}",
+                                old.name, season_id, verified, old.id, new.id
+                            ));
+                        }
+                    }
+                }
+
+                for user in &users_diff.removed {
+                    diff.add_deletion(ConfigChange::new(
+                        ConfigChangeType::Remove,
+                        "user",
+                        &user.name,
+                        &format!("season {}, ID {}", season_id, user.id),
+                    ));
+                    if let Some(verified) = verified_as(&user.id) {
+                        diff.add_warning(format!(
+                            "{} in season {} is verified as {} and would lose their entry in users.json",
+                            user.name, season_id, verified
+                        ));
+                    }
                 }
             } else {
                 diff.add_addition(ConfigChange::new(
@@ -333,6 +1014,80 @@ impl ConfigManager {
             }
         }
 
+        // Compare season configs
+        for (season_id, config) in &self.staged.season_configs {
+            let details = format!("season.json ({} channels)", config.channels.len());
+            if self.seasons.contains_key(season_id) {
+                diff.add_modification(ConfigChange::new(
+                    ConfigChangeType::Modify,
+                    "season",
+                    season_id,
+                    &details,
+                ));
+            } else {
+                diff.add_addition(ConfigChange::new(
+                    ConfigChangeType::Add,
+                    "season",
+                    season_id,
+                    &details,
+                ));
+            }
+        }
+
+        // Compare global role and permission definitions
+        if let Some(staged_roles) = &self.staged.global_roles {
+            diff.add_modification(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "roles",
+                "roles.json",
+                &format!("{} roles defined", staged_roles.roles.len()),
+            ));
+        }
+        if let Some(staged_permissions) = &self.staged.global_permissions {
+            diff.add_modification(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "permissions",
+                "permissions.json",
+                &format!("{} levels defined", staged_permissions.definitions.len()),
+            ));
+        }
+
+        // Compare layouts
+        if let Some(staged_structure) = &self.staged.global_structure {
+            diff.add_modification(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "structure",
+                "structure.json",
+                &format!("{} default channels", staged_structure.default_channels.len()),
+            ));
+        }
+        for (season_id, category) in &self.staged.category_structures {
+            let details = format!("category.json ({} channels)", category.channels.len());
+            if self.category_structures.contains_key(season_id) {
+                diff.add_modification(ConfigChange::new(
+                    ConfigChangeType::Modify,
+                    "category",
+                    season_id,
+                    &details,
+                ));
+            } else {
+                diff.add_addition(ConfigChange::new(
+                    ConfigChangeType::Add,
+                    "category",
+                    season_id,
+                    &details,
+                ));
+            }
+        }
+        for season_id in self.staged.season_roles.keys() {
+            diff.add_modification(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "season",
+                season_id,
+                "roles.json",
+            ));
+        }
+
         // Compare special members
         if let Some(staged_sm) = &self.staged.special_members {
             if self.special_members.is_some() {
@@ -356,10 +1111,11 @@ impl ConfigManager {
     }
 
     /// Stage users for a season from bytes with explicit season ID
+    /// A `.csv` filename is read as a CSV export using the season's column mapping
     pub fn stage_season_from_bytes_with_id(
         &mut self,
         data: &[u8],
-        _filename: &str,
+        filename: &str,
         season_id: Option<&str>,
         staged_by: Option<String>,
     ) -> Result<()> {
@@ -367,16 +1123,38 @@ impl ConfigManager {
             message: "Season ID is required".to_string(),
         })?;
 
-        let users: Vec<SeasonUser> = serde_json::from_slice(data).map_err(|e| {
-            BotError::ConfigValidation {
-                message: format!("Failed to parse users JSON: {}", e),
-            }
-        })?;
+        let users = self.parse_season_users(data, filename, sid)?;
 
         self.staged.stage_season_users(sid.to_string(), users, staged_by);
         Ok(())
     }
 
+    /// CSV column mapping for a season (staged season.json first, then the loaded one)
+    pub fn csv_columns_for(&self, season_id: &str) -> CsvColumns {
+        self.staged
+            .season_configs
+            .get(season_id)
+            .or(self.seasons.get(season_id).map(|s| &s.config))
+            .and_then(|c| c.csv_columns.clone())
+            .unwrap_or_default()
+    }
+
+    /// Parse an uploaded users file as JSON or, for `.csv` files, as a CSV export
+    fn parse_season_users(
+        &self,
+        data: &[u8],
+        filename: &str,
+        season_id: &str,
+    ) -> Result<Vec<SeasonUser>> {
+        if filename.to_lowercase().ends_with(".csv") {
+            return parse_users_csv(data, &self.csv_columns_for(season_id));
+        }
+
+        serde_json::from_slice(data).map_err(|e| BotError::ConfigValidation {
+            message: format!("Failed to parse users JSON: {}", e),
+        })
+    }
+
     /// Stage users for a season from bytes (derives season_id from filename)
     pub fn stage_season_from_bytes(
         &mut self,
@@ -384,13 +1162,13 @@ impl ConfigManager {
         filename: &str,
         staged_by: Option<String>,
     ) -> Result<String> {
-        let season_id = filename.trim_end_matches(".json").to_uppercase();
+        let lower = filename.to_lowercase();
+        let season_id = lower
+            .trim_end_matches(".json")
+            .trim_end_matches(".csv")
+            .to_uppercase();
 
-        let users: Vec<SeasonUser> = serde_json::from_slice(data).map_err(|e| {
-            BotError::ConfigValidation {
-                message: format!("Failed to parse users JSON: {}", e),
-            }
-        })?;
+        let users = self.parse_season_users(data, filename, &season_id)?;
 
         let user_count = users.len();
         self.staged.stage_season_users(season_id.clone(), users, staged_by);
@@ -441,40 +1219,285 @@ impl ConfigManager {
         }
     }
 
+    /// Stage season.json with an adopted category entry
+    ///
+    /// Starts from the staged season.json, then the loaded one, and otherwise
+    /// a new inactive season. An existing entry with the same name is replaced.
+    /// Returns a summary with notes on how the season's layout changes.
+    pub fn stage_adopted_category(
+        &mut self,
+        season_id: &str,
+        category: ChannelDefinition,
+        staged_by: Option<String>,
+    ) -> String {
+        let mut config = self
+            .staged
+            .season_configs
+            .get(season_id)
+            .or(self.seasons.get(season_id).map(|s| &s.config))
+            .cloned()
+            .unwrap_or_else(|| SeasonConfig {
+                name: season_id.to_string(),
+                active: false,
+                member_role: None,
+                starts_at: None,
+                ends_at: None,
+                channels: vec![],
+                csv_columns: None,
+            });
+
+        let mut notes = Vec::new();
+        if !self.seasons.contains_key(season_id) {
+            notes.push(format!("Season {} will be created as inactive.", season_id));
+        } else if config.channels.is_empty() {
+            notes.push(
+                "The season had no channels in season.json, so it stops inheriting the default layout."
+                    .to_string(),
+            );
+        }
+        if self
+            .category_structures
+            .get(season_id)
+            .is_some_and(|c| !c.channels.is_empty())
+        {
+            notes.push(
+                "category.json lists channels of its own, which take precedence over season.json."
+                    .to_string(),
+            );
+        }
+
+        let name = category.name.clone();
+        match config
+            .channels
+            .iter_mut()
+            .find(|c| c.channel_type == ChannelType::Category && c.name == name)
+        {
+            Some(existing) => {
+                *existing = category;
+                notes.push(format!("Replaced the existing '{}' entry.", name));
+            }
+            None => config.channels.push(category),
+        }
+
+        self.staged
+            .stage_season_config(season_id.to_string(), config, staged_by);
+        let mut summary = format!("Staged season.json for season {} with category '{}'", season_id, name);
+        for note in notes {
+            summary.push_str(&format!("\n{}", note));
+        }
+        summary
+    }
+
+    /// Stage a config file by its path relative to the data directory
+    ///
+    /// Used to restore a version from the config history through the normal
+    /// preview/commit flow.
+    pub fn stage_file(
+        &mut self,
+        file: &str,
+        data: &[u8],
+        staged_by: Option<String>,
+    ) -> Result<String> {
+        let parse_error = |e: serde_json::Error| BotError::ConfigValidation {
+            message: format!("Failed to parse {}: {}", file, e),
+        };
+
+        match file.split('/').collect::<Vec<_>>().as_slice() {
+            ["global", "roles.json"] => {
+                let config: GlobalRolesConfig = serde_json::from_slice(data).map_err(parse_error)?;
+                let count = config.roles.len();
+                self.staged.stage_global_roles(config, staged_by);
+                Ok(format!("Staged roles.json with {} roles", count))
+            }
+            ["global", "permissions.json"] => {
+                let config: GlobalPermissionsConfig =
+                    serde_json::from_slice(data).map_err(parse_error)?;
+                let count = config.definitions.len();
+                self.staged.stage_global_permissions(config, staged_by);
+                Ok(format!("Staged permissions.json with {} levels", count))
+            }
+            ["global", "assignments.json"] => self.stage_special_members_from_bytes(data, staged_by),
+            ["seasons", season_id, "season.json"] => {
+                let config: SeasonConfig = serde_json::from_slice(data).map_err(parse_error)?;
+                self.staged
+                    .stage_season_config(season_id.to_string(), config, staged_by);
+                Ok(format!("Staged season.json for season {}", season_id))
+            }
+            ["seasons", season_id, "users.json"] => {
+                self.stage_season_from_bytes_with_id(data, file, Some(season_id), staged_by)?;
+                Ok(format!("Staged users.json for season {}", season_id))
+            }
+            ["seasons", season_id, "category.json"] => {
+                let mut config: CategoryStructureConfig =
+                    serde_json::from_slice(data).map_err(parse_error)?;
+                if config.season_id.is_empty() {
+                    config.season_id = season_id.to_string();
+                }
+                self.staged
+                    .stage_category_structure(season_id.to_string(), config, staged_by);
+                Ok(format!("Staged category.json for season {}", season_id))
+            }
+            ["seasons", season_id, "roles.json"] => {
+                let content: serde_json::Value =
+                    serde_json::from_slice(data).map_err(parse_error)?;
+                self.staged
+                    .stage_season_roles(season_id.to_string(), content, staged_by);
+                Ok(format!("Staged roles.json for season {}", season_id))
+            }
+            ["global", "structure.json"] => {
+                let config: GlobalStructureConfig =
+                    serde_json::from_slice(data).map_err(parse_error)?;
+                let count = config.default_channels.len();
+                self.staged.stage_global_structure(config, staged_by);
+                Ok(format!("Staged structure.json with {} default channels", count))
+            }
+            _ => Err(BotError::ConfigNotFound {
+                config_type: "file".to_string(),
+                name: file.to_string(),
+            }),
+        }
+    }
+
+    /// Write a config file and record the new version in the config history
+    ///
+    /// `file` is relative to the data directory. A failure to record history
+    /// is logged but does not fail the write.
+    pub async fn write_config_file(
+        &self,
+        file: &str,
+        content: &str,
+        author: &str,
+        action: &str,
+    ) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let path = std::path::Path::new(&self.data_path).join(file);
+        let path_str = path.display().to_string();
+        let previous = tokio::fs::read_to_string(&path).await.ok();
+
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.ok();
+        }
+
+        // Sync to disk so a reload right after the write sees the new content
+        let mut handle = tokio::fs::File::create(&path)
+            .await
+            .map_err(|e| BotError::StateSave {
+                path: path_str.clone(),
+                source: e,
+            })?;
+        handle
+            .write_all(content.as_bytes())
+            .await
+            .map_err(|e| BotError::StateSave {
+                path: path_str.clone(),
+                source: e,
+            })?;
+        if let Err(e) = handle.sync_all().await {
+            warn!("Failed to sync {} to disk: {}", path_str, e);
+        }
+
+        if let Err(e) = self
+            .history
+            .record(file, previous.as_deref(), content, author, action)
+            .await
+        {
+            warn!("Failed to record config history for {}: {}", file, e);
+        }
+
+        Ok(())
+    }
+
+    /// Get the config history
+    pub fn history(&self) -> &ConfigHistory {
+        &self.history
+    }
+
     /// Commit staged configuration
     pub async fn commit_staged(&mut self) -> Result<Vec<ConfigChange>> {
         if self.staged.is_empty() {
             return Err(BotError::NoStagedConfig);
         }
 
+        // Refuse to commit if the staged config introduces new problems
+        let new_issues = self.validate_with_staged().new_since(&self.validate());
+        if !new_issues.is_empty() {
+            return Err(new_issues_error("staged config", &new_issues));
+        }
+
         let mut changes = Vec::new();
+        let author = self
+            .staged
+            .staged_by
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+        // Items leave the staged config only once written, so a failed write
+        // keeps the rest staged for another attempt
+
+        // Commit season configs first so staged users land in the right season
+        let mut season_ids: Vec<String> = self.staged.season_configs.keys().cloned().collect();
+        season_ids.sort();
+        for season_id in season_ids {
+            let config = self.staged.season_configs[&season_id].clone();
+            let content = serde_json::to_string_pretty(&config)?;
+            self.write_config_file(
+                &format!("seasons/{}/season.json", season_id),
+                &content,
+                &author,
+                "commit",
+            )
+            .await?;
+            self.staged.season_configs.remove(&season_id);
 
-        // Commit seasons
-        for (season_id, staged) in self.staged.seasons.drain() {
-            let dir_path = format!("{}/seasons/{}", self.data_path, season_id);
-            let users_path = format!("{}/users.json", dir_path);
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "season",
+                &season_id,
+                &format!("Saved season.json ({} channels)", config.channels.len()),
+            ));
 
-            tokio::fs::create_dir_all(&dir_path).await.ok();
+            if let Some(season) = self.seasons.get_mut(&season_id) {
+                season.config = config;
+            } else {
+                let season = Season::new(season_id.clone(), config, vec![]);
+                self.seasons.insert(season_id, season);
+            }
+        }
 
+        // Commit seasons
+        let mut season_ids: Vec<String> = self.staged.seasons.keys().cloned().collect();
+        season_ids.sort();
+        for season_id in season_ids {
             // Save users as array
-            let content = serde_json::to_string_pretty(&staged.users)?;
-            tokio::fs::write(&users_path, content)
-                .await
-                .map_err(|e| BotError::StateSave {
-                    path: users_path.clone(),
-                    source: e,
-                })?;
-
+            let users = self.staged.seasons[&season_id].users.clone();
+            let content = serde_json::to_string_pretty(&users)?;
+            self.write_config_file(
+                &format!("seasons/{}/users.json", season_id),
+                &content,
+                &author,
+                "commit",
+            )
+            .await?;
+            self.staged.seasons.remove(&season_id);
+
+            let details = match self.seasons.get(&season_id) {
+                Some(current) => format!(
+                    "Saved {} users ({})",
+                    users.len(),
+                    diff_season_users(&current.users, &users).summary()
+                ),
+                None => format!("Saved {} users", users.len()),
+            };
             changes.push(ConfigChange::new(
                 ConfigChangeType::Add,
                 "season",
                 &season_id,
-                &format!("Saved {} users", staged.users.len()),
+                &details,
             ));
 
             // Update in-memory season
             if let Some(season) = self.seasons.get_mut(&season_id) {
-                season.users = staged.users;
+                season.users = users;
             } else {
                 let season = Season::new(
                     season_id.clone(),
@@ -482,28 +1505,69 @@ impl ConfigManager {
                         name: season_id.clone(),
                         active: true,
                         member_role: None, // Will fallback to "Medlem{season_id}"
+                        starts_at: None,
+                        ends_at: None,
                         channels: vec![],
+                        csv_columns: None,
                     },
-                    staged.users,
+                    users,
                 );
                 self.seasons.insert(season_id, season);
             }
         }
 
-        // Commit special members to global/assignments.json
-        if let Some(config) = self.staged.special_members.take() {
-            let dir_path = format!("{}/global", self.data_path);
-            let path = format!("{}/assignments.json", dir_path);
+        // Commit layout overrides and season role files
+        let mut season_ids: Vec<String> = self.staged.category_structures.keys().cloned().collect();
+        season_ids.sort();
+        for season_id in season_ids {
+            let config = self.staged.category_structures[&season_id].clone();
+            let content = serde_json::to_string_pretty(&config)?;
+            self.write_config_file(
+                &format!("seasons/{}/category.json", season_id),
+                &content,
+                &author,
+                "commit",
+            )
+            .await?;
+            self.staged.category_structures.remove(&season_id);
 
-            tokio::fs::create_dir_all(&dir_path).await.ok();
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "category",
+                &season_id,
+                &format!("Saved category.json ({} channels)", config.channels.len()),
+            ));
+
+            self.category_structures.insert(season_id, config);
+        }
+
+        let mut season_ids: Vec<String> = self.staged.season_roles.keys().cloned().collect();
+        season_ids.sort();
+        for season_id in season_ids {
+            let content = serde_json::to_string_pretty(&self.staged.season_roles[&season_id])?;
+            self.write_config_file(
+                &format!("seasons/{}/roles.json", season_id),
+                &content,
+                &author,
+                "commit",
+            )
+            .await?;
+            self.staged.season_roles.remove(&season_id);
+
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "season",
+                &season_id,
+                "Saved roles.json",
+            ));
+        }
 
+        // Commit special members to global/assignments.json
+        if let Some(config) = self.staged.special_members.clone() {
             let content = serde_json::to_string_pretty(&config)?;
-            tokio::fs::write(&path, content)
-                .await
-                .map_err(|e| BotError::StateSave {
-                    path: path.clone(),
-                    source: e,
-                })?;
+            self.write_config_file("global/assignments.json", &content, &author, "commit")
+                .await?;
+            self.staged.special_members = None;
 
             changes.push(ConfigChange::new(
                 ConfigChangeType::Add,
@@ -515,6 +1579,55 @@ impl ConfigManager {
             self.special_members = Some(config);
         }
 
+        // Commit role and permission definitions and the default layout
+        if let Some(config) = self.staged.global_roles.clone() {
+            let content = serde_json::to_string_pretty(&config)?;
+            self.write_config_file("global/roles.json", &content, &author, "commit")
+                .await?;
+            self.staged.global_roles = None;
+
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "roles",
+                "roles.json",
+                &format!("{} roles", config.roles.len()),
+            ));
+
+            self.global_roles = Some(config);
+        }
+
+        if let Some(config) = self.staged.global_permissions.clone() {
+            let content = serde_json::to_string_pretty(&config)?;
+            self.write_config_file("global/permissions.json", &content, &author, "commit")
+                .await?;
+            self.staged.global_permissions = None;
+
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "permissions",
+                "permissions.json",
+                &format!("{} levels", config.definitions.len()),
+            ));
+
+            self.global_permissions = Some(config);
+        }
+
+        if let Some(config) = self.staged.global_structure.clone() {
+            let content = serde_json::to_string_pretty(&config)?;
+            self.write_config_file("global/structure.json", &content, &author, "commit")
+                .await?;
+            self.staged.global_structure = None;
+
+            changes.push(ConfigChange::new(
+                ConfigChangeType::Modify,
+                "structure",
+                "structure.json",
+                &format!("{} default channels", config.default_channels.len()),
+            ));
+
+            self.global_structure = Some(config);
+        }
+
         self.staged.clear();
         Ok(changes)
     }
@@ -592,6 +1705,12 @@ impl ConfigManager {
                 "Permission definitions".to_string(),
             ));
         }
+        if self.global_structure.is_some() {
+            files.push((
+                "global/structure.json".to_string(),
+                "Default channel layout".to_string(),
+            ));
+        }
 
         // Seasons
         for (season_id, season) in &self.seasons {
@@ -603,6 +1722,12 @@ impl ConfigManager {
                 format!("seasons/{}/users.json", season_id),
                 format!("{} users", season.user_count()),
             ));
+            if self.category_structures.contains_key(season_id) {
+                files.push((
+                    format!("seasons/{}/category.json", season_id),
+                    "Category structure".to_string(),
+                ));
+            }
         }
 
         files
@@ -625,9 +1750,184 @@ impl ConfigManager {
     }
 }
 
+/// Compare two serializable values by their JSON form
+fn json_eq<T: serde::Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Refusal of a change that would introduce `issues`
+fn new_issues_error(what: &str, issues: &[ValidationIssue]) -> BotError {
+    BotError::ConfigValidation {
+        message: format!(
+            "{} introduces {} problem(s):\n{}",
+            what,
+            issues.len(),
+            issues
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+    }
+}
+
 /// Shared config manager type
 pub type SharedConfigManager = Arc<tokio::sync::RwLock<ConfigManager>>;
 
 pub fn create_shared_config_manager(data_path: &str) -> SharedConfigManager {
     Arc::new(tokio::sync::RwLock::new(ConfigManager::new(data_path)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn season(season_id: &str, users: usize) -> Season {
+        let users = (0..users)
+            .map(|i| SeasonUser {
+                name: format!("User {}", i),
+                id: format!("{}-{}", season_id, i),
+                email: None,
+            })
+            .collect();
+        Season::new(season_id.to_string(), SeasonConfig::default(), users)
+    }
+
+    #[test]
+    fn test_diff_loaded() {
+        let mut current = ConfigManager::new("data");
+        current.seasons.insert("2025E".to_string(), season("2025E", 2));
+        current.seasons.insert("2024F".to_string(), season("2024F", 1));
+
+        let mut fresh = ConfigManager::new("data");
+        let mut changed = season("2025E", 3);
+        changed.config.active = false;
+        fresh.seasons.insert("2025E".to_string(), changed);
+        fresh.seasons.insert("2026F".to_string(), season("2026F", 0));
+
+        assert_eq!(
+            current.diff_loaded(&fresh),
+            vec![
+                "- season 2024F",
+                "~ season 2025E: active true → false, users 2 → 3",
+                "+ season 2026F (0 users, 1 channels)",
+            ]
+        );
+        assert!(fresh.diff_loaded(&fresh).is_empty());
+    }
+
+    #[test]
+    fn test_season_structure_inherits_global_layout() {
+        let mut config = ConfigManager::new("data");
+        config.global_structure = Some(GlobalStructureConfig::default());
+
+        let mut inherited = season("2025E", 0);
+        inherited.config.channels.clear();
+        config.seasons.insert("2025E".to_string(), inherited);
+        config.category_structures.insert(
+            "2025E".to_string(),
+            serde_json::from_str(
+                r#"{ "category_name": "2025 Efterår",
+                     "additional_channels": [{ "name": "tutors", "type": "text" }] }"#,
+            )
+            .unwrap(),
+        );
+        config.seasons.insert("2026F".to_string(), season("2026F", 0));
+
+        let merged = config.get_season_structure("2025E").unwrap();
+        assert_eq!(merged.category_name, "2025 Efterår");
+        let names: Vec<&str> = merged.channels.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["general", "tutors"]);
+        assert!(merged.channels[0].role_permissions.contains_key("Medlem2025E"));
+        assert_eq!(merged.roles[0].name, "Medlem2025E");
+
+        // Channels in season.json replace the inherited layout
+        let own = config.get_season_structure("2026F").unwrap();
+        assert_eq!(own.category_name, config.seasons["2026F"].name());
+        assert!(json_eq(&own.channels, &config.seasons["2026F"].config.channels));
+
+        // category.json's additions still apply on top of them
+        config.category_structures.insert(
+            "2026F".to_string(),
+            serde_json::from_str(r#"{ "additional_channels": [{ "name": "tutors", "type": "text" }] }"#)
+                .unwrap(),
+        );
+        let own = config.get_season_structure("2026F").unwrap();
+        let names: Vec<&str> = own.channels.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["general", "tutors"]);
+    }
+
+    #[tokio::test]
+    async fn test_rollover_copies_category_and_roles() {
+        let root = std::env::temp_dir()
+            .join(format!("eventy-rollover-{}", Utc::now().timestamp_millis()));
+        let source = root.join("seasons/2025E");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(source.join("category.json"), r#"{ "category_name": "2025E" }"#).unwrap();
+        std::fs::write(source.join("roles.json"), r#"{ "roles": {} }"#).unwrap();
+
+        let mut config = ConfigManager::new(root.to_str().unwrap());
+        config.history = ConfigHistory::new(root.join("history"));
+        config.seasons.insert("2025E".to_string(), season("2025E", 0));
+        let roles = |names: &[&str]| GlobalRolesConfig {
+            roles: names
+                .iter()
+                .map(|name| serde_json::from_value(serde_json::json!({ "name": name })).unwrap())
+                .collect(),
+        };
+
+        // A member role missing from roles.json is refused before anything is written
+        config.global_roles = Some(roles(&["Medlem"]));
+        let err = config
+            .rollover_season("2025E", "2026F", None, vec![], "alice")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'Medlem2026F' is not defined"));
+        assert!(!root.join("seasons/2026F").exists());
+
+        config.global_roles = Some(roles(&["Medlem", "Medlem2026F"]));
+        let report = config
+            .rollover_season("2025E", "2026F", None, vec![], "alice")
+            .await
+            .unwrap();
+
+        assert_eq!(report.files_copied, vec!["category.json", "roles.json"]);
+        assert_eq!(config.category_structures["2026F"].get_category_name(), "2026F");
+        let roles = std::fs::read_to_string(root.join("seasons/2026F/roles.json")).unwrap();
+        assert_eq!(roles, r#"{ "roles": {} }"#);
+        let versions = config.history().list_for_file("seasons/2026F/season.json").await;
+        assert_eq!(versions[0].author, "alice");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[tokio::test]
+    async fn test_failed_commit_keeps_unwritten_files_staged() {
+        let root = std::env::temp_dir()
+            .join(format!("eventy-commit-{}", Utc::now().timestamp_millis()));
+        std::fs::create_dir_all(root.join("seasons")).unwrap();
+        // A file where the season directory should be makes writes into it fail
+        std::fs::write(root.join("seasons/2025E"), "").unwrap();
+
+        let mut config = ConfigManager::new(root.to_str().unwrap());
+        config.history = ConfigHistory::new(root.join("history"));
+        for (file, data) in [
+            ("seasons/2024F/category.json", r#"{ "channels": [] }"#),
+            ("seasons/2025E/category.json", r#"{ "channels": [] }"#),
+            ("seasons/2024F/roles.json", r#"{ "roles": {} }"#),
+            ("global/structure.json", r#"{ "default_channels": [] }"#),
+        ] {
+            config.stage_file(file, data.as_bytes(), None).unwrap();
+        }
+
+        assert!(config.commit_staged().await.is_err());
+        assert!(root.join("seasons/2024F/category.json").exists());
+        assert!(config.category_structures.contains_key("2024F"));
+        let still_staged: Vec<&String> = config.staged.category_structures.keys().collect();
+        assert_eq!(still_staged, vec!["2025E"]);
+        assert!(config.staged.season_roles.contains_key("2024F"));
+        assert!(config.staged.global_structure.is_some());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
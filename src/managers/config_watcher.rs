@@ -0,0 +1,203 @@
+use notify::{EventKind, RecursiveMode, Watcher};
+use poise::serenity_prelude::{GuildId, Http};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use crate::config::ValidationIssue;
+use crate::managers::{ConfigManager, SharedConfigManager, SharedMaintainersManager};
+
+/// How long to wait for more file events before reloading
+const DEBOUNCE: Duration = Duration::from_millis(750);
+
+/// Most change lines to include in a maintainers channel notice
+const MAX_NOTICE_LINES: usize = 20;
+
+/// Reloads the config when files under the data directory change
+///
+/// A reload loads the whole tree into a fresh ConfigManager and only swaps it
+/// in if it introduces no new validation problems, so a half-written or broken
+/// file never replaces a working config.
+pub struct ConfigWatcher {
+    config_manager: SharedConfigManager,
+    maintainers_manager: SharedMaintainersManager,
+    http: Arc<Http>,
+    guild_id: Option<GuildId>,
+    /// Problems from the last rejected reload, so the same rejection is only reported once
+    last_rejected: Vec<ValidationIssue>,
+}
+
+impl ConfigWatcher {
+    pub fn new(
+        config_manager: SharedConfigManager,
+        maintainers_manager: SharedMaintainersManager,
+        http: Arc<Http>,
+        guild_id: Option<GuildId>,
+    ) -> Self {
+        Self {
+            config_manager,
+            maintainers_manager,
+            http,
+            guild_id,
+            last_rejected: Vec::new(),
+        }
+    }
+
+    /// Watch the data directory and reload on changes until the watcher fails
+    pub async fn run(mut self) {
+        let data_path = self.config_manager.read().await.get_data_path().to_string();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let mut watcher = match notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            match res {
+                Ok(event) if is_config_change(&event) => {
+                    let _ = tx.send(());
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Config watcher error: {}", e),
+            }
+        }) {
+            Ok(w) => w,
+            Err(e) => {
+                error!("Failed to create config watcher: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = watcher.watch(Path::new(&data_path), RecursiveMode::Recursive) {
+            error!("Failed to watch '{}' for config changes: {}", data_path, e);
+            return;
+        }
+        info!("Watching '{}' for config changes", data_path);
+
+        while rx.recv().await.is_some() {
+            // Let editors finish writing, then collapse the burst into one reload
+            tokio::time::sleep(DEBOUNCE).await;
+            while rx.try_recv().is_ok() {}
+
+            self.reload(&data_path).await;
+        }
+    }
+
+    /// Load the config tree from disk and swap it in if it is valid
+    async fn reload(&mut self, data_path: &str) {
+        debug!("Config files changed, reloading '{}'", data_path);
+
+        let mut fresh = ConfigManager::new(data_path);
+        if let Err(e) = fresh.load_all().await {
+            error!("Failed to reload config: {}", e);
+            return;
+        }
+
+        let mut config = self.config_manager.write().await;
+
+        let new_issues = fresh.validate().new_since(&config.validate());
+        if !new_issues.is_empty() {
+            drop(config);
+            if new_issues != self.last_rejected {
+                let lines: Vec<String> = new_issues.iter().map(|i| i.to_string()).collect();
+                warn!(
+                    "Config change on disk not applied, {} problem(s):\n{}",
+                    lines.len(),
+                    lines.join("\n")
+                );
+                self.notify(&format!(
+                    "⚠️ **Config change on disk not applied** ({} problem(s)):\n{}",
+                    lines.len(),
+                    format_lines(&lines)
+                ))
+                .await;
+                self.last_rejected = new_issues;
+            }
+            return;
+        }
+        self.last_rejected.clear();
+
+        let changes = config.diff_loaded(&fresh);
+        if changes.is_empty() {
+            debug!("Config reload found no changes");
+            return;
+        }
+
+        config.replace_loaded(fresh);
+        drop(config);
+
+        info!(
+            "Config reloaded from disk ({} change(s)):\n{}",
+            changes.len(),
+            changes.join("\n")
+        );
+        self.notify(&format!(
+            "🔄 **Config reloaded from disk** ({} change(s)):\n{}",
+            changes.len(),
+            format_lines(&changes)
+        ))
+        .await;
+    }
+
+    async fn notify(&self, message: &str) {
+        let Some(guild_id) = self.guild_id else {
+            return;
+        };
+        let maintainers_manager = self.maintainers_manager.read().await;
+        if let Err(e) = maintainers_manager
+            .post_notice(&self.http, guild_id, message)
+            .await
+        {
+            warn!("Failed to post config reload notice to maintainers channel: {}", e);
+        }
+    }
+}
+
+/// Whether a file event could affect the loaded config
+fn is_config_change(event: &notify::Event) -> bool {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    event
+        .paths
+        .iter()
+        .any(|p| p.extension().is_some_and(|ext| ext == "json"))
+}
+
+/// Format change lines as a code block, capped to fit in a Discord message
+fn format_lines(lines: &[String]) -> String {
+    let mut shown: Vec<&str> = lines
+        .iter()
+        .take(MAX_NOTICE_LINES)
+        .map(String::as_str)
+        .collect();
+    let more = lines.len().saturating_sub(MAX_NOTICE_LINES);
+    let more_line = format!("… and {} more", more);
+    if more > 0 {
+        shown.push(&more_line);
+    }
+    format!("```\n{}\n```", shown.join("\n"))
+}
+
+/// Start the config watcher as a background task
+pub fn spawn_config_watcher(
+    config_manager: SharedConfigManager,
+    maintainers_manager: SharedMaintainersManager,
+    http: Arc<Http>,
+    guild_id: Option<GuildId>,
+) {
+    let watcher = ConfigWatcher::new(config_manager, maintainers_manager, http, guild_id);
+    tokio::spawn(watcher.run());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_lines_caps_output() {
+        let lines: Vec<String> = (0..25).map(|i| format!("+ season S{}", i)).collect();
+        let formatted = format_lines(&lines);
+        assert!(formatted.contains("+ season S19"));
+        assert!(!formatted.contains("+ season S20"));
+        assert!(formatted.contains("… and 5 more"));
+    }
+}
@@ -0,0 +1,732 @@
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{self as serenity, ChannelId, GuildId, Http, RoleId};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use super::sync_plan::{GuildSnapshot, PlanAction};
+use crate::error::{BotError, Result};
+use crate::managers::{
+    SharedChannelManager, SharedConfigManager, SharedMaintainersManager, SharedRoleManager,
+};
+use crate::state::channel_state::GuildChannelState;
+use crate::state::{channel_state_path, EntityType, SharedChannelState};
+
+/// Minutes between drift checks unless DRIFT_CHECK_INTERVAL_MINUTES is set
+const DEFAULT_CHECK_INTERVAL_MINUTES: u64 = 360;
+
+/// Longest drift notice posted in the maintainers channel
+const MAX_NOTICE_LEN: usize = 1800;
+
+/// How a drifted item is brought back in line with the config
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DriftScope {
+    /// Sync this role from `roles.json`
+    Role(String),
+    /// Review and apply the season's sync plan
+    Season(String),
+    /// Sync one channel of a season, or the category itself without a channel
+    SeasonChannel {
+        season_id: String,
+        category: String,
+        channel: Option<String>,
+    },
+    /// Rename the channel back to the name recorded in the channel state
+    RenamedChannel { channel_id: ChannelId, name: String },
+    /// Forget a channel state entry whose Discord entity is gone
+    StaleEntry { kind: EntityType, name: String },
+}
+
+impl DriftScope {
+    /// Heading the item is listed under
+    pub fn group(&self) -> String {
+        match self {
+            DriftScope::Role(_) => "roles".to_string(),
+            DriftScope::Season(id) | DriftScope::SeasonChannel { season_id: id, .. } => {
+                format!("season {}", id)
+            }
+            DriftScope::RenamedChannel { .. } | DriftScope::StaleEntry { .. } => {
+                "channel state".to_string()
+            }
+        }
+    }
+
+    /// Encode the scope for a form field, e.g. "role:Board"
+    pub fn key(&self) -> String {
+        match self {
+            DriftScope::Role(name) => format!("role:{}", name),
+            DriftScope::Season(id) => format!("season:{}", id),
+            // Names are encoded since category names may contain ':'
+            DriftScope::SeasonChannel {
+                season_id,
+                category,
+                channel,
+            } => {
+                let mut key = format!(
+                    "season-channel:{}:{}",
+                    season_id,
+                    urlencoding::encode(category)
+                );
+                if let Some(channel) = channel {
+                    key.push(':');
+                    key.push_str(&urlencoding::encode(channel));
+                }
+                key
+            }
+            DriftScope::RenamedChannel { channel_id, name } => {
+                format!("channel:{}:{}", channel_id, name)
+            }
+            DriftScope::StaleEntry { kind, name } => format!("entry:{}:{}", kind.as_str(), name),
+        }
+    }
+
+    /// Parse a scope encoded by `key`
+    pub fn from_key(key: &str) -> Option<Self> {
+        let (prefix, rest) = key.split_once(':')?;
+        match prefix {
+            "role" => Some(DriftScope::Role(rest.to_string())),
+            "season" => Some(DriftScope::Season(rest.to_string())),
+            "season-channel" => {
+                let decode = |s: &str| urlencoding::decode(s).ok().map(|s| s.into_owned());
+                let (season_id, names) = rest.split_once(':')?;
+                let (category, channel) = match names.split_once(':') {
+                    Some((category, channel)) => (category, Some(decode(channel)?)),
+                    None => (names, None),
+                };
+                Some(DriftScope::SeasonChannel {
+                    season_id: season_id.to_string(),
+                    category: decode(category)?,
+                    channel,
+                })
+            }
+            "channel" => {
+                let (id, name) = rest.split_once(':')?;
+                Some(DriftScope::RenamedChannel {
+                    channel_id: ChannelId::new(id.parse().ok()?),
+                    name: name.to_string(),
+                })
+            }
+            "entry" => {
+                let (kind, name) = rest.split_once(':')?;
+                Some(DriftScope::StaleEntry {
+                    kind: EntityType::parse(kind)?,
+                    name: name.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// One difference between Discord and the config or channel state
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DriftItem {
+    pub scope: DriftScope,
+    /// Diff-style description, e.g. "~ role Board: hoist false → true"
+    pub line: String,
+}
+
+/// Result of one drift check
+#[derive(Debug, Clone)]
+pub struct DriftReport {
+    pub checked_at: DateTime<Utc>,
+    pub items: Vec<DriftItem>,
+    pub warnings: Vec<String>,
+}
+
+impl DriftReport {
+    /// Whether Discord matches the config
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Items grouped under their headings, in report order
+    pub fn groups(&self) -> Vec<(String, Vec<&DriftItem>)> {
+        let mut groups: Vec<(String, Vec<&DriftItem>)> = Vec::new();
+        for item in &self.items {
+            let group = item.scope.group();
+            match groups.last_mut() {
+                Some((name, items)) if *name == group => items.push(item),
+                _ => groups.push((group, vec![item])),
+            }
+        }
+        groups
+    }
+
+    /// Short hash of the items, used to post a notice only when the drift changes
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.items.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Format the report for the maintainers channel, cut to fit in `max_len` bytes
+    pub fn format_notice(&self, max_len: usize) -> String {
+        if self.is_empty() {
+            return "🔍 Drift resolved: Discord matches the config again.".to_string();
+        }
+
+        let mut output = format!(
+            "🔍 **Drift detected**: {} difference(s) between Discord and the config.\n```diff\n",
+            self.items.len()
+        );
+        let footer = "Review and reconcile them on the admin dashboard.";
+        let mut shown = 0;
+        'groups: for (group, items) in self.groups() {
+            let heading = format!("# {}\n", group);
+            if output.len() + heading.len() + footer.len() + 50 > max_len {
+                break;
+            }
+            output.push_str(&heading);
+            for item in items {
+                if output.len() + item.line.len() + footer.len() + 50 > max_len {
+                    break 'groups;
+                }
+                output.push_str(&item.line);
+                output.push('\n');
+                shown += 1;
+            }
+        }
+        if shown < self.items.len() {
+            output.push_str(&format!("… and {} more\n", self.items.len() - shown));
+        }
+        output.push_str("```");
+        output.push_str(footer);
+        output
+    }
+}
+
+/// Compares the live guild with the declared config and the channel state
+///
+/// Roles are compared against `roles.json`, and seasons that have been synced
+/// and not archived against their merged layout. Channel state entries whose
+/// Discord entity was deleted or renamed are reported too.
+pub struct DriftDetector {
+    config_manager: SharedConfigManager,
+    role_manager: SharedRoleManager,
+    channel_manager: SharedChannelManager,
+    state: SharedChannelState,
+    latest: Option<DriftReport>,
+    last_notified: Option<String>,
+}
+
+impl DriftDetector {
+    pub fn new(
+        config_manager: SharedConfigManager,
+        role_manager: SharedRoleManager,
+        channel_manager: SharedChannelManager,
+        state: SharedChannelState,
+    ) -> Self {
+        Self {
+            config_manager,
+            role_manager,
+            channel_manager,
+            state,
+            latest: None,
+            last_notified: None,
+        }
+    }
+
+    /// The most recent report, if a check has run
+    pub fn latest(&self) -> Option<&DriftReport> {
+        self.latest.as_ref()
+    }
+
+    /// Compare the guild with the config without changing anything
+    pub async fn check(&self, http: &Http, guild_id: GuildId) -> Result<DriftReport> {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let (roles, seasons) = {
+            let config = self.config_manager.read().await;
+            let roles = config
+                .get_global_roles()
+                .map(|r| r.roles.clone())
+                .unwrap_or_default();
+            let mut season_ids: Vec<String> = config.get_seasons().keys().cloned().collect();
+            season_ids.sort();
+            let seasons: Vec<_> = season_ids
+                .into_iter()
+                .filter_map(|id| config.get_season_structure(&id).map(|s| (id, s)))
+                .collect();
+            (roles, seasons)
+        };
+        let guild_state = self
+            .state
+            .read()
+            .await
+            .get_guild(&guild_id.to_string())
+            .cloned()
+            .unwrap_or_else(|| GuildChannelState::new(&guild_id.to_string(), ""));
+
+        let mut items = Vec::new();
+        let mut warnings = Vec::new();
+
+        // Roles declared in roles.json
+        let role_manager = self.role_manager.read().await;
+        for role_def in &roles {
+            let plan = role_manager
+                .plan_roles(&snapshot, std::slice::from_ref(role_def))
+                .await;
+            let Some(change) = plan.changes.first() else {
+                continue;
+            };
+            let line = if change.action == PlanAction::Create {
+                missing_role_line(&guild_state, &snapshot, &role_def.name)
+            } else {
+                change.line()
+            };
+            items.push(DriftItem {
+                scope: DriftScope::Role(role_def.name.clone()),
+                line,
+            });
+        }
+        drop(role_manager);
+
+        // Seasons that have been synced and are not archived
+        let channel_manager = self.channel_manager.read().await;
+        for (season_id, structure) in &seasons {
+            let names = structure.category_names();
+            let synced = names.iter().any(|name| {
+                guild_state
+                    .categories
+                    .get(name)
+                    .is_some_and(|c| !c.archived)
+            });
+            if !synced {
+                continue;
+            }
+            let plan = channel_manager
+                .plan_season_structure(&snapshot, structure)
+                .await;
+            items.extend(plan.changes.iter().map(|change| DriftItem {
+                scope: match &change.channel {
+                    Some(target) => DriftScope::SeasonChannel {
+                        season_id: season_id.clone(),
+                        category: target.category.clone(),
+                        channel: target.channel.clone(),
+                    },
+                    None => DriftScope::Season(season_id.clone()),
+                },
+                line: change.line(),
+            }));
+            warnings.extend(
+                plan.warnings
+                    .into_iter()
+                    .map(|w| format!("Season {}: {}", season_id, w)),
+            );
+        }
+        drop(channel_manager);
+
+        let declared: Vec<&str> = roles.iter().map(|r| r.name.as_str()).collect();
+        items.extend(state_drift(&guild_state, &snapshot, &declared));
+
+        Ok(DriftReport {
+            checked_at: Utc::now(),
+            items,
+            warnings,
+        })
+    }
+
+    /// Keep a report as the latest one
+    ///
+    /// Returns the notice to post if the drift changed since the last notice.
+    /// A clean report is only announced after drift was reported.
+    pub fn record(&mut self, report: DriftReport) -> Option<String> {
+        let notice = drift_changed(&mut self.last_notified, &report)
+            .then(|| report.format_notice(MAX_NOTICE_LEN));
+        self.latest = Some(report);
+        notice
+    }
+
+    /// Reconcile one drifted item
+    ///
+    /// Season changes not limited to one category or channel, like reordering
+    /// or creating a category, are reconciled by reviewing and applying the
+    /// season's sync plan instead. Returns a short description of what was done.
+    pub async fn reconcile(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        scope: &DriftScope,
+    ) -> Result<String> {
+        match scope {
+            DriftScope::Role(name) => {
+                let role_def = self
+                    .config_manager
+                    .read()
+                    .await
+                    .get_global_roles()
+                    .and_then(|r| r.roles.iter().find(|r| r.name == *name).cloned())
+                    .ok_or_else(|| BotError::RoleNotFound { name: name.clone() })?;
+                let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+                let role_manager = self.role_manager.read().await;
+                let existing = role_manager.find_role(&snapshot, &role_def).await;
+                let outcome = role_manager
+                    .apply_role(http, guild_id, &role_def, existing)
+                    .await?;
+                Ok(format!("Role '{}': {:?}", name, outcome).to_lowercase())
+            }
+            DriftScope::Season(id) => Err(BotError::Internal {
+                message: format!("season '{}' is reconciled through its sync plan", id),
+            }),
+            DriftScope::SeasonChannel {
+                season_id,
+                category,
+                channel,
+            } => {
+                let structure = self
+                    .config_manager
+                    .read()
+                    .await
+                    .get_season_structure(season_id)
+                    .ok_or_else(|| BotError::ConfigNotFound {
+                        config_type: "season".to_string(),
+                        name: season_id.clone(),
+                    })?;
+                let summary = self
+                    .channel_manager
+                    .read()
+                    .await
+                    .sync_season_channel(http, guild_id, &structure, category, channel.as_deref())
+                    .await?;
+                let mut message = match channel {
+                    Some(name) => format!("Synced channel #{} ({})", name, category),
+                    None => format!("Synced category {}", category),
+                };
+                if !summary.missing_roles.is_empty() {
+                    message.push_str(&format!(
+                        "; missing roles: {}",
+                        summary.missing_roles.join(", ")
+                    ));
+                }
+                for warning in &summary.warnings {
+                    message.push_str(&format!("; {}", warning));
+                }
+                Ok(message)
+            }
+            DriftScope::RenamedChannel { channel_id, name } => {
+                channel_id
+                    .edit(http, serenity::EditChannel::new().name(name))
+                    .await?;
+                info!("Renamed channel {} back to '{}'", channel_id, name);
+                Ok(format!("Renamed channel back to #{}", name))
+            }
+            DriftScope::StaleEntry { kind, name } => {
+                let mut state = self.state.write().await;
+                let forgotten = state
+                    .get_guild_mut(&guild_id.to_string(), "")
+                    .forget(*kind, name);
+                if !forgotten {
+                    return Ok(format!("{} '{}' was not tracked", kind.as_str(), name));
+                }
+                state.save(&channel_state_path()).await?;
+                info!("Forgot stale {} '{}' in channel state", kind.as_str(), name);
+                Ok(format!("Forgot {} '{}'", kind.as_str(), name))
+            }
+        }
+    }
+}
+
+/// Check whether a report differs from the last one announced, and remember it
+///
+/// Clean reports count as changed only after drift was announced.
+fn drift_changed(last_notified: &mut Option<String>, report: &DriftReport) -> bool {
+    let fingerprint = (!report.is_empty()).then(|| report.fingerprint());
+    let changed =
+        fingerprint != *last_notified && (fingerprint.is_some() || last_notified.is_some());
+    *last_notified = fingerprint;
+    changed
+}
+
+/// Describe a declared role the role sync would create
+///
+/// Roles renamed in Discord are still found by their recorded ID, so a
+/// recorded role here was deleted.
+fn missing_role_line(guild: &GuildChannelState, snapshot: &GuildSnapshot, name: &str) -> String {
+    let recorded = guild
+        .get_role_id(name)
+        .and_then(|id| id.parse::<u64>().ok())
+        .map(RoleId::new);
+    match recorded {
+        Some(id) if !snapshot.roles.contains_key(&id) => {
+            format!("- role {}: deleted in Discord", name)
+        }
+        _ => format!("+ role {}: missing in Discord", name),
+    }
+}
+
+/// Channel state entries whose Discord entity was deleted or renamed
+///
+/// Roles still declared in `roles.json` are left out; the role check covers them.
+fn state_drift(
+    guild: &GuildChannelState,
+    snapshot: &GuildSnapshot,
+    declared_roles: &[&str],
+) -> Vec<DriftItem> {
+    let parse = |id: &str| id.parse::<u64>().ok();
+    let mut items = Vec::new();
+
+    let mut roles: Vec<_> = guild
+        .roles
+        .iter()
+        .filter(|(name, _)| !declared_roles.contains(&name.as_str()))
+        .collect();
+    roles.sort_by_key(|(name, _)| name.as_str());
+    for (name, entry) in roles {
+        if parse(&entry.discord_id).is_some_and(|id| !snapshot.roles.contains_key(&RoleId::new(id)))
+        {
+            items.push(DriftItem {
+                scope: DriftScope::StaleEntry {
+                    kind: EntityType::Role,
+                    name: name.clone(),
+                },
+                line: format!("- role {}: deleted in Discord", name),
+            });
+        }
+    }
+
+    let mut categories: Vec<_> = guild.categories.iter().collect();
+    categories.sort_by_key(|(name, _)| name.as_str());
+    for (name, entry) in categories {
+        if parse(&entry.discord_id)
+            .is_some_and(|id| !snapshot.channels.contains_key(&ChannelId::new(id)))
+        {
+            items.push(DriftItem {
+                scope: DriftScope::StaleEntry {
+                    kind: EntityType::Category,
+                    name: name.clone(),
+                },
+                line: format!("- category {}: deleted in Discord", name),
+            });
+        }
+    }
+
+    let mut channels: Vec<_> = guild.channels.iter().collect();
+    channels.sort_by_key(|(key, entry)| (entry.name.as_str(), key.as_str()));
+    for (key, entry) in channels {
+        let Some(id) = parse(&entry.discord_id).map(ChannelId::new) else {
+            continue;
+        };
+        let label = match entry
+            .parent_category
+            .as_deref()
+            .and_then(|parent| guild.categories.values().find(|c| c.discord_id == parent))
+        {
+            Some(category) => format!("#{} ({})", entry.name, category.name),
+            None => format!("#{}", entry.name),
+        };
+        match snapshot.channels.get(&id) {
+            None => items.push(DriftItem {
+                scope: DriftScope::StaleEntry {
+                    kind: EntityType::Channel,
+                    name: key.clone(),
+                },
+                line: format!("- channel {}: deleted in Discord", label),
+            }),
+            Some(channel) if channel.name != entry.name => items.push(DriftItem {
+                scope: DriftScope::RenamedChannel {
+                    channel_id: id,
+                    name: entry.name.clone(),
+                },
+                line: format!(
+                    "~ channel {}: renamed in Discord to '{}'",
+                    label, channel.name
+                ),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    items
+}
+
+/// Shared drift detector type
+pub type SharedDriftDetector = Arc<tokio::sync::RwLock<DriftDetector>>;
+
+pub fn create_shared_drift_detector(
+    config_manager: SharedConfigManager,
+    role_manager: SharedRoleManager,
+    channel_manager: SharedChannelManager,
+    state: SharedChannelState,
+) -> SharedDriftDetector {
+    Arc::new(tokio::sync::RwLock::new(DriftDetector::new(
+        config_manager,
+        role_manager,
+        channel_manager,
+        state,
+    )))
+}
+
+/// Run a drift check now, keep the report and post a notice if the drift changed
+pub async fn run_drift_check(
+    detector: &SharedDriftDetector,
+    maintainers_manager: &SharedMaintainersManager,
+    http: &Http,
+    guild_id: GuildId,
+) -> Result<()> {
+    let report = detector.read().await.check(http, guild_id).await?;
+    info!(
+        "Drift check found {} difference(s) and {} warning(s)",
+        report.items.len(),
+        report.warnings.len()
+    );
+    for warning in &report.warnings {
+        warn!("Drift check: {}", warning);
+    }
+
+    let notice = detector.write().await.record(report);
+    if let Some(notice) = notice {
+        let maintainers_manager = maintainers_manager.read().await;
+        if let Err(e) = maintainers_manager
+            .post_notice(http, guild_id, &notice)
+            .await
+        {
+            warn!("Failed to post drift report to maintainers channel: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Start periodic drift checks as a background task
+///
+/// Checks every DRIFT_CHECK_INTERVAL_MINUTES (default 6 hours); 0 disables them.
+pub fn spawn_drift_detector(
+    detector: SharedDriftDetector,
+    maintainers_manager: SharedMaintainersManager,
+    http: Arc<Http>,
+    guild_id: Option<GuildId>,
+) {
+    let minutes = std::env::var("DRIFT_CHECK_INTERVAL_MINUTES")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_CHECK_INTERVAL_MINUTES);
+    let Some(guild_id) = guild_id.filter(|_| minutes > 0) else {
+        info!("Drift detection disabled");
+        return;
+    };
+
+    tokio::spawn(async move {
+        info!("Drift detector started (checking every {} min)", minutes);
+        let mut interval = tokio::time::interval(Duration::from_secs(minutes * 60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_drift_check(&detector, &maintainers_manager, &http, guild_id).await
+            {
+                error!("Drift check failed: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poise::serenity_prelude::UserId;
+    use std::collections::HashMap;
+
+    fn item(scope: DriftScope, line: &str) -> DriftItem {
+        DriftItem {
+            scope,
+            line: line.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_scope_keys_round_trip() {
+        let scopes = [
+            DriftScope::Role("Board: 2025".to_string()),
+            DriftScope::Season("2025E".to_string()),
+            DriftScope::SeasonChannel {
+                season_id: "2025E".to_string(),
+                category: "Staff: 2025".to_string(),
+                channel: Some("general".to_string()),
+            },
+            DriftScope::SeasonChannel {
+                season_id: "2025E".to_string(),
+                category: "Staff: 2025".to_string(),
+                channel: None,
+            },
+            DriftScope::RenamedChannel {
+                channel_id: ChannelId::new(42),
+                name: "general".to_string(),
+            },
+            DriftScope::StaleEntry {
+                kind: EntityType::Category,
+                name: "2024E".to_string(),
+            },
+        ];
+        for scope in scopes {
+            assert_eq!(DriftScope::from_key(&scope.key()), Some(scope));
+        }
+        assert_eq!(DriftScope::from_key("entry:thing:x"), None);
+    }
+
+    #[test]
+    fn test_state_drift_reports_deleted_entries() {
+        let mut guild = GuildChannelState::new("1", "Test");
+        guild.add_role("Medlem2024E", "10", None, true);
+        guild.add_role("Board", "11", None, true);
+        guild.add_category("2024E", "20", 0, true);
+        let role: serenity::Role = serde_json::from_value(serde_json::json!({
+            "id": "11", "guild_id": "1", "name": "Board", "color": 0, "hoist": false,
+            "managed": false, "mentionable": false, "permissions": "0", "position": 1
+        }))
+        .unwrap();
+        let snapshot = GuildSnapshot {
+            guild_id: GuildId::new(1),
+            channels: HashMap::new(),
+            roles: HashMap::from([(RoleId::new(11), role)]),
+            everyone_role: RoleId::new(1),
+            bot_user: UserId::new(2),
+            bot_roles: vec![],
+        };
+
+        let items = state_drift(&guild, &snapshot, &["Board"]);
+        assert_eq!(
+            items,
+            vec![
+                item(
+                    DriftScope::StaleEntry {
+                        kind: EntityType::Role,
+                        name: "Medlem2024E".to_string()
+                    },
+                    "- role Medlem2024E: deleted in Discord"
+                ),
+                item(
+                    DriftScope::StaleEntry {
+                        kind: EntityType::Category,
+                        name: "2024E".to_string()
+                    },
+                    "- category 2024E: deleted in Discord"
+                ),
+            ]
+        );
+        assert_eq!(
+            missing_role_line(&guild, &snapshot, "Medlem2024E"),
+            "- role Medlem2024E: deleted in Discord"
+        );
+    }
+
+    #[test]
+    fn test_notifies_only_when_drift_changes() {
+        let report = |items: Vec<DriftItem>| DriftReport {
+            checked_at: Utc::now(),
+            items,
+            warnings: vec![],
+        };
+        let drift = vec![item(
+            DriftScope::Role("Board".to_string()),
+            "~ role Board: hoist false → true",
+        )];
+
+        let mut last = None;
+        assert!(!drift_changed(&mut last, &report(vec![])));
+        assert!(drift_changed(&mut last, &report(drift.clone())));
+        assert!(!drift_changed(&mut last, &report(drift.clone())));
+        assert!(drift_changed(&mut last, &report(vec![])));
+
+        let notice = report(drift).format_notice(MAX_NOTICE_LEN);
+        assert!(notice.contains("# roles\n~ role Board: hoist false → true\n"));
+    }
+}
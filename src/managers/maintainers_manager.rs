@@ -2,9 +2,13 @@ use poise::serenity_prelude::{Attachment, ChannelId, GuildId, Http};
 use std::sync::Arc;
 use tracing::info;
 
+use crate::config::ConfigDiff;
 use crate::error::{BotError, Result};
 use crate::managers::{SharedChannelManager, SharedConfigManager};
 
+/// Most of a maintainers channel reply the staged diff may take up (Discord caps messages at 2000)
+const MAX_DIFF_LEN: usize = 1500;
+
 /// Type of configuration file detected
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConfigType {
@@ -40,6 +44,13 @@ impl MaintainersManager {
             .await
     }
 
+    /// Post a notice to the maintainers channel, creating the channel if needed
+    pub async fn post_notice(&self, http: &Http, guild_id: GuildId, message: &str) -> Result<()> {
+        let channel_id = self.ensure_channel_exists(http, guild_id).await?;
+        channel_id.say(http, message).await?;
+        Ok(())
+    }
+
     /// Check if a channel is the maintainers channel
     pub async fn is_maintainers_channel(&self, channel_id: ChannelId, guild_id: GuildId) -> bool {
         let channel_manager = self.channel_manager.read().await;
@@ -71,6 +82,12 @@ impl MaintainersManager {
             return ConfigType::Season(season_id.to_uppercase());
         }
 
+        // CSV exports are always season member lists
+        if filename_lower.ends_with(".csv") {
+            let season_id = filename_lower.trim_end_matches(".csv").to_string();
+            return ConfigType::Season(season_id.to_uppercase());
+        }
+
         ConfigType::Unknown
     }
 
@@ -80,11 +97,12 @@ impl MaintainersManager {
         attachment: &Attachment,
         staged_by: Option<String>,
     ) -> Result<String> {
-        // Check if it's a JSON file
-        if !attachment.filename.ends_with(".json") {
+        // Check if it's a JSON or CSV file
+        let filename_lower = attachment.filename.to_lowercase();
+        if !filename_lower.ends_with(".json") && !filename_lower.ends_with(".csv") {
             return Err(BotError::ConfigValidation {
                 message: format!(
-                    "Only JSON files are supported. Got: {}",
+                    "Only JSON and CSV files are supported. Got: {}",
                     attachment.filename
                 ),
             });
@@ -144,7 +162,12 @@ impl MaintainersManager {
     }
 
     /// Format attachment handling results for Discord message
-    pub fn format_results(&self, results: &[(String, Result<String>)]) -> String {
+    /// `diff` is the staged config diff, shown before the commit hint when anything was staged
+    pub fn format_results(
+        &self,
+        results: &[(String, Result<String>)],
+        diff: Option<&ConfigDiff>,
+    ) -> String {
         let mut output = String::new();
 
         for (filename, result) in results {
@@ -159,6 +182,10 @@ impl MaintainersManager {
         }
 
         if results.iter().any(|(_, r)| r.is_ok()) {
+            if let Some(diff) = diff {
+                output.push('\n');
+                output.push_str(&diff.format_truncated(MAX_DIFF_LEN));
+            }
             output.push_str(
                 "\nUse `/preview-config` to see what would change, then `/commit-config` to apply.",
             );
@@ -200,6 +227,10 @@ mod tests {
             detect_type_from_filename("assignments.json"),
             ConfigType::SpecialMembers
         );
+        assert_eq!(
+            detect_type_from_filename("2025e.csv"),
+            ConfigType::Season("2025E".to_string())
+        );
     }
 
     fn detect_type_from_filename(filename: &str) -> ConfigType {
@@ -217,6 +248,11 @@ mod tests {
             return ConfigType::Season(season_id.to_uppercase());
         }
 
+        if filename_lower.ends_with(".csv") {
+            let season_id = filename_lower.trim_end_matches(".csv").to_string();
+            return ConfigType::Season(season_id.to_uppercase());
+        }
+
         ConfigType::Unknown
     }
 }
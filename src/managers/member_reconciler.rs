@@ -0,0 +1,472 @@
+use poise::serenity_prelude::{GuildId, Http, RoleId, UserId};
+use std::collections::HashMap;
+use tracing::{error, info, warn};
+
+use super::sync_plan::{PlanAction, SyncPlan};
+use crate::error::{BotError, Result};
+use crate::managers::{SharedChannelManager, SharedConfigManager, SharedRoleManager};
+use crate::state::user_database::{TrackedUser, VerificationStatus};
+use crate::state::{user_database_path, SharedUserDatabase};
+
+/// How a member's managed roles compare with what they should have
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemberRoleRow {
+    pub discord_id: String,
+    pub username: String,
+    /// Verification status in the user database, or "untracked"
+    pub status: String,
+    /// Managed roles the member should have
+    pub desired: Vec<String>,
+    /// Managed roles the member had before the reconciliation
+    pub current: Vec<String>,
+    /// Roles added, or that would be added when not applying
+    pub added: Vec<String>,
+    /// Roles removed, or that would be removed when not applying
+    pub removed: Vec<String>,
+    /// Changes that failed, e.g. "add:Bestyrelse"
+    pub failed: Vec<String>,
+}
+
+impl MemberRoleRow {
+    fn is_changed(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.failed.is_empty()
+    }
+}
+
+/// Per-member result of reconciling managed roles across the guild
+#[derive(Debug, Clone, Default)]
+pub struct MemberRoleReport {
+    /// Whether the changes were made or only computed
+    pub applied: bool,
+    /// One row per member, sorted by username
+    pub rows: Vec<MemberRoleRow>,
+    /// Managed roles that don't exist in Discord, so they can't be added
+    pub missing_roles: Vec<String>,
+}
+
+impl MemberRoleReport {
+    /// The changes as a sync plan, one update per member
+    pub fn plan(&self) -> SyncPlan {
+        let mut plan = SyncPlan::default();
+        for row in self
+            .rows
+            .iter()
+            .filter(|r| !r.added.is_empty() || !r.removed.is_empty())
+        {
+            let details = row
+                .added
+                .iter()
+                .map(|r| format!("+{}", r))
+                .chain(row.removed.iter().map(|r| format!("-{}", r)))
+                .collect();
+            plan.push(
+                PlanAction::Update,
+                format!("member {} ({})", row.username, row.discord_id),
+                details,
+            );
+        }
+        for role in &self.missing_roles {
+            plan.warnings.push(format!(
+                "Role '{}' doesn't exist in Discord, so nobody gets it - sync roles first",
+                role
+            ));
+        }
+        plan
+    }
+
+    /// One-line summary, e.g. "120 members, 3 changed: 3 roles added, 1 removed, 0 failed."
+    pub fn summary(&self) -> String {
+        let count = |f: fn(&MemberRoleRow) -> usize| self.rows.iter().map(f).sum::<usize>();
+        format!(
+            "{} members, {} changed: {} roles {}, {} {}, {} failed.",
+            self.rows.len(),
+            self.rows.iter().filter(|r| r.is_changed()).count(),
+            count(|r| r.added.len()),
+            if self.applied { "added" } else { "to add" },
+            count(|r| r.removed.len()),
+            if self.applied { "removed" } else { "to remove" },
+            count(|r| r.failed.len())
+        )
+    }
+
+    /// The report as CSV, one line per member
+    pub fn to_csv(&self) -> Result<Vec<u8>> {
+        let csv_error = |e: &dyn std::fmt::Display| BotError::Internal {
+            message: format!("Failed to write CSV: {}", e),
+        };
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record([
+                "discord_id",
+                "username",
+                "status",
+                "desired",
+                "current",
+                "added",
+                "removed",
+                "failed",
+            ])
+            .map_err(|e| csv_error(&e))?;
+        for row in &self.rows {
+            writer
+                .write_record([
+                    row.discord_id.as_str(),
+                    &row.username,
+                    &row.status,
+                    &row.desired.join("; "),
+                    &row.current.join("; "),
+                    &row.added.join("; "),
+                    &row.removed.join("; "),
+                    &row.failed.join("; "),
+                ])
+                .map_err(|e| csv_error(&e))?;
+        }
+        writer.into_inner().map_err(|e| csv_error(&e.error()))
+    }
+}
+
+/// Bring every member's managed roles in line with the user database
+///
+/// Managed roles are the member roles of the seasons in the config and the
+/// roles in assignments.json; other roles are never touched. Members get the
+/// member role of each of their seasons that is still active once verified,
+/// and the special roles assignments.json gives their username unless their
+/// verification was revoked. Member roles of archived seasons are left alone,
+/// since archiving decides whether former members keep them. With `apply`
+/// false nothing changes and the report lists what would.
+pub async fn reconcile_members(
+    http: &Http,
+    guild_id: GuildId,
+    config_manager: &SharedConfigManager,
+    channel_manager: &SharedChannelManager,
+    user_db: &SharedUserDatabase,
+    role_manager: &SharedRoleManager,
+    apply: bool,
+) -> Result<MemberRoleReport> {
+    let (seasons, special_members) = {
+        let config = config_manager.read().await;
+        let seasons: Vec<(SeasonMemberRole, Vec<String>)> = config
+            .get_seasons()
+            .iter()
+            .map(|(season_id, season)| {
+                let role = SeasonMemberRole {
+                    season_id: season_id.clone(),
+                    member_role: season.member_role(),
+                    active: season.is_active(),
+                    archived: false,
+                };
+                let categories = config.get_season_category_names(season_id).unwrap_or_default();
+                (role, categories)
+            })
+            .collect();
+        let special_members = config.get_special_members().cloned().unwrap_or_default();
+        (seasons, special_members)
+    };
+    let mut season_member_roles = Vec::new();
+    {
+        let channel_manager = channel_manager.read().await;
+        for (mut role, categories) in seasons {
+            role.archived = channel_manager
+                .are_categories_archived(guild_id, &categories)
+                .await;
+            season_member_roles.push(role);
+        }
+    }
+    let (mut managed, season_roles) = managed_season_roles(&season_member_roles);
+    managed.extend(special_members.get_all_role_names());
+    managed.sort();
+    managed.dedup();
+
+    let mut report = MemberRoleReport {
+        applied: apply,
+        ..Default::default()
+    };
+    let mut role_ids: Vec<(String, RoleId)> = Vec::new();
+    {
+        let role_manager = role_manager.read().await;
+        for name in managed {
+            match role_manager.get_role_id(http, guild_id, &name).await {
+                Ok(id) => role_ids.push((name, id)),
+                Err(_) => report.missing_roles.push(name),
+            }
+        }
+    }
+
+    let users: HashMap<String, TrackedUser> = user_db.read().await.users.clone();
+    let mut after: Option<UserId> = None;
+    loop {
+        let members = guild_id.members(http, Some(1000), after).await?;
+        let Some(last) = members.last() else {
+            break;
+        };
+        after = Some(last.user.id);
+        let page_len = members.len();
+
+        for member in members.iter().filter(|m| !m.user.bot) {
+            let discord_id = member.user.id.to_string();
+            let user = users.get(&discord_id);
+            let desired = desired_roles(
+                user,
+                &season_roles,
+                special_members.get_roles_for_user(&member.user.name),
+            );
+            let current: Vec<&(String, RoleId)> = role_ids
+                .iter()
+                .filter(|(_, id)| member.roles.contains(id))
+                .collect();
+
+            let mut row = MemberRoleRow {
+                discord_id,
+                username: member.user.name.clone(),
+                status: user.map_or("untracked".to_string(), |u| {
+                    status_name(&u.verification_status)
+                }),
+                current: current.iter().map(|(name, _)| name.clone()).collect(),
+                ..Default::default()
+            };
+            for (name, id) in &role_ids {
+                let wanted = desired.contains(name);
+                let has = member.roles.contains(id);
+                if wanted == has {
+                    continue;
+                }
+                let result = match (apply, wanted) {
+                    (false, _) => Ok(()),
+                    (true, true) => member.add_role(http, *id).await,
+                    (true, false) => member.remove_role(http, *id).await,
+                };
+                let (verb, list) = if wanted {
+                    ("add", &mut row.added)
+                } else {
+                    ("remove", &mut row.removed)
+                };
+                match result {
+                    Ok(()) => list.push(name.clone()),
+                    Err(e) => {
+                        error!(
+                            "Failed to {} role '{}' for {}: {}",
+                            verb, name, member.user.name, e
+                        );
+                        row.failed.push(format!("{}:{}", verb, name));
+                    }
+                }
+            }
+            row.desired = desired;
+            report.rows.push(row);
+        }
+
+        if page_len < 1000 {
+            break;
+        }
+    }
+    report.rows.sort_by(|a, b| a.username.cmp(&b.username));
+
+    if apply {
+        record_current_roles(user_db, &report).await;
+        info!("Member role reconciliation: {}", report.summary());
+    }
+    Ok(report)
+}
+
+/// A season's member role and the state of the season
+#[derive(Debug, Clone)]
+struct SeasonMemberRole {
+    season_id: String,
+    member_role: String,
+    active: bool,
+    /// Every category of the season has been archived
+    archived: bool,
+}
+
+/// Season member roles to manage, and the member role of each active season
+///
+/// Roles only used by archived seasons are not managed, so reconciling keeps
+/// whatever `/archive_season` left members with.
+fn managed_season_roles(seasons: &[SeasonMemberRole]) -> (Vec<String>, HashMap<String, String>) {
+    let managed = seasons
+        .iter()
+        .filter(|s| !s.archived)
+        .map(|s| s.member_role.clone())
+        .collect();
+    let season_roles = seasons
+        .iter()
+        .filter(|s| s.active && !s.archived)
+        .map(|s| (s.season_id.clone(), s.member_role.clone()))
+        .collect();
+    (managed, season_roles)
+}
+
+/// Managed roles a member should have
+///
+/// `season_roles` maps the IDs of active seasons to their member role and
+/// `special_roles` are the member's roles from assignments.json.
+fn desired_roles(
+    user: Option<&TrackedUser>,
+    season_roles: &HashMap<String, String>,
+    special_roles: Vec<String>,
+) -> Vec<String> {
+    if user.is_some_and(|u| u.is_revoked()) {
+        return Vec::new();
+    }
+    let mut roles: Vec<String> = user
+        .filter(|u| u.verification_status == VerificationStatus::Verified)
+        .map(|u| {
+            u.verification_ids
+                .keys()
+                .filter_map(|season_id| season_roles.get(season_id).cloned())
+                .collect()
+        })
+        .unwrap_or_default();
+    roles.extend(special_roles);
+    roles.sort();
+    roles.dedup();
+    roles
+}
+
+fn status_name(status: &VerificationStatus) -> String {
+    match status {
+        VerificationStatus::Pending => "pending",
+        VerificationStatus::Verified => "verified",
+        VerificationStatus::Revoked => "revoked",
+        VerificationStatus::Expired => "expired",
+    }
+    .to_string()
+}
+
+/// Update the tracked users' current roles after applying a reconciliation
+async fn record_current_roles(user_db: &SharedUserDatabase, report: &MemberRoleReport) {
+    let mut db = user_db.write().await;
+    let mut changed = false;
+    for row in report
+        .rows
+        .iter()
+        .filter(|r| !r.added.is_empty() || !r.removed.is_empty())
+    {
+        let Some(user) = db.find_by_discord_id_mut(&row.discord_id) else {
+            continue;
+        };
+        for role in &row.added {
+            user.add_role(role);
+        }
+        for role in &row.removed {
+            user.remove_role(role);
+        }
+        changed = true;
+    }
+    if changed {
+        if let Err(e) = db.save(&user_database_path()).await {
+            warn!("Failed to save user database after reconciliation: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(status: VerificationStatus, seasons: &[&str]) -> TrackedUser {
+        let mut user = TrackedUser::new(
+            "1".to_string(),
+            "uuid".to_string(),
+            seasons[0].to_string(),
+            "Alice".to_string(),
+            vec![],
+        );
+        for season in &seasons[1..] {
+            user.add_verification_id(season, "uuid");
+        }
+        user.verification_status = status;
+        user
+    }
+
+    #[test]
+    fn test_desired_roles() {
+        let season_roles = HashMap::from([("2025E".to_string(), "Medlem2025E".to_string())]);
+        let special = || vec!["Bestyrelse".to_string()];
+
+        // Only active seasons count; 2024E is inactive or gone from the config
+        let verified = user(VerificationStatus::Verified, &["2024E", "2025E"]);
+        assert_eq!(
+            desired_roles(Some(&verified), &season_roles, special()),
+            vec!["Bestyrelse", "Medlem2025E"]
+        );
+
+        let revoked = user(VerificationStatus::Revoked, &["2025E"]);
+        assert!(desired_roles(Some(&revoked), &season_roles, special()).is_empty());
+
+        let expired = user(VerificationStatus::Expired, &["2025E"]);
+        assert_eq!(
+            desired_roles(Some(&expired), &season_roles, special()),
+            vec!["Bestyrelse"]
+        );
+        assert_eq!(
+            desired_roles(None, &season_roles, special()),
+            vec!["Bestyrelse"]
+        );
+    }
+
+    #[test]
+    fn test_desired_roles_leave_archived_seasons_alone() {
+        let season = |id: &str, active, archived| SeasonMemberRole {
+            season_id: id.to_string(),
+            member_role: format!("Medlem{}", id),
+            active,
+            archived,
+        };
+        let (managed, season_roles) = managed_season_roles(&[
+            season("2024E", false, true),
+            season("2025F", false, false),
+            season("2025E", true, false),
+        ]);
+
+        // The archived season's role is neither managed nor desired, so a
+        // reconcile never strips it from former members
+        assert_eq!(managed, vec!["Medlem2025F", "Medlem2025E"]);
+        let verified = user(VerificationStatus::Verified, &["2024E", "2025E"]);
+        assert_eq!(
+            desired_roles(Some(&verified), &season_roles, vec![]),
+            vec!["Medlem2025E"]
+        );
+    }
+
+    #[test]
+    fn test_report_plan_and_csv() {
+        let report = MemberRoleReport {
+            applied: false,
+            rows: vec![
+                MemberRoleRow {
+                    discord_id: "1".to_string(),
+                    username: "alice".to_string(),
+                    status: "verified".to_string(),
+                    desired: vec!["Medlem2025E".to_string()],
+                    current: vec!["Bestyrelse".to_string()],
+                    added: vec!["Medlem2025E".to_string()],
+                    removed: vec!["Bestyrelse".to_string()],
+                    failed: vec![],
+                },
+                MemberRoleRow {
+                    discord_id: "2".to_string(),
+                    username: "bob".to_string(),
+                    status: "untracked".to_string(),
+                    ..Default::default()
+                },
+            ],
+            missing_roles: vec![],
+        };
+
+        assert_eq!(
+            report.plan().lines(),
+            vec!["~ member alice (1): +Medlem2025E; -Bestyrelse"]
+        );
+        assert_eq!(
+            report.summary(),
+            "2 members, 1 changed: 1 roles to add, 1 to remove, 0 failed."
+        );
+        assert_eq!(
+            String::from_utf8(report.to_csv().unwrap()).unwrap(),
+            "discord_id,username,status,desired,current,added,removed,failed\n\
+             1,alice,verified,Medlem2025E,Bestyrelse,Medlem2025E,Bestyrelse,\n\
+             2,bob,untracked,,,,,\n"
+        );
+    }
+}
@@ -1,16 +1,29 @@
 pub mod channel_manager;
 pub mod config_manager;
+pub mod config_watcher;
+pub mod drift_detector;
 pub mod maintainers_manager;
+pub mod member_reconciler;
 pub mod permission_checker;
 pub mod role_manager;
+pub mod season_scheduler;
+pub mod sync_plan;
 pub mod verification_manager;
 
 pub use channel_manager::{create_shared_channel_manager, SharedChannelManager};
 pub use config_manager::{create_shared_config_manager, ConfigManager, SharedConfigManager};
+pub use config_watcher::spawn_config_watcher;
+pub use drift_detector::{
+    create_shared_drift_detector, run_drift_check, spawn_drift_detector, DriftScope,
+    SharedDriftDetector,
+};
 pub use maintainers_manager::{create_shared_maintainers_manager, SharedMaintainersManager};
+pub use member_reconciler::reconcile_members;
 pub use permission_checker::{
     check_role_permission_management, log_role_permission_management_check,
     run_startup_permission_check,
 };
-pub use role_manager::{create_shared_role_manager, SharedRoleManager};
+pub use role_manager::{create_shared_role_manager, RoleSyncOutcome, SharedRoleManager};
+pub use season_scheduler::spawn_season_scheduler;
+pub use sync_plan::{GuildSnapshot, SyncPlan};
 pub use verification_manager::{create_shared_verification_manager, SharedVerificationManager};
@@ -1,10 +1,14 @@
-use poise::serenity_prelude::{self as serenity, Colour, GuildId, Http, RoleId, UserId};
+use poise::serenity_prelude::{
+    self as serenity, Colour, GuildId, Http, LightMethod, Request, RoleId, Route, UserId,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
+use super::sync_plan::{GuildSnapshot, PlanAction, SyncPlan};
 use crate::config::RoleDefinition;
 use crate::error::{BotError, Result};
+use crate::state::channel_state::GuildChannelState;
 use crate::state::{EntityType, SharedChannelState};
 
 /// Manages Discord role creation and assignment
@@ -19,6 +23,9 @@ impl RoleManager {
     }
 
     /// Ensure all roles from the global structure exist in the guild
+    ///
+    /// Existing roles are found as `plan_roles` finds them and only renamed
+    /// if their name differs; their other settings are left alone.
     pub async fn ensure_roles_exist(
         &self,
         http: &Http,
@@ -32,9 +39,25 @@ impl RoleManager {
 
         for role_def in roles {
             // Check if role already exists
-            if let Some((role_id, _)) = existing_roles.iter().find(|(_, r)| r.name == role_def.name)
-            {
+            let existing = {
+                let state = self.state.read().await;
+                resolve_role(state.get_guild(&guild_id.to_string()), &existing_roles, role_def)
+            };
+            if let Some(role) = existing {
+                let role_id = &role.id;
                 debug!("Role '{}' already exists", role_def.name);
+                if role.name != role_def.name {
+                    match guild_id
+                        .edit_role(http, role.id, serenity::EditRole::new().name(&role_def.name))
+                        .await
+                    {
+                        Ok(_) => info!("Renamed role '{}' to '{}'", role.name, role_def.name),
+                        Err(e) => error!(
+                            "Failed to rename role '{}' to '{}': {}",
+                            role.name, role_def.name, e
+                        ),
+                    }
+                }
                 created_roles.insert(role_def.name.clone(), *role_id);
 
                 // Update state
@@ -44,6 +67,7 @@ impl RoleManager {
                     &role_def.name,
                     &role_id.to_string(),
                     role_def.color.as_deref(),
+                    false,
                 );
 
                 continue;
@@ -62,6 +86,7 @@ impl RoleManager {
                         &role_def.name,
                         &role_id.to_string(),
                         role_def.color.as_deref(),
+                        true,
                     );
                 }
                 Err(e) => {
@@ -81,6 +106,133 @@ impl RoleManager {
         Ok(created_roles)
     }
 
+    /// Plan a role sync without applying it
+    ///
+    /// Missing roles are planned as creates and existing ones as edits when
+    /// their name, color, hoist, mentionable setting or synced permissions
+    /// differ from the definition. Roles are found as `find_role` finds them;
+    /// a renamed role is listed under its current name.
+    pub async fn plan_roles(&self, snapshot: &GuildSnapshot, roles: &[RoleDefinition]) -> SyncPlan {
+        let state = self.state.read().await;
+        let guild = state.get_guild(&snapshot.guild_id.to_string());
+        let mut plan = SyncPlan::default();
+
+        for role_def in roles {
+            let target = format!("role {}", role_def.name);
+            match resolve_role(guild, &snapshot.roles, role_def) {
+                Some(role) if role.name != role_def.name => {
+                    plan.push(
+                        PlanAction::Rename,
+                        format!("role {}", role.name),
+                        role_edits(role, role_def),
+                    );
+                }
+                Some(role) => {
+                    let edits = role_edits(role, role_def);
+                    if !edits.is_empty() {
+                        plan.push(PlanAction::Update, target, edits);
+                    }
+                }
+                None => {
+                    let mut details = Vec::new();
+                    if let Some(color) = &role_def.color {
+                        details.push(format!("color {}", color));
+                    }
+                    if role_def.hoist {
+                        details.push("hoist".to_string());
+                    }
+                    if role_def.mentionable {
+                        details.push("mentionable".to_string());
+                    }
+                    if role_def.syncs_permissions() {
+                        details.push(format!(
+                            "permissions {}",
+                            role_def.get_permissions().get_permission_names().join(", ")
+                        ));
+                    }
+                    plan.push(PlanAction::Create, target, details);
+                }
+            }
+        }
+
+        plan
+    }
+
+    /// Plan a role sync from `roles.json`: `plan_roles` plus the role order
+    ///
+    /// Roles with a `position` are put directly below the bot's highest role,
+    /// 0 being the top. Roles at or above the bot's highest role cannot be
+    /// moved by the bot and are reported as warnings.
+    pub async fn plan_role_sync(&self, snapshot: &GuildSnapshot, roles: &[RoleDefinition]) -> SyncPlan {
+        let mut plan = self.plan_roles(snapshot, roles).await;
+        let state = self.state.read().await;
+        let order = role_order(state.get_guild(&snapshot.guild_id.to_string()), snapshot, roles);
+
+        for (name, from, to) in &order.moved {
+            plan.push(
+                PlanAction::Reorder,
+                format!("role {}", name),
+                vec![format!("position {} → {}", from, to)],
+            );
+        }
+        if order.unplaced {
+            plan.push(
+                PlanAction::Reorder,
+                "roles",
+                vec!["put in config order after creation".to_string()],
+            );
+        }
+        plan.warnings.extend(order.above_bot_warnings(snapshot));
+        plan
+    }
+
+    /// Put roles with a `position` in config order below the bot's highest role
+    ///
+    /// Uses Discord's bulk role position endpoint, so the roles move in one
+    /// request. Call after the roles have been created.
+    pub async fn apply_role_order(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        roles: &[RoleDefinition],
+    ) -> Result<RoleOrderReport> {
+        let snapshot = GuildSnapshot::fetch(http, guild_id).await?;
+        let order = {
+            let state = self.state.read().await;
+            role_order(state.get_guild(&guild_id.to_string()), &snapshot, roles)
+        };
+        let report = RoleOrderReport {
+            moved: order.moved.iter().map(|(name, _, _)| name.to_string()).collect(),
+            above_bot: order.above_bot_warnings(&snapshot),
+        };
+        for warning in &report.above_bot {
+            warn!("{}", warning);
+        }
+        if order.moves.is_empty() {
+            return Ok(report);
+        }
+
+        let body = serde_json::Value::Array(
+            order
+                .moves
+                .iter()
+                .map(|(id, _, position)| serde_json::json!({ "id": id, "position": position }))
+                .collect(),
+        );
+        http.request(
+            Request::new(Route::GuildRoles { guild_id }, LightMethod::Patch)
+                .body(Some(body.to_string().into_bytes())),
+        )
+        .await?;
+        info!(
+            "Moved {} roles in guild {} to put {} in config order",
+            order.moves.len(),
+            guild_id,
+            report.moved.join(", ")
+        );
+        Ok(report)
+    }
+
     /// Create a single role
     async fn create_role(
         &self,
@@ -88,26 +240,62 @@ impl RoleManager {
         guild_id: GuildId,
         role_def: &RoleDefinition,
     ) -> Result<RoleId> {
-        let color = role_def
-            .color
-            .as_ref()
-            .and_then(|c| parse_hex_color(c))
-            .unwrap_or(Colour::default());
-
-        let role = guild_id
-            .create_role(
-                http,
-                serenity::EditRole::new()
-                    .name(&role_def.name)
-                    .colour(color)
-                    .hoist(role_def.hoist)
-                    .mentionable(role_def.mentionable),
-            )
-            .await?;
+        let role = guild_id.create_role(http, role_builder(role_def)).await?;
 
         Ok(role.id)
     }
 
+    /// Find the live role for a definition (see `resolve_role`)
+    pub async fn find_role<'a>(
+        &self,
+        snapshot: &'a GuildSnapshot,
+        role_def: &RoleDefinition,
+    ) -> Option<&'a serenity::Role> {
+        let state = self.state.read().await;
+        resolve_role(
+            state.get_guild(&snapshot.guild_id.to_string()),
+            &snapshot.roles,
+            role_def,
+        )
+    }
+
+    /// Create a role, or bring `existing` in line with its definition
+    pub async fn apply_role(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        role_def: &RoleDefinition,
+        existing: Option<&serenity::Role>,
+    ) -> serenity::Result<RoleSyncOutcome> {
+        let (outcome, role_id) = match existing {
+            Some(role) if role_edits(role, role_def).is_empty() => {
+                return Ok(RoleSyncOutcome::Unchanged);
+            }
+            Some(role) => {
+                guild_id
+                    .edit_role(http, role.id, role_builder(role_def))
+                    .await?;
+                info!("Updated role '{}'", role_def.name);
+                (RoleSyncOutcome::Updated, role.id)
+            }
+            None => {
+                let role = guild_id.create_role(http, role_builder(role_def)).await?;
+                info!("Created role '{}' (ID: {})", role_def.name, role.id);
+                (RoleSyncOutcome::Created, role.id)
+            }
+        };
+
+        let mut state = self.state.write().await;
+        let guild = state.get_guild_mut(&guild_id.to_string(), "");
+        guild.add_role(
+            &role_def.name,
+            &role_id.to_string(),
+            role_def.color.as_deref(),
+            outcome == RoleSyncOutcome::Created,
+        );
+        Ok(outcome)
+    }
+
     /// Get role ID by name from cache or fetch from Discord
     pub async fn get_role_id(
         &self,
@@ -134,7 +322,7 @@ impl RoleManager {
                 // Update cache
                 let mut state = self.state.write().await;
                 let guild = state.get_guild_mut(&guild_id.to_string(), "");
-                guild.add_role(role_name, &role_id.to_string(), None);
+                guild.add_role(role_name, &role_id.to_string(), None, false);
 
                 return Ok(role_id);
             }
@@ -209,6 +397,104 @@ impl RoleManager {
         Ok(())
     }
 
+    /// Remove multiple roles from a user, skipping roles they don't have
+    /// Returns (roles_removed, roles_failed)
+    pub async fn remove_roles_from_user(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_names: &[String],
+    ) -> (Vec<String>, Vec<String>) {
+        let mut removed = Vec::new();
+        let mut failed = Vec::new();
+
+        let member = match guild_id.member(http, user_id).await {
+            Ok(m) => m,
+            Err(e) => {
+                // Not in the guild - nothing to remove
+                debug!("Member {} not found in guild {}: {}", user_id, guild_id, e);
+                return (removed, failed);
+            }
+        };
+
+        for role_name in role_names {
+            let role_id = match self.get_role_id(http, guild_id, role_name).await {
+                Ok(id) => id,
+                Err(e) => {
+                    warn!("Role '{}' not found in guild {}: {}", role_name, guild_id, e);
+                    failed.push(role_name.clone());
+                    continue;
+                }
+            };
+
+            if !member.roles.contains(&role_id) {
+                continue;
+            }
+
+            match member.remove_role(http, role_id).await {
+                Ok(_) => {
+                    info!("Removed role '{}' from user {}", role_name, user_id);
+                    removed.push(role_name.clone());
+                }
+                Err(e) => {
+                    error!("Failed to remove role '{}' from {}: {}", role_name, user_id, e);
+                    failed.push(role_name.clone());
+                }
+            }
+        }
+
+        (removed, failed)
+    }
+
+    /// Remove a role from every guild member who has it
+    /// Returns (members_removed, members_failed)
+    pub async fn remove_role_from_all_members(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        role_name: &str,
+    ) -> Result<(usize, Vec<String>)> {
+        let role_id = self.get_role_id(http, guild_id, role_name).await?;
+        let mut removed = 0;
+        let mut failed = Vec::new();
+        let mut after: Option<UserId> = None;
+
+        loop {
+            let members = guild_id.members(http, Some(1000), after).await?;
+            let Some(last) = members.last() else {
+                break;
+            };
+            after = Some(last.user.id);
+            let page_len = members.len();
+
+            for member in members.iter().filter(|m| m.roles.contains(&role_id)) {
+                match member.remove_role(http, role_id).await {
+                    Ok(_) => removed += 1,
+                    Err(e) => {
+                        error!(
+                            "Failed to remove role '{}' from {}: {}",
+                            role_name, member.user.name, e
+                        );
+                        failed.push(member.user.name.clone());
+                    }
+                }
+            }
+
+            if page_len < 1000 {
+                break;
+            }
+        }
+
+        info!(
+            "Removed role '{}' from {} members ({} failed)",
+            role_name,
+            removed,
+            failed.len()
+        );
+        Ok((removed, failed))
+    }
+
     /// Check if a role exists in the guild
     pub async fn role_exists(&self, http: &Http, guild_id: GuildId, role_name: &str) -> bool {
         self.get_role_id(http, guild_id, role_name).await.is_ok()
@@ -317,6 +603,44 @@ impl RoleManager {
         (added, failed)
     }
 
+    /// Apply nickname and roles to a freshly verified member
+    /// Returns (roles_added, roles_failed)
+    pub async fn apply_verified_member(
+        &self,
+        http: &Http,
+        guild_id: GuildId,
+        user_id: UserId,
+        discord_username: &str,
+        display_name: &str,
+        roles: &[String],
+    ) -> (Vec<String>, Vec<String>) {
+        // Set nickname
+        if let Err(e) = guild_id
+            .edit_member(http, user_id, serenity::EditMember::new().nickname(display_name))
+            .await
+        {
+            error!(
+                "Failed to set nickname for {} in guild {}: {}. Bot requires 'Manage Nicknames' permission and must have a higher role than the target user.",
+                user_id, guild_id, e
+            );
+        } else {
+            info!("Set nickname for {} to '{}'", user_id, display_name);
+        }
+
+        let (added, failed) = self
+            .sync_assignments_for_user(http, guild_id, user_id, discord_username, roles)
+            .await;
+
+        if !added.is_empty() {
+            info!("Assigned roles to {}: {:?}", user_id, added);
+        }
+        if !failed.is_empty() {
+            warn!("Failed to assign some roles to {}: {:?}", user_id, failed);
+        }
+
+        (added, failed)
+    }
+
     /// Full sync of assignment roles for a user - adds missing roles AND removes roles they shouldn't have
     /// `desired_roles` - roles the user SHOULD have according to assignments.json
     /// `all_assignment_roles` - all roles that are managed by assignments.json (used to know which roles to potentially remove)
@@ -453,6 +777,216 @@ fn parse_hex_color(hex: &str) -> Option<Colour> {
     u32::from_str_radix(hex, 16).ok().map(Colour::new)
 }
 
+/// What `RoleManager::apply_role` did to a role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleSyncOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// Role settings from a definition, used both to create and to edit a role
+fn role_builder(role_def: &RoleDefinition) -> serenity::EditRole<'_> {
+    let color = role_def
+        .color
+        .as_ref()
+        .and_then(|c| parse_hex_color(c))
+        .unwrap_or_default();
+
+    let builder = serenity::EditRole::new()
+        .name(&role_def.name)
+        .colour(color)
+        .hoist(role_def.hoist)
+        .mentionable(role_def.mentionable);
+    if role_def.syncs_permissions() {
+        builder.permissions(role_def.get_permissions())
+    } else {
+        builder
+    }
+}
+
+/// Find the live role for a definition
+///
+/// Tries the definition's name and then each of its `previous_names`: first
+/// the role whose ID the channel state recorded under that name, so roles
+/// renamed in Discord are found, then a role with that name.
+fn resolve_role<'a>(
+    guild: Option<&GuildChannelState>,
+    roles: &'a HashMap<RoleId, serenity::Role>,
+    role_def: &RoleDefinition,
+) -> Option<&'a serenity::Role> {
+    let by_id = |name: &String| {
+        let id = guild?.get_role_id(name)?.parse::<u64>().ok()?;
+        roles.get(&RoleId::new(id))
+    };
+    let by_name = |name: &String| roles.values().find(|r| r.name == *name);
+    std::iter::once(&role_def.name)
+        .chain(&role_def.previous_names)
+        .find_map(|name| by_id(name).or_else(|| by_name(name)))
+}
+
+/// What `RoleManager::apply_role_order` did
+#[derive(Debug, Default)]
+pub struct RoleOrderReport {
+    /// Roles from the config that moved
+    pub moved: Vec<String>,
+    /// One line per role that could not be placed because it sits at or
+    /// above the bot's highest role
+    pub above_bot: Vec<String>,
+}
+
+/// The moves that put roles with a `position` in config order
+struct RoleOrder<'a> {
+    /// (role, current position, new position) of every role that moves
+    moves: Vec<(RoleId, u16, u16)>,
+    /// The config roles among `moves`
+    moved: Vec<(&'a str, u16, u16)>,
+    /// Config roles at or above the bot's highest role, with their position
+    above_bot: Vec<(&'a str, u16)>,
+    /// Whether some roles with a position do not exist yet
+    unplaced: bool,
+}
+
+impl RoleOrder<'_> {
+    fn above_bot_warnings(&self, snapshot: &GuildSnapshot) -> Vec<String> {
+        let bot_role = snapshot
+            .bot_top_role()
+            .map(|r| format!("'{}'", r.name))
+            .unwrap_or_else(|| "(none)".to_string());
+        self.above_bot
+            .iter()
+            .map(|(name, position)| {
+                format!(
+                    "Role '{}' (position {}) is not below the bot's highest role {}, so its position can't be set - move the bot's role above it in Discord server settings",
+                    name, position, bot_role
+                )
+            })
+            .collect()
+    }
+}
+
+/// Work out where roles with a `position` go, see `role_positions`
+fn role_order<'a>(
+    guild: Option<&GuildChannelState>,
+    snapshot: &GuildSnapshot,
+    roles: &'a [RoleDefinition],
+) -> RoleOrder<'a> {
+    let bot_position = snapshot.bot_top_role().map_or(0, |r| r.position);
+    let mut positioned: Vec<(u16, usize, &RoleDefinition)> = roles
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| r.position.map(|p| (p, i, r)))
+        .collect();
+    positioned.sort_by_key(|(position, i, _)| (*position, *i));
+
+    let mut order = RoleOrder {
+        moves: Vec::new(),
+        moved: Vec::new(),
+        above_bot: Vec::new(),
+        unplaced: false,
+    };
+    let mut ordered: Vec<(RoleId, &str)> = Vec::new();
+    for (_, _, role_def) in positioned {
+        match resolve_role(guild, &snapshot.roles, role_def) {
+            Some(role) if role.position >= bot_position => {
+                order.above_bot.push((role_def.name.as_str(), role.position));
+            }
+            Some(role) => ordered.push((role.id, role_def.name.as_str())),
+            None => order.unplaced = true,
+        }
+    }
+
+    let ids: Vec<RoleId> = ordered.iter().map(|(id, _)| *id).collect();
+    order.moves = role_positions(&snapshot.roles, snapshot.everyone_role, bot_position, &ids);
+    for (id, name) in ordered {
+        if let Some((_, from, to)) = order.moves.iter().find(|(moved, _, _)| *moved == id) {
+            order.moved.push((name, *from, *to));
+        }
+    }
+    order
+}
+
+/// New positions that put `ordered` directly below the bot's highest role
+///
+/// `ordered` lists roles top to bottom; the other roles below the bot keep
+/// their relative order underneath them. Positions are renumbered from 1,
+/// just above @everyone, and only roles whose position changes are returned,
+/// as (role, current position, new position). Nothing moves when the order
+/// already matches.
+fn role_positions(
+    roles: &HashMap<RoleId, serenity::Role>,
+    everyone: RoleId,
+    bot_position: u16,
+    ordered: &[RoleId],
+) -> Vec<(RoleId, u16, u16)> {
+    let mut current: Vec<&serenity::Role> = roles
+        .values()
+        .filter(|r| r.id != everyone && r.position < bot_position)
+        .collect();
+    current.sort_by_key(|r| (std::cmp::Reverse(r.position), r.id));
+
+    let desired: Vec<&serenity::Role> = ordered
+        .iter()
+        .filter_map(|id| roles.get(id))
+        .chain(current.iter().copied().filter(|r| !ordered.contains(&r.id)))
+        .collect();
+    if desired.iter().map(|r| r.id).eq(current.iter().map(|r| r.id)) {
+        return Vec::new();
+    }
+
+    let top = desired.len() as u16;
+    desired
+        .iter()
+        .enumerate()
+        .filter_map(|(i, role)| {
+            let position = top - i as u16;
+            (role.position != position).then_some((role.id, role.position, position))
+        })
+        .collect()
+}
+
+/// Describe the settings of `role` that differ from its definition
+fn role_edits(role: &serenity::Role, role_def: &RoleDefinition) -> Vec<String> {
+    let mut edits = Vec::new();
+
+    if role.name != role_def.name {
+        edits.push(format!("name '{}' → '{}'", role.name, role_def.name));
+    }
+
+    let target_color = role_def
+        .color
+        .as_ref()
+        .and_then(|c| parse_hex_color(c))
+        .unwrap_or_default();
+    if role.colour != target_color {
+        edits.push(format!("color #{:06x} → #{:06x}", role.colour.0, target_color.0));
+    }
+    if role.hoist != role_def.hoist {
+        edits.push(format!("hoist {} → {}", role.hoist, role_def.hoist));
+    }
+    if role.mentionable != role_def.mentionable {
+        edits.push(format!(
+            "mentionable {} → {}",
+            role.mentionable, role_def.mentionable
+        ));
+    }
+    if role_def.syncs_permissions() {
+        let target = role_def.get_permissions();
+        let mut changes: Vec<String> = Vec::new();
+        for name in (target - role.permissions).get_permission_names() {
+            changes.push(format!("+{}", name));
+        }
+        for name in (role.permissions - target).get_permission_names() {
+            changes.push(format!("-{}", name));
+        }
+        if !changes.is_empty() {
+            edits.push(format!("permissions {}", changes.join(", ")));
+        }
+    }
+
+    edits
+}
+
 /// Shared role manager type
 pub type SharedRoleManager = Arc<tokio::sync::RwLock<RoleManager>>;
 
@@ -470,4 +1004,109 @@ mod tests {
         assert_eq!(parse_hex_color("00ff00"), Some(Colour::new(0x00ff00)));
         assert_eq!(parse_hex_color("#2ecc71"), Some(Colour::new(0x2ecc71)));
     }
+
+    #[test]
+    fn test_role_edits() {
+        let role: serenity::Role = serde_json::from_value(serde_json::json!({
+            "id": "5",
+            "guild_id": "1",
+            "name": "Board",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "mentionable": true,
+            "permissions": "0",
+            "position": 3
+        }))
+        .unwrap();
+        let role_def: RoleDefinition = serde_json::from_str(
+            r##"{ "name": "Board", "color": "#2ecc71", "hoist": true, "mentionable": true }"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            role_edits(&role, &role_def),
+            vec!["color #000000 → #2ecc71", "hoist false → true"]
+        );
+
+        // Permissions are only compared when the definition lists them
+        let role_def: RoleDefinition = serde_json::from_str(
+            r##"{ "name": "Board2", "mentionable": true, "permissions": ["CHANGE_NICKNAME"] }"##,
+        )
+        .unwrap();
+        assert_eq!(
+            role_edits(&role, &role_def),
+            vec!["name 'Board' → 'Board2'", "permissions +Change Nickname"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_role() {
+        let role = |id: u64, name: &str| -> serenity::Role {
+            serde_json::from_value(serde_json::json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "name": name,
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "mentionable": false,
+                "permissions": "0",
+                "position": 1
+            }))
+            .unwrap()
+        };
+        let roles: HashMap<RoleId, serenity::Role> = [role(5, "Board (renamed)"), role(6, "Helpers")]
+            .into_iter()
+            .map(|r| (r.id, r))
+            .collect();
+        let mut guild = GuildChannelState::new("1", "Guild");
+        guild.add_role("Board", "5", None, true);
+
+        // Found through the ID recorded in state despite the rename in Discord
+        let role_def: RoleDefinition = serde_json::from_str(r#"{ "name": "Board" }"#).unwrap();
+        assert_eq!(resolve_role(Some(&guild), &roles, &role_def).map(|r| r.id.get()), Some(5));
+
+        // Found through a previous name when state does not know the role
+        let role_def: RoleDefinition =
+            serde_json::from_str(r#"{ "name": "Mentors", "previous_names": ["Helpers"] }"#).unwrap();
+        assert_eq!(resolve_role(None, &roles, &role_def).map(|r| r.id.get()), Some(6));
+
+        let role_def: RoleDefinition = serde_json::from_str(r#"{ "name": "Mentors" }"#).unwrap();
+        assert!(resolve_role(None, &roles, &role_def).is_none());
+    }
+
+    #[test]
+    fn test_role_positions() {
+        let role = |id: u64, position: u16| -> (RoleId, serenity::Role) {
+            let role: serenity::Role = serde_json::from_value(serde_json::json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "name": format!("role{}", id),
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "mentionable": false,
+                "permissions": "0",
+                "position": position
+            }))
+            .unwrap();
+            (role.id, role)
+        };
+        // @everyone (1), then roles 10-13 from the bottom up, then the bot's role (20)
+        let roles: HashMap<RoleId, serenity::Role> =
+            [role(1, 0), role(10, 1), role(11, 2), role(12, 3), role(13, 4), role(20, 5)]
+                .into_iter()
+                .collect();
+        let id = RoleId::new;
+
+        // Already directly below the bot in this order
+        assert!(role_positions(&roles, id(1), 5, &[id(13), id(12)]).is_empty());
+
+        // 10 goes to the top; the others keep their order below it
+        assert_eq!(
+            role_positions(&roles, id(1), 5, &[id(10), id(13)]),
+            vec![(id(10), 1, 4), (id(13), 4, 3), (id(12), 3, 2), (id(11), 2, 1)]
+        );
+    }
 }
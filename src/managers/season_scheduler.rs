@@ -0,0 +1,253 @@
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{GuildId, Http};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::config::{Season, SeasonPhase};
+use crate::managers::{SharedConfigManager, SharedMaintainersManager};
+use crate::state::{user_database_path, SharedUserDatabase};
+
+/// How often the scheduler checks season start/end dates
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A season whose `active` flag the scheduler is about to change
+#[derive(Debug, Clone, PartialEq)]
+struct SeasonTransition {
+    season_id: String,
+    name: String,
+    phase: SeasonPhase,
+    active: bool,
+}
+
+impl SeasonTransition {
+    fn notice(&self) -> String {
+        match self.phase {
+            SeasonPhase::Running => format!(
+                "📅 Season **{}** (`{}`) has started and is now open for verification.",
+                self.name, self.season_id
+            ),
+            SeasonPhase::NotStarted => format!(
+                "📅 Season **{}** (`{}`) has not started yet and was set inactive.",
+                self.name, self.season_id
+            ),
+            SeasonPhase::Ended => format!(
+                "📅 Season **{}** (`{}`) has ended and is now closed for verification.",
+                self.name, self.season_id
+            ),
+        }
+    }
+}
+
+/// Flips seasons active/inactive according to their `starts_at` and `ends_at` dates
+///
+/// The scheduler only acts when it first sees a season or when the season
+/// crosses one of its dates, so a maintainer can still toggle `active` by hand
+/// in between. When a season ends, verified users left without an active
+/// season are marked expired.
+pub struct SeasonScheduler {
+    config_manager: SharedConfigManager,
+    maintainers_manager: SharedMaintainersManager,
+    user_db: SharedUserDatabase,
+    http: Arc<Http>,
+    guild_id: Option<GuildId>,
+    last_phases: HashMap<String, SeasonPhase>,
+}
+
+impl SeasonScheduler {
+    pub fn new(
+        config_manager: SharedConfigManager,
+        maintainers_manager: SharedMaintainersManager,
+        user_db: SharedUserDatabase,
+        http: Arc<Http>,
+        guild_id: Option<GuildId>,
+    ) -> Self {
+        Self {
+            config_manager,
+            maintainers_manager,
+            user_db,
+            http,
+            guild_id,
+            last_phases: HashMap::new(),
+        }
+    }
+
+    /// Run the scheduler loop forever
+    pub async fn run(mut self) {
+        info!(
+            "Season scheduler started (checking every {}s)",
+            SCHEDULER_INTERVAL.as_secs()
+        );
+        let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+        loop {
+            interval.tick().await;
+            self.tick(Utc::now()).await;
+        }
+    }
+
+    /// Check all seasons once and apply any transitions
+    async fn tick(&mut self, now: DateTime<Utc>) {
+        let transitions = {
+            let config = self.config_manager.read().await;
+            collect_transitions(config.get_all_seasons(), &mut self.last_phases, now)
+        };
+        let season_ended = transitions.iter().any(|t| t.phase == SeasonPhase::Ended);
+
+        for transition in transitions {
+            let result = {
+                let mut config = self.config_manager.write().await;
+                config
+                    .set_season_active(
+                        &transition.season_id,
+                        transition.active,
+                        "season scheduler",
+                    )
+                    .await
+            };
+
+            if let Err(e) = result {
+                error!(
+                    "Failed to set season '{}' active={}: {}",
+                    transition.season_id, transition.active, e
+                );
+                continue;
+            }
+
+            self.notify(&transition.notice()).await;
+        }
+
+        if season_ended {
+            self.expire_lapsed_users().await;
+        }
+    }
+
+    /// Mark verified users expired when none of their seasons is active anymore
+    async fn expire_lapsed_users(&self) {
+        let expired = {
+            let config = self.config_manager.read().await;
+            let mut db = self.user_db.write().await;
+            let expired = db.expire_lapsed(|season_id| {
+                config.get_season(season_id).is_some_and(|s| s.is_active())
+            });
+            if !expired.is_empty() {
+                if let Err(e) = db.save(&user_database_path()).await {
+                    error!("Failed to save user database after expiring users: {}", e);
+                }
+            }
+            expired
+        };
+
+        if !expired.is_empty() {
+            self.notify(&format!(
+                "⌛ {} verified member(s) no longer belong to an active season and were marked \
+                 expired.",
+                expired.len()
+            ))
+            .await;
+        }
+    }
+
+    async fn notify(&self, message: &str) {
+        info!("{}", message);
+        let Some(guild_id) = self.guild_id else {
+            return;
+        };
+        let maintainers_manager = self.maintainers_manager.read().await;
+        if let Err(e) = maintainers_manager
+            .post_notice(&self.http, guild_id, message)
+            .await
+        {
+            warn!("Failed to post season notice to maintainers channel: {}", e);
+        }
+    }
+}
+
+/// Work out which scheduled seasons need their `active` flag changed
+///
+/// Updates `last_phases` with the phase of every scheduled season.
+fn collect_transitions<'a>(
+    seasons: impl IntoIterator<Item = &'a Season>,
+    last_phases: &mut HashMap<String, SeasonPhase>,
+    now: DateTime<Utc>,
+) -> Vec<SeasonTransition> {
+    let mut transitions = Vec::new();
+
+    for season in seasons {
+        if !season.config.has_schedule() {
+            last_phases.remove(&season.season_id);
+            continue;
+        }
+
+        let phase = season.config.phase_at(now);
+        let previous = last_phases.insert(season.season_id.clone(), phase);
+        if previous == Some(phase) {
+            continue;
+        }
+
+        let active = phase == SeasonPhase::Running;
+        if season.is_active() != active {
+            transitions.push(SeasonTransition {
+                season_id: season.season_id.clone(),
+                name: season.name().to_string(),
+                phase,
+                active,
+            });
+        }
+    }
+
+    transitions
+}
+
+/// Start the season scheduler as a background task
+pub fn spawn_season_scheduler(
+    config_manager: SharedConfigManager,
+    maintainers_manager: SharedMaintainersManager,
+    user_db: SharedUserDatabase,
+    http: Arc<Http>,
+    guild_id: Option<GuildId>,
+) {
+    let scheduler =
+        SeasonScheduler::new(config_manager, maintainers_manager, user_db, http, guild_id);
+    tokio::spawn(scheduler.run());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SeasonConfig;
+
+    fn scheduled_season(active: bool) -> Season {
+        let config = SeasonConfig {
+            active,
+            starts_at: Some("2025-08-25T00:00:00Z".parse().unwrap()),
+            ends_at: Some("2026-01-31T00:00:00Z".parse().unwrap()),
+            ..SeasonConfig::default()
+        };
+        Season::new("2025E".to_string(), config, vec![])
+    }
+
+    #[test]
+    fn test_transitions_only_on_phase_change() {
+        let mut last_phases = HashMap::new();
+        let before: DateTime<Utc> = "2025-08-01T00:00:00Z".parse().unwrap();
+        let during: DateTime<Utc> = "2025-09-01T00:00:00Z".parse().unwrap();
+
+        // First sighting before the start date deactivates the season
+        let season = scheduled_season(true);
+        let t = collect_transitions([&season], &mut last_phases, before);
+        assert_eq!(t.len(), 1);
+        assert!(!t[0].active);
+
+        // A manual override within the same phase is left alone
+        let season = scheduled_season(true);
+        assert!(collect_transitions([&season], &mut last_phases, before).is_empty());
+
+        // Crossing the start date activates it
+        let season = scheduled_season(false);
+        let t = collect_transitions([&season], &mut last_phases, during);
+        assert_eq!(t.len(), 1);
+        assert!(t[0].active);
+        assert_eq!(t[0].phase, SeasonPhase::Running);
+    }
+}
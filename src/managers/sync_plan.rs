@@ -0,0 +1,363 @@
+use poise::serenity_prelude::{
+    self as serenity, ChannelId, GuildId, Http, Permissions, RoleId, UserId,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::error::Result;
+
+/// The guild's channels and roles, fetched once so a sync can be planned against them
+pub struct GuildSnapshot {
+    pub guild_id: GuildId,
+    pub channels: HashMap<ChannelId, serenity::GuildChannel>,
+    pub roles: HashMap<RoleId, serenity::Role>,
+    pub everyone_role: RoleId,
+    pub bot_user: UserId,
+    pub bot_roles: Vec<RoleId>,
+}
+
+impl GuildSnapshot {
+    /// Fetch the current channels and roles of a guild
+    pub async fn fetch(http: &Http, guild_id: GuildId) -> Result<Self> {
+        let bot_user = http.get_current_user().await?.id;
+        Ok(Self {
+            guild_id,
+            channels: guild_id.channels(http).await?,
+            roles: guild_id.roles(http).await?,
+            everyone_role: guild_id.everyone_role(),
+            bot_user,
+            bot_roles: guild_id.member(http, bot_user).await?.roles,
+        })
+    }
+
+    /// The bot's highest role, which every role it manages must sit below
+    pub fn bot_top_role(&self) -> Option<&serenity::Role> {
+        self.bot_roles
+            .iter()
+            .filter_map(|id| self.roles.get(id))
+            .max_by_key(|r| r.position)
+    }
+
+    /// Find a role by name
+    pub fn role_by_name(&self, name: &str) -> Option<&serenity::Role> {
+        self.roles.values().find(|r| r.name == name)
+    }
+
+    /// Who a permission overwrite applies to, as shown in a plan
+    pub fn overwrite_target(&self, kind: &serenity::PermissionOverwriteType) -> String {
+        match kind {
+            serenity::PermissionOverwriteType::Role(id) if *id == self.everyone_role => {
+                "@everyone".to_string()
+            }
+            serenity::PermissionOverwriteType::Role(id) => self
+                .roles
+                .get(id)
+                .map(|r| r.name.clone())
+                .unwrap_or_else(|| format!("role {}", id)),
+            serenity::PermissionOverwriteType::Member(id) if *id == self.bot_user => {
+                "bot".to_string()
+            }
+            serenity::PermissionOverwriteType::Member(id) => format!("member {}", id),
+            _ => "unknown".to_string(),
+        }
+    }
+}
+
+/// What a planned change does to its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlanAction {
+    Create,
+    Update,
+    Rename,
+    Reorder,
+    Delete,
+}
+
+impl PlanAction {
+    fn symbol(self) -> char {
+        match self {
+            PlanAction::Create => '+',
+            PlanAction::Update | PlanAction::Rename | PlanAction::Reorder => '~',
+            PlanAction::Delete => '-',
+        }
+    }
+}
+
+/// One change a sync would make
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlannedChange {
+    pub action: PlanAction,
+    /// e.g. "role Medlem2025E" or "channel #general (2025E)"
+    pub target: String,
+    pub details: Vec<String>,
+    /// Season category or channel the change applies to, if it is limited to one
+    pub channel: Option<ChannelTarget>,
+}
+
+/// A category of a season's layout, or a channel inside it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChannelTarget {
+    pub category: String,
+    /// None for the category itself
+    pub channel: Option<String>,
+}
+
+impl PlannedChange {
+    /// The change as a single diff-style line
+    pub fn line(&self) -> String {
+        if self.details.is_empty() {
+            format!("{} {}", self.action.symbol(), self.target)
+        } else {
+            format!(
+                "{} {}: {}",
+                self.action.symbol(),
+                self.target,
+                self.details.join("; ")
+            )
+        }
+    }
+}
+
+/// Changes a sync would make, computed without touching Discord
+#[derive(Debug, Default, Clone)]
+pub struct SyncPlan {
+    pub changes: Vec<PlannedChange>,
+    pub warnings: Vec<String>,
+}
+
+impl SyncPlan {
+    /// Add a change
+    pub fn push(&mut self, action: PlanAction, target: impl Into<String>, details: Vec<String>) {
+        self.changes.push(PlannedChange {
+            action,
+            target: target.into(),
+            details,
+            channel: None,
+        });
+    }
+
+    /// Add a change to one category or channel of a season
+    pub fn push_channel(
+        &mut self,
+        channel: ChannelTarget,
+        action: PlanAction,
+        target: impl Into<String>,
+        details: Vec<String>,
+    ) {
+        self.push(action, target, details);
+        if let Some(change) = self.changes.last_mut() {
+            change.channel = Some(channel);
+        }
+    }
+
+    /// Whether the sync would change nothing
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes with the given action
+    pub fn count(&self, action: PlanAction) -> usize {
+        self.changes.iter().filter(|c| c.action == action).count()
+    }
+
+    /// One-line summary, e.g. "Plan: 2 to create, 1 to update, 0 to rename, 0 to reorder."
+    ///
+    /// Deletions are only mentioned when there are any.
+    pub fn headline(&self) -> String {
+        if self.is_empty() {
+            return "No changes. Discord already matches the config.".to_string();
+        }
+        let deletes = match self.count(PlanAction::Delete) {
+            0 => String::new(),
+            n => format!(", {} to delete", n),
+        };
+        format!(
+            "Plan: {} to create, {} to update, {} to rename, {} to reorder{}.",
+            self.count(PlanAction::Create),
+            self.count(PlanAction::Update),
+            self.count(PlanAction::Rename),
+            self.count(PlanAction::Reorder),
+            deletes
+        )
+    }
+
+    /// All changes as diff-style lines, one per change
+    pub fn lines(&self) -> Vec<String> {
+        self.changes.iter().map(PlannedChange::line).collect()
+    }
+
+    /// Format the plan for Discord, cut at a line boundary to fit in `max_len` bytes
+    pub fn format_truncated(&self, max_len: usize) -> String {
+        let mut output = format!("**{}**\n", self.headline());
+        let mut warnings = String::new();
+        if !self.warnings.is_empty() {
+            warnings = format!("\n**Warnings:**\n- {}", self.warnings.join("\n- "));
+        }
+        if self.is_empty() {
+            output.push_str(&warnings);
+            return output;
+        }
+
+        let lines = self.lines();
+        output.push_str("```diff\n");
+        for (i, line) in lines.iter().enumerate() {
+            // Leave room for the "more changes" note, the closing fence and the warnings
+            if output.len() + line.len() + warnings.len().min(300) + 50 > max_len {
+                output.push_str(&format!("… and {} more change(s)\n", lines.len() - i));
+                break;
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+        output.push_str("```");
+
+        if output.len() + warnings.len() <= max_len {
+            output.push_str(&warnings);
+        } else if !self.warnings.is_empty() {
+            output.push_str(&format!(
+                "\n**Warnings:** {} (see the logs)",
+                self.warnings.len()
+            ));
+        }
+        output
+    }
+
+    /// Short hash of the changes, used to check that an applied plan is still current
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.changes.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// A permission overwrite keyed by who it applies to
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverwriteSpec {
+    pub target: String,
+    /// Config level name for role overwrites, shown in the plan
+    pub level: Option<String>,
+    pub allow: Permissions,
+    pub deny: Permissions,
+}
+
+/// Describe how `desired` overwrites differ from the `live` ones
+///
+/// Entries read "+target (level)" for added, "~target (level)" for changed and
+/// "-target" for removed overwrites, sorted by target. With `keep_unlisted`,
+/// live overwrites missing from `desired` are left alone instead of removed.
+pub fn overwrite_changes(
+    live: &[OverwriteSpec],
+    desired: &[OverwriteSpec],
+    keep_unlisted: bool,
+) -> Vec<String> {
+    let label = |spec: &OverwriteSpec| match &spec.level {
+        Some(level) => format!("{} ({})", spec.target, level),
+        None => spec.target.clone(),
+    };
+
+    let mut changes: Vec<(String, String)> = Vec::new();
+    for want in desired {
+        match live.iter().find(|have| have.target == want.target) {
+            None => changes.push((want.target.clone(), format!("+{}", label(want)))),
+            Some(have) if have.allow != want.allow || have.deny != want.deny => {
+                changes.push((want.target.clone(), format!("~{}", label(want))))
+            }
+            Some(_) => {}
+        }
+    }
+    if !keep_unlisted {
+        for have in live {
+            if !desired.iter().any(|want| want.target == have.target) {
+                changes.push((have.target.clone(), format!("-{}", have.target)));
+            }
+        }
+    }
+
+    changes.sort();
+    changes.into_iter().map(|(_, change)| change).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(target: &str, allow: Permissions) -> OverwriteSpec {
+        OverwriteSpec {
+            target: target.to_string(),
+            level: None,
+            allow,
+            deny: Permissions::empty(),
+        }
+    }
+
+    #[test]
+    fn test_overwrite_changes() {
+        let live = vec![
+            spec("@everyone", Permissions::empty()),
+            spec("Medlem2025E", Permissions::VIEW_CHANNEL),
+            spec("Old", Permissions::VIEW_CHANNEL),
+        ];
+        let mut board = spec("Board", Permissions::VIEW_CHANNEL);
+        board.level = Some("admin".to_string());
+        let desired = vec![
+            spec("Medlem2025E", Permissions::VIEW_CHANNEL),
+            spec("@everyone", Permissions::CONNECT),
+            board,
+        ];
+
+        assert_eq!(
+            overwrite_changes(&live, &desired, false),
+            vec!["~@everyone", "+Board (admin)", "-Old"]
+        );
+        assert_eq!(
+            overwrite_changes(&live, &desired, true),
+            vec!["~@everyone", "+Board (admin)"]
+        );
+    }
+
+    #[test]
+    fn test_plan_format_and_fingerprint() {
+        let mut plan = SyncPlan::default();
+        assert!(plan.format_truncated(2000).starts_with("**No changes."));
+
+        plan.push(PlanAction::Create, "role Medlem2025E", vec![]);
+        plan.push(
+            PlanAction::Update,
+            "role Board",
+            vec!["hoist false → true".to_string()],
+        );
+        assert_eq!(
+            plan.headline(),
+            "Plan: 1 to create, 1 to update, 0 to rename, 0 to reorder."
+        );
+        assert_eq!(
+            plan.lines(),
+            vec!["+ role Medlem2025E", "~ role Board: hoist false → true"]
+        );
+
+        let mut with_delete = plan.clone();
+        with_delete.push(PlanAction::Delete, "role Medlem2024E", vec![]);
+        assert!(with_delete
+            .headline()
+            .ends_with("0 to reorder, 1 to delete."));
+        assert_eq!(with_delete.lines()[2], "- role Medlem2024E");
+
+        let fingerprint = plan.fingerprint();
+        assert_eq!(fingerprint, plan.clone().fingerprint());
+        plan.push(PlanAction::Reorder, "channel #general (2025E)", vec![]);
+        assert_ne!(fingerprint, plan.fingerprint());
+
+        for i in 0..100 {
+            plan.push(
+                PlanAction::Create,
+                format!("channel #channel-{}", i),
+                vec![],
+            );
+        }
+        let formatted = plan.format_truncated(500);
+        assert!(formatted.len() <= 500);
+        assert!(formatted.contains("more change(s)"));
+        assert!(formatted.ends_with("```"));
+    }
+}
@@ -2,9 +2,9 @@ use poise::serenity_prelude::UserId;
 use std::sync::Arc;
 use tracing::info;
 
-use crate::error::Result;
+use crate::error::{BotError, Result};
 use crate::managers::{ConfigManager, SharedConfigManager};
-use crate::state::{SharedUserDatabase, TrackedUser, UserDatabase};
+use crate::state::{SharedUserDatabase, TrackedUser, UserDatabase, VerificationStatus};
 
 /// Result of a verification attempt
 #[derive(Debug, Clone)]
@@ -66,15 +66,18 @@ impl VerificationManager {
         let (season_id, season_user) = match config.find_user_by_verification_id(provided_id) {
             Some((season, user)) => (season.season_id.clone(), user.clone()),
             None => {
+                let error = config.verification_closed_reason(provided_id).unwrap_or_else(|| {
+                    format!(
+                        "Could not find ID '{}' in our records. Please check your ID and try again.",
+                        provided_id
+                    )
+                });
                 return VerificationResult {
                     success: false,
                     display_name: String::new(),
                     seasons: vec![],
                     roles_to_assign: vec![],
-                    error: Some(format!(
-                        "Could not find ID '{}' in our records. Please check your ID and try again.",
-                        provided_id
-                    )),
+                    error: Some(error),
                 };
             }
         };
@@ -98,6 +101,19 @@ impl VerificationManager {
 
             // Check if this Discord user is already verified for THIS season
             if let Some(existing) = db.find_by_discord_id(&user_id.to_string()) {
+                if existing.is_revoked() {
+                    return VerificationResult {
+                        success: false,
+                        display_name: existing.display_name.clone(),
+                        seasons: vec![],
+                        roles_to_assign: vec![],
+                        error: Some(
+                            "Your verification has been revoked. Please contact an administrator."
+                                .to_string(),
+                        ),
+                    };
+                }
+
                 if existing.verification_ids.contains_key(&season_id) {
                     return VerificationResult {
                         success: false,
@@ -115,16 +131,7 @@ impl VerificationManager {
 
         // Found the user! Get their special roles by Discord username
         let special_roles = config.get_special_roles_for_user(discord_username);
-
-        // Get the season-specific member role (e.g., "Medlem2025F")
-        // This is loaded from season.json's member_role field, or falls back to "Medlem{season_id}"
-        let season_member_role = config
-            .get_season(&season_id)
-            .map(|s| s.member_role())
-            .unwrap_or_else(|| format!("Medlem{}", season_id));
-
-        let mut roles_to_assign = vec![season_member_role];
-        roles_to_assign.extend(special_roles.clone());
+        let roles_to_assign = season_roles(&config, &season_id, discord_username);
 
         let display_name = season_user.name.clone();
 
@@ -144,6 +151,8 @@ impl VerificationManager {
                 }
                 // Update display name if it changed (optional, but good practice)
                 updated_user.display_name = display_name.clone();
+                // Verifying for a new season re-activates expired users
+                updated_user.verification_status = VerificationStatus::Verified;
                 updated_user.update_last_seen();
 
                 db.upsert_user(updated_user);
@@ -173,6 +182,93 @@ impl VerificationManager {
         }
     }
 
+    /// Get the roles a user should have for a season (member role + special roles)
+    pub async fn get_roles_for_season(&self, season_id: &str, discord_username: &str) -> Vec<String> {
+        let config: tokio::sync::RwLockReadGuard<'_, ConfigManager> =
+            self.config_manager.read().await;
+        season_roles(&config, season_id, discord_username)
+    }
+
+    /// Get all roles a tracked user should have (member role per season + special roles)
+    pub async fn get_roles_for_user(&self, user: &TrackedUser) -> Vec<String> {
+        let config: tokio::sync::RwLockReadGuard<'_, ConfigManager> =
+            self.config_manager.read().await;
+
+        let mut roles: Vec<String> = Vec::new();
+        for season_id in user.verification_ids.keys() {
+            if let Some(season) = config.get_season(season_id) {
+                let member_role = season.member_role();
+                if !roles.contains(&member_role) {
+                    roles.push(member_role);
+                }
+            }
+        }
+        for role in &user.special_roles {
+            if !roles.contains(role) {
+                roles.push(role.clone());
+            }
+        }
+        roles
+    }
+
+    /// Revoke a user's verification, recording the reason in their notes
+    /// Returns the updated user; the caller is responsible for removing roles
+    pub async fn revoke_user(
+        &self,
+        user_id: UserId,
+        reason: &str,
+        revoked_by: &str,
+    ) -> Result<TrackedUser> {
+        let mut db: tokio::sync::RwLockWriteGuard<'_, UserDatabase> = self.user_db.write().await;
+        let user = db
+            .find_by_discord_id_mut(&user_id.to_string())
+            .ok_or_else(|| BotError::UserNotFound {
+                user_id: user_id.to_string(),
+            })?;
+
+        if user.verification_status != VerificationStatus::Verified {
+            return Err(BotError::NotVerified {
+                discord_id: user_id.to_string(),
+            });
+        }
+
+        user.revoke(reason, revoked_by);
+        info!(
+            "Revoked verification for {} ('{}') by {}: {}",
+            user_id, user.display_name, revoked_by, reason
+        );
+        Ok(user.clone())
+    }
+
+    /// Reinstate a revoked or expired user
+    /// Returns the updated user; the caller is responsible for restoring roles
+    pub async fn unrevoke_user(
+        &self,
+        user_id: UserId,
+        reason: Option<&str>,
+        reinstated_by: &str,
+    ) -> Result<TrackedUser> {
+        let mut db: tokio::sync::RwLockWriteGuard<'_, UserDatabase> = self.user_db.write().await;
+        let user = db
+            .find_by_discord_id_mut(&user_id.to_string())
+            .ok_or_else(|| BotError::UserNotFound {
+                user_id: user_id.to_string(),
+            })?;
+
+        if user.verification_status == VerificationStatus::Verified {
+            return Err(BotError::NotRevoked {
+                discord_id: user_id.to_string(),
+            });
+        }
+
+        user.reinstate(reason, reinstated_by);
+        info!(
+            "Reinstated verification for {} ('{}') by {}",
+            user_id, user.display_name, reinstated_by
+        );
+        Ok(user.clone())
+    }
+
     /// Save the user database to disk
     pub async fn save_database(&self, path: &str) -> Result<()> {
         let db: tokio::sync::RwLockReadGuard<'_, UserDatabase> = self.user_db.read().await;
@@ -198,6 +294,20 @@ impl VerificationManager {
     }
 }
 
+/// Season member role followed by the user's special roles
+fn season_roles(config: &ConfigManager, season_id: &str, discord_username: &str) -> Vec<String> {
+    // Get the season-specific member role (e.g., "Medlem2025F")
+    // This is loaded from season.json's member_role field, or falls back to "Medlem{season_id}"
+    let season_member_role = config
+        .get_season(season_id)
+        .map(|s| s.member_role())
+        .unwrap_or_else(|| format!("Medlem{}", season_id));
+
+    let mut roles = vec![season_member_role];
+    roles.extend(config.get_special_roles_for_user(discord_username));
+    roles
+}
+
 /// Shared verification manager type
 pub type SharedVerificationManager = Arc<VerificationManager>;
 
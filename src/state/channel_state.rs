@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Current schema version of the channel state file
+const STATE_VERSION: u32 = 3;
+
 /// State tracking for Discord channels, categories, and roles
 /// Used to minimize API calls by caching what's already been created
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -21,7 +24,7 @@ pub struct ChannelState {
 impl ChannelState {
     pub fn new() -> Self {
         Self {
-            version: 1,
+            version: STATE_VERSION,
             last_synced: current_timestamp(),
             guilds: HashMap::new(),
         }
@@ -31,10 +34,14 @@ impl ChannelState {
     pub async fn load(path: &str) -> crate::error::Result<Self> {
         match tokio::fs::read_to_string(path).await {
             Ok(content) => {
-                serde_json::from_str(&content).map_err(|e| crate::error::BotError::ConfigParse {
-                    path: path.to_string(),
-                    source: e,
-                })
+                let mut state: Self = serde_json::from_str(&content).map_err(|e| {
+                    crate::error::BotError::ConfigParse {
+                        path: path.to_string(),
+                        source: e,
+                    }
+                })?;
+                state.migrate();
+                Ok(state)
             }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
             Err(e) => Err(crate::error::BotError::StateLoad {
@@ -44,6 +51,34 @@ impl ChannelState {
         }
     }
 
+    /// Bring state written by an older version up to date
+    ///
+    /// Before version 2 every tracked entity was marked as created by the bot,
+    /// including ones it only found in Discord. Those flags can't be trusted, so
+    /// they are cleared and prune leaves entities recorded before then alone.
+    ///
+    /// Before version 3 channels were keyed by name alone, so they are re-keyed
+    /// by `channel_key`. Entries one season's channel overwrote in another
+    /// category are already lost; the next sync records them again.
+    fn migrate(&mut self) {
+        if self.version < 2 {
+            for guild in self.guilds.values_mut() {
+                guild.categories.values_mut().for_each(|c| c.created_by_bot = false);
+                guild.channels.values_mut().for_each(|c| c.created_by_bot = false);
+                guild.roles.values_mut().for_each(|r| r.created_by_bot = false);
+            }
+        }
+        if self.version < 3 {
+            for guild in self.guilds.values_mut() {
+                guild.channels = std::mem::take(&mut guild.channels)
+                    .into_values()
+                    .map(|c| (channel_key(c.parent_category.as_deref(), &c.name), c))
+                    .collect();
+            }
+        }
+        self.version = STATE_VERSION;
+    }
+
     /// Save to file atomically
     pub async fn save(&self, path: &str) -> crate::error::Result<()> {
         let content = serde_json::to_string_pretty(self)?;
@@ -79,6 +114,8 @@ impl ChannelState {
     }
 
     /// Check if an entity needs sync (doesn't exist or is outdated)
+    ///
+    /// Channels are looked up by their `channel_key`.
     pub fn needs_sync(&self, guild_id: &str, entity_type: EntityType, name: &str) -> bool {
         match self.guilds.get(guild_id) {
             None => true,
@@ -97,13 +134,33 @@ impl ChannelState {
 }
 
 /// Entity type for sync checking
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EntityType {
     Category,
     Channel,
     Role,
 }
 
+impl EntityType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EntityType::Category => "category",
+            EntityType::Channel => "channel",
+            EntityType::Role => "role",
+        }
+    }
+
+    /// Parse the name returned by `as_str`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "category" => Some(EntityType::Category),
+            "channel" => Some(EntityType::Channel),
+            "role" => Some(EntityType::Role),
+            _ => None,
+        }
+    }
+}
+
 /// State for a single guild
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -114,7 +171,7 @@ pub struct GuildChannelState {
     /// Categories (name -> state)
     pub categories: HashMap<String, CategoryState>,
 
-    /// Channels (name -> state)
+    /// Channels (`channel_key` -> state)
     pub channels: HashMap<String, ChannelStateEntry>,
 
     /// Roles (name -> state)
@@ -122,6 +179,10 @@ pub struct GuildChannelState {
 
     /// ID of the maintainers channel if it exists
     pub maintainers_channel_id: Option<String>,
+
+    /// Categories making up each season's layout (season ID -> state)
+    #[serde(default)]
+    pub seasons: HashMap<String, SeasonLayoutState>,
 }
 
 impl GuildChannelState {
@@ -133,68 +194,173 @@ impl GuildChannelState {
             channels: HashMap::new(),
             roles: HashMap::new(),
             maintainers_channel_id: None,
+            seasons: HashMap::new(),
         }
     }
 
-    /// Record a created category
-    pub fn add_category(&mut self, name: &str, discord_id: &str, position: u16) {
+    /// Record a category, `created` if the bot just created it
+    /// Keeps the archived flag if the category was already tracked and the
+    /// created-by-bot flag if it was tracked with the same ID, and moves an
+    /// entry for the same ID under an old name to `name`
+    pub fn add_category(&mut self, name: &str, discord_id: &str, position: u16, created: bool) {
+        let mut previous = self.categories.remove(name);
+        let mut created_by_bot = created
+            || previous
+                .as_ref()
+                .is_some_and(|c| c.discord_id == discord_id && c.created_by_bot);
+        for old in same_id_keys(&self.categories, discord_id, |c| &c.discord_id) {
+            let entry = self.categories.remove(&old);
+            created_by_bot |= entry.as_ref().is_some_and(|c| c.created_by_bot);
+            previous = entry.or(previous);
+            for layout in self.seasons.values_mut() {
+                layout
+                    .categories
+                    .iter_mut()
+                    .filter(|c| **c == old)
+                    .for_each(|c| *c = name.to_string());
+            }
+        }
+        let (archived, archived_at) = previous
+            .map(|c| (c.archived, c.archived_at))
+            .unwrap_or_default();
+
         self.categories.insert(
             name.to_string(),
             CategoryState {
                 discord_id: discord_id.to_string(),
                 name: name.to_string(),
                 position,
-                created_by_bot: true,
+                created_by_bot,
                 last_verified: current_timestamp(),
+                archived,
+                archived_at,
             },
         );
     }
 
-    /// Record a created channel
+    /// Mark a tracked category as archived
+    /// Returns false if the category is not tracked
+    pub fn mark_category_archived(&mut self, name: &str) -> bool {
+        match self.categories.get_mut(name) {
+            Some(category) => {
+                category.archived = true;
+                category.archived_at = Some(current_timestamp());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if a category has been archived
+    pub fn is_category_archived(&self, name: &str) -> bool {
+        self.categories.get(name).is_some_and(|c| c.archived)
+    }
+
+    /// Record the categories a season was last synced into, in order
+    pub fn set_season_categories(&mut self, season_id: &str, categories: Vec<String>) {
+        self.seasons.insert(
+            season_id.to_string(),
+            SeasonLayoutState {
+                categories,
+                last_synced: current_timestamp(),
+            },
+        );
+    }
+
+    /// Categories a season was last synced into (empty if never synced)
+    pub fn season_categories(&self, season_id: &str) -> &[String] {
+        self.seasons
+            .get(season_id)
+            .map(|s| s.categories.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Record a channel under its parent category's ID, `created` if the bot just created it
+    /// Keeps the created-by-bot flag if the channel was already tracked with
+    /// the same ID, and drops entries for the same ID under an old name or category
     pub fn add_channel(
         &mut self,
         name: &str,
         discord_id: &str,
         parent: Option<&str>,
         channel_type: &str,
+        created: bool,
     ) {
+        let key = channel_key(parent, name);
+        let mut created_by_bot = created
+            || self
+                .channels
+                .get(&key)
+                .is_some_and(|c| c.discord_id == discord_id && c.created_by_bot);
+        for old in same_id_keys(&self.channels, discord_id, |c| &c.discord_id) {
+            if old != key {
+                created_by_bot |= self.channels.remove(&old).is_some_and(|c| c.created_by_bot);
+            }
+        }
         self.channels.insert(
-            name.to_string(),
+            key,
             ChannelStateEntry {
                 discord_id: discord_id.to_string(),
                 name: name.to_string(),
                 parent_category: parent.map(String::from),
                 channel_type: channel_type.to_string(),
-                created_by_bot: true,
+                created_by_bot,
                 permissions_applied: true,
                 last_verified: current_timestamp(),
             },
         );
     }
 
-    /// Record a created role
-    pub fn add_role(&mut self, name: &str, discord_id: &str, color: Option<&str>) {
+    /// Record a role, `created` if the bot just created it
+    /// Keeps the created-by-bot flag if the role was already tracked with the
+    /// same ID, and drops entries for the same ID under an old name
+    pub fn add_role(&mut self, name: &str, discord_id: &str, color: Option<&str>, created: bool) {
+        let mut created_by_bot = created
+            || self
+                .roles
+                .get(name)
+                .is_some_and(|r| r.discord_id == discord_id && r.created_by_bot);
+        for old in same_id_keys(&self.roles, discord_id, |r| &r.discord_id) {
+            if old != name {
+                created_by_bot |= self.roles.remove(&old).is_some_and(|r| r.created_by_bot);
+            }
+        }
         self.roles.insert(
             name.to_string(),
             RoleState {
                 discord_id: discord_id.to_string(),
                 name: name.to_string(),
                 color: color.map(String::from),
-                created_by_bot: true,
+                created_by_bot,
                 permissions_applied: true,
                 last_verified: current_timestamp(),
             },
         );
     }
 
+    /// Mark whatever is tracked under this Discord ID as not created by the bot
+    pub fn disown(&mut self, discord_id: &str) {
+        for category in self.categories.values_mut().filter(|c| c.discord_id == discord_id) {
+            category.created_by_bot = false;
+        }
+        for channel in self.channels.values_mut().filter(|c| c.discord_id == discord_id) {
+            channel.created_by_bot = false;
+        }
+        for role in self.roles.values_mut().filter(|r| r.discord_id == discord_id) {
+            role.created_by_bot = false;
+        }
+    }
+
     /// Get category ID by name
     pub fn get_category_id(&self, name: &str) -> Option<&str> {
         self.categories.get(name).map(|c| c.discord_id.as_str())
     }
 
-    /// Get channel ID by name
-    pub fn get_channel_id(&self, name: &str) -> Option<&str> {
-        self.channels.get(name).map(|c| c.discord_id.as_str())
+    /// Get channel ID by parent category ID and name
+    pub fn get_channel_id(&self, parent: Option<&str>, name: &str) -> Option<&str> {
+        self.channels
+            .get(&channel_key(parent, name))
+            .map(|c| c.discord_id.as_str())
     }
 
     /// Get role ID by name
@@ -202,6 +368,16 @@ impl GuildChannelState {
         self.roles.get(name).map(|r| r.discord_id.as_str())
     }
 
+    /// Stop tracking an entity, given by name or, for channels, by `channel_key`
+    /// Returns false if it was not tracked
+    pub fn forget(&mut self, kind: EntityType, name: &str) -> bool {
+        match kind {
+            EntityType::Category => self.categories.remove(name).is_some(),
+            EntityType::Channel => self.channels.remove(name).is_some(),
+            EntityType::Role => self.roles.remove(name).is_some(),
+        }
+    }
+
     /// Set maintainers channel ID
     pub fn set_maintainers_channel(&mut self, channel_id: &str) {
         self.maintainers_channel_id = Some(channel_id.to_string());
@@ -217,6 +393,20 @@ pub struct CategoryState {
     pub position: u16,
     pub created_by_bot: bool,
     pub last_verified: u64,
+    /// Set when the season owning this category is archived
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<u64>,
+}
+
+/// The categories synced together for one season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeasonLayoutState {
+    /// Category names in layout order
+    pub categories: Vec<String>,
+    pub last_synced: u64,
 }
 
 /// State for a channel
@@ -251,6 +441,34 @@ pub fn create_shared_channel_state(state: ChannelState) -> SharedChannelState {
     Arc::new(tokio::sync::RwLock::new(state))
 }
 
+/// Key a channel is tracked under: "{parent category ID}/{name}", or the bare
+/// name for channels outside any category
+pub fn channel_key(parent: Option<&str>, name: &str) -> String {
+    match parent {
+        Some(parent) => format!("{}/{}", parent, name),
+        None => name.to_string(),
+    }
+}
+
+/// Keys of the entries tracking the Discord entity `discord_id`
+fn same_id_keys<T>(
+    entries: &HashMap<String, T>,
+    discord_id: &str,
+    id_of: impl Fn(&T) -> &String,
+) -> Vec<String> {
+    entries
+        .iter()
+        .filter(|(_, entry)| id_of(entry) == discord_id)
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+/// Path of the channel state file inside STATE_PATH (default: state/)
+pub fn channel_state_path() -> String {
+    let state_path = std::env::var("STATE_PATH").unwrap_or_else(|_| "state".to_string());
+    format!("{}/channel_state.json", state_path)
+}
+
 fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -267,9 +485,9 @@ mod tests {
         let mut state = ChannelState::new();
 
         let guild = state.get_guild_mut("123", "Test Guild");
-        guild.add_role("Medlem", "456", Some("#00ff00"));
-        guild.add_category("2025E", "789", 0);
-        guild.add_channel("general", "101", Some("2025E"), "text");
+        guild.add_role("Medlem", "456", Some("#00ff00"), true);
+        guild.add_category("2025E", "789", 0, true);
+        guild.add_channel("general", "101", Some("2025E"), "text", true);
 
         assert!(state.get_guild("123").is_some());
         assert_eq!(
@@ -278,14 +496,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_migrate_clears_legacy_created_flags() {
+        let mut state = ChannelState::new();
+        state.get_guild_mut("123", "Test").add_role("Medlem", "456", None, true);
+        state.version = 1;
+
+        state.migrate();
+        assert_eq!(state.version, STATE_VERSION);
+        assert!(!state.get_guild("123").unwrap().roles["Medlem"].created_by_bot);
+
+        // Current state keeps its flags
+        state.get_guild_mut("123", "Test").add_role("Board", "789", None, true);
+        state.migrate();
+        assert!(state.get_guild("123").unwrap().roles["Board"].created_by_bot);
+    }
+
+    #[test]
+    fn test_channels_keyed_per_category() {
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("123", "Test");
+        guild.add_channel("general", "101", Some("789"), "Text", true);
+        guild.add_channel("general", "102", Some("790"), "Text", false);
+        guild.add_channel("bot-config", "103", None, "text", true);
+
+        assert_eq!(guild.get_channel_id(Some("789"), "general"), Some("101"));
+        assert_eq!(guild.get_channel_id(Some("790"), "general"), Some("102"));
+        assert_eq!(guild.get_channel_id(None, "general"), None);
+        assert_eq!(guild.get_channel_id(None, "bot-config"), Some("103"));
+        assert!(guild.channels["789/general"].created_by_bot);
+        assert!(!guild.channels["790/general"].created_by_bot);
+
+        assert!(guild.forget(EntityType::Channel, "790/general"));
+        assert_eq!(guild.get_channel_id(Some("789"), "general"), Some("101"));
+    }
+
+    #[test]
+    fn test_migrate_rekeys_channels() {
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("123", "Test");
+        guild.add_channel("general", "101", Some("789"), "Text", true);
+        guild.add_channel("bot-config", "103", None, "text", true);
+        // Version 2 keyed channels by name alone
+        guild.channels = std::mem::take(&mut guild.channels)
+            .into_values()
+            .map(|c| (c.name.clone(), c))
+            .collect();
+        state.version = 2;
+
+        state.migrate();
+        let guild = state.get_guild("123").unwrap();
+        assert_eq!(guild.get_channel_id(Some("789"), "general"), Some("101"));
+        assert_eq!(guild.get_channel_id(None, "bot-config"), Some("103"));
+        assert!(guild.channels["789/general"].created_by_bot);
+    }
+
+    #[test]
+    fn test_renamed_entities_keep_their_entry() {
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("123", "Test");
+        guild.add_role("Tutor", "456", None, true);
+        guild.add_category("2025E", "789", 0, true);
+        guild.mark_category_archived("2025E");
+        guild.set_season_categories("2025E", vec!["2025E".to_string()]);
+        guild.add_channel("general", "101", Some("789"), "Text", true);
+
+        guild.add_role("Mentor", "456", None, false);
+        guild.add_category("2025 Autumn", "789", 0, false);
+        guild.add_channel("lobby", "101", Some("789"), "Text", false);
+
+        assert_eq!(guild.get_role_id("Tutor"), None);
+        assert!(guild.roles["Mentor"].created_by_bot);
+        assert_eq!(guild.get_category_id("2025E"), None);
+        assert!(guild.is_category_archived("2025 Autumn"));
+        assert!(guild.categories["2025 Autumn"].created_by_bot);
+        assert_eq!(guild.season_categories("2025E"), ["2025 Autumn".to_string()]);
+        assert_eq!(guild.get_channel_id(Some("789"), "general"), None);
+        assert!(guild.channels["789/lobby"].created_by_bot);
+    }
+
     #[test]
     fn test_needs_sync() {
         let mut state = ChannelState::new();
         let guild = state.get_guild_mut("123", "Test");
-        guild.add_role("Medlem", "456", None);
+        guild.add_role("Medlem", "456", None, true);
 
         assert!(!state.needs_sync("123", EntityType::Role, "Medlem"));
         assert!(state.needs_sync("123", EntityType::Role, "Admin"));
         assert!(state.needs_sync("999", EntityType::Role, "Medlem"));
+
+        let guild = state.get_guild_mut("123", "Test");
+        assert!(guild.forget(EntityType::Role, "Medlem"));
+        assert!(!guild.forget(EntityType::Role, "Medlem"));
+        assert!(state.needs_sync("123", EntityType::Role, "Medlem"));
+    }
+
+    #[test]
+    fn test_archived_category_survives_re_add() {
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("123", "Test");
+
+        assert!(!guild.mark_category_archived("2025E"));
+        guild.add_category("2025E", "789", 0, true);
+        assert!(guild.mark_category_archived("2025E"));
+
+        // Re-syncing the category must not clear the archived flag
+        guild.add_category("2025E", "789", 5, false);
+        assert!(guild.is_category_archived("2025E"));
+        assert_eq!(guild.categories["2025E"].position, 5);
+        assert!(guild.categories["2025E"].created_by_bot);
+    }
+
+    #[test]
+    fn test_recreated_entity_is_not_inherited_as_bot_created() {
+        let mut state = ChannelState::new();
+        let guild = state.get_guild_mut("123", "Test");
+        guild.add_role("Board", "5", None, true);
+        guild.add_category("Board", "6", 0, true);
+        guild.add_channel("general", "7", Some("6"), "Text", true);
+
+        // Deleted and recreated by hand under the same name
+        guild.add_role("Board", "9", None, false);
+        guild.add_category("Board", "10", 0, false);
+        guild.add_channel("general", "11", Some("6"), "Text", false);
+
+        assert_eq!(guild.get_role_id("Board"), Some("9"));
+        assert!(!guild.roles["Board"].created_by_bot);
+        assert_eq!(guild.get_category_id("Board"), Some("10"));
+        assert!(!guild.categories["Board"].created_by_bot);
+        assert_eq!(guild.get_channel_id(Some("6"), "general"), Some("11"));
+        assert!(!guild.channels["6/general"].created_by_bot);
     }
 }
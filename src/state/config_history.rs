@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+use crate::error::{BotError, Result};
+
+/// A saved version of one config file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    /// Unique, sortable snapshot ID (`{millis}-{file slug}`)
+    pub id: String,
+    /// File relative to the data directory (e.g. "seasons/2025E/users.json")
+    pub file: String,
+    /// When the version was saved (unix seconds)
+    pub timestamp: u64,
+    /// Who made the change (Discord or admin panel username)
+    pub author: String,
+    /// How the change was made (e.g. "commit", "admin edit")
+    pub action: String,
+    /// Full file contents at this version
+    pub content: String,
+}
+
+/// Timestamped snapshots of every config file the bot writes
+///
+/// Each snapshot is stored as its own JSON file under the history directory,
+/// so listing is a directory scan and nothing is ever rewritten.
+#[derive(Debug, Clone)]
+pub struct ConfigHistory {
+    root: PathBuf,
+}
+
+impl ConfigHistory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Record a new version of a config file
+    ///
+    /// `previous` is what the file contained before the write. It is stored
+    /// first if the file has no history yet, so the very first tracked change
+    /// can still be rolled back. Returns `None` if the content is unchanged
+    /// since the latest snapshot.
+    pub async fn record(
+        &self,
+        file: &str,
+        previous: Option<&str>,
+        content: &str,
+        author: &str,
+        action: &str,
+    ) -> Result<Option<ConfigSnapshot>> {
+        let versions = self.list_for_file(file).await;
+
+        match versions.first() {
+            Some(latest) if latest.content == content => return Ok(None),
+            Some(_) => {}
+            None => {
+                if let Some(previous) = previous.filter(|p| *p != content) {
+                    self.write_snapshot(file, previous, "unknown", "before first tracked change")
+                        .await?;
+                }
+            }
+        }
+
+        self.write_snapshot(file, content, author, action)
+            .await
+            .map(Some)
+    }
+
+    async fn write_snapshot(
+        &self,
+        file: &str,
+        content: &str,
+        author: &str,
+        action: &str,
+    ) -> Result<ConfigSnapshot> {
+        tokio::fs::create_dir_all(&self.root)
+            .await
+            .map_err(|e| BotError::StateSave {
+                path: self.root.display().to_string(),
+                source: e,
+            })?;
+
+        let slug = file.trim_end_matches(".json").replace(['/', '\\'], "_");
+        let mut millis = current_millis();
+        let mut id = format!("{}-{}", millis, slug);
+        while self.snapshot_path(&id).exists() {
+            millis += 1;
+            id = format!("{}-{}", millis, slug);
+        }
+
+        let snapshot = ConfigSnapshot {
+            id,
+            file: file.to_string(),
+            timestamp: millis / 1000,
+            author: author.to_string(),
+            action: action.to_string(),
+            content: content.to_string(),
+        };
+
+        let path = self.snapshot_path(&snapshot.id);
+        let json = serde_json::to_string_pretty(&snapshot)?;
+        tokio::fs::write(&path, json)
+            .await
+            .map_err(|e| BotError::StateSave {
+                path: path.display().to_string(),
+                source: e,
+            })?;
+
+        Ok(snapshot)
+    }
+
+    fn snapshot_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{}.json", id))
+    }
+
+    /// All snapshots, newest first
+    pub async fn list(&self) -> Vec<ConfigSnapshot> {
+        let mut snapshots = Vec::new();
+
+        let Ok(mut entries) = tokio::fs::read_dir(&self.root).await else {
+            return snapshots;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let parsed = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    serde_json::from_str::<ConfigSnapshot>(&s).map_err(|e| e.to_string())
+                });
+            match parsed {
+                Ok(snapshot) => snapshots.push(snapshot),
+                Err(e) => warn!("Skipping unreadable config snapshot {}: {}", path.display(), e),
+            }
+        }
+
+        snapshots.sort_by(|a, b| b.id.cmp(&a.id));
+        snapshots
+    }
+
+    /// Snapshots of a single file, newest first
+    pub async fn list_for_file(&self, file: &str) -> Vec<ConfigSnapshot> {
+        self.list()
+            .await
+            .into_iter()
+            .filter(|s| s.file == file)
+            .collect()
+    }
+
+    /// Look up a snapshot by ID
+    pub async fn get(&self, id: &str) -> Option<ConfigSnapshot> {
+        // IDs come from URLs, so never let them escape the history directory
+        if id.is_empty() || !id.chars().all(|c| c.is_alphanumeric() || "-_".contains(c)) {
+            return None;
+        }
+        let content = tokio::fs::read_to_string(self.snapshot_path(id)).await.ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// The version of the same file saved just before `snapshot`
+    pub async fn previous_version(&self, snapshot: &ConfigSnapshot) -> Option<ConfigSnapshot> {
+        self.list_for_file(&snapshot.file)
+            .await
+            .into_iter()
+            .find(|s| s.id < snapshot.id)
+    }
+}
+
+/// Unified line diff between two versions of a file
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(old_label, new_label)
+        .to_string()
+}
+
+/// Get the config history directory path from STATE_PATH env var
+pub fn config_history_path() -> String {
+    let state_path = std::env::var("STATE_PATH").unwrap_or_else(|_| "state".to_string());
+    format!("{}/config_history", state_path)
+}
+
+fn current_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_keeps_baseline_and_skips_unchanged() {
+        let root = std::env::temp_dir().join(format!("eventy-history-{}", current_millis()));
+        let history = ConfigHistory::new(&root);
+        let file = "global/roles.json";
+
+        let first = history
+            .record(file, Some("{\"roles\": []}"), "{\"roles\": [1]}", "alice", "admin edit")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(history
+            .record(file, Some("{\"roles\": [1]}"), "{\"roles\": [1]}", "bob", "admin edit")
+            .await
+            .unwrap()
+            .is_none());
+
+        let versions = history.list_for_file(file).await;
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].id, first.id);
+        assert_eq!(versions[1].action, "before first tracked change");
+
+        let previous = history.previous_version(&first).await.unwrap();
+        assert_eq!(previous.content, "{\"roles\": []}");
+        assert!(history.get("../roles").await.is_none());
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -1,9 +1,12 @@
 pub mod channel_state;
+pub mod config_history;
 pub mod user_database;
 
 pub use channel_state::{
-    create_shared_channel_state, ChannelState, EntityType, SharedChannelState,
+    channel_state_path, create_shared_channel_state, ChannelState, EntityType, SharedChannelState,
 };
+pub use config_history::{config_history_path, unified_diff, ConfigHistory};
 pub use user_database::{
-    create_shared_user_database, SharedUserDatabase, TrackedUser, UserDatabase,
+    create_shared_user_database, user_database_path, SharedUserDatabase, TrackedUser, UserDatabase,
+    VerificationStatus,
 };
@@ -139,6 +139,12 @@ impl UserDatabase {
         self.users.get(discord_id)
     }
 
+    /// Find a user by their Discord ID for modification
+    pub fn find_by_discord_id_mut(&mut self, discord_id: &str) -> Option<&mut TrackedUser> {
+        self.last_updated = current_timestamp();
+        self.users.get_mut(discord_id)
+    }
+
     /// Find a user by their verification ID (UUID)
     pub fn find_by_verification_id(&self, verification_id: &str) -> Option<&TrackedUser> {
         self.users.values().find(|u| {
@@ -175,6 +181,25 @@ impl UserDatabase {
             .collect()
     }
 
+    /// Mark verified users expired once none of their seasons is active
+    ///
+    /// Returns the users that expired.
+    pub fn expire_lapsed(&mut self, is_active: impl Fn(&str) -> bool) -> Vec<TrackedUser> {
+        let mut expired = Vec::new();
+        for user in self.users.values_mut() {
+            if user.verification_status == VerificationStatus::Verified
+                && !user.verification_ids.keys().any(|season_id| is_active(season_id))
+            {
+                user.expire();
+                expired.push(user.clone());
+            }
+        }
+        if !expired.is_empty() {
+            self.last_updated = current_timestamp();
+        }
+        expired
+    }
+
     /// Get user count
     pub fn user_count(&self) -> usize {
         self.users.len()
@@ -267,6 +292,45 @@ impl TrackedUser {
         self.verification_ids
             .insert(season_id.to_string(), verification_id.to_string());
     }
+
+    /// Append a timestamped line to the notes
+    pub fn add_note(&mut self, note: &str) {
+        let line = format!(
+            "[{}] {}",
+            chrono::Utc::now().format("%Y-%m-%d %H:%M UTC"),
+            note
+        );
+        self.notes = Some(match self.notes.take() {
+            Some(existing) if !existing.is_empty() => format!("{}\n{}", existing, line),
+            _ => line,
+        });
+    }
+
+    /// Check if the user's verification has been revoked
+    pub fn is_revoked(&self) -> bool {
+        self.verification_status == VerificationStatus::Revoked
+    }
+
+    /// Revoke the user's verification and record why
+    pub fn revoke(&mut self, reason: &str, revoked_by: &str) {
+        self.verification_status = VerificationStatus::Revoked;
+        self.add_note(&format!("Revoked by {}: {}", revoked_by, reason));
+    }
+
+    /// Mark the user's verification expired because their seasons ended
+    pub fn expire(&mut self) {
+        self.verification_status = VerificationStatus::Expired;
+        self.add_note("Expired: none of their seasons is active");
+    }
+
+    /// Restore a revoked or expired user to verified
+    pub fn reinstate(&mut self, reason: Option<&str>, reinstated_by: &str) {
+        self.verification_status = VerificationStatus::Verified;
+        match reason {
+            Some(reason) => self.add_note(&format!("Reinstated by {}: {}", reinstated_by, reason)),
+            None => self.add_note(&format!("Reinstated by {}", reinstated_by)),
+        }
+    }
 }
 
 /// Verification status for a user
@@ -286,6 +350,12 @@ pub fn create_shared_user_database(db: UserDatabase) -> SharedUserDatabase {
     Arc::new(tokio::sync::RwLock::new(db))
 }
 
+/// Path of the user database file inside STATE_PATH (default: state/)
+pub fn user_database_path() -> String {
+    let state_path = std::env::var("STATE_PATH").unwrap_or_else(|_| "state".to_string());
+    format!("{}/user_database.json", state_path)
+}
+
 fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -334,4 +404,55 @@ mod tests {
         assert!(db.find_by_discord_id("123").is_some());
         assert!(db.find_by_verification_id("uuid-1").is_some());
     }
+
+    #[test]
+    fn test_revoke_and_reinstate() {
+        let mut db = UserDatabase::new();
+        db.upsert_user(TrackedUser::new(
+            "123".to_string(),
+            "uuid-1".to_string(),
+            "2025E".to_string(),
+            "Test".to_string(),
+            vec![],
+        ));
+
+        db.find_by_discord_id_mut("123").unwrap().revoke("left the choir", "admin");
+        assert!(!db.is_verified("123"));
+        let user = db.find_by_discord_id("123").unwrap();
+        assert!(user.is_revoked());
+        assert!(user.notes.as_deref().unwrap().contains("Revoked by admin: left the choir"));
+
+        db.find_by_discord_id_mut("123").unwrap().reinstate(None, "admin");
+        assert!(db.is_verified("123"));
+        let notes = db.find_by_discord_id("123").unwrap().notes.clone().unwrap();
+        assert_eq!(notes.lines().count(), 2);
+        assert!(notes.lines().last().unwrap().ends_with("Reinstated by admin"));
+    }
+
+    #[test]
+    fn test_expire_lapsed() {
+        let mut db = UserDatabase::new();
+        for (id, season) in [("1", "2025E"), ("2", "2025F")] {
+            db.upsert_user(TrackedUser::new(
+                id.to_string(),
+                format!("uuid-{}", id),
+                season.to_string(),
+                "Test".to_string(),
+                vec![],
+            ));
+        }
+        db.find_by_discord_id_mut("2").unwrap().add_verification_id("2025E", "uuid-3");
+
+        let expired = db.expire_lapsed(|season_id| season_id == "2025F");
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].discord_id, "1");
+        let user = db.find_by_discord_id("1").unwrap();
+        assert_eq!(user.verification_status, VerificationStatus::Expired);
+        assert!(db.is_verified("2"));
+
+        // Already expired users are not reported again
+        let expired = db.expire_lapsed(|_| false);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].discord_id, "2");
+    }
 }
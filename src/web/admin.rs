@@ -30,8 +30,17 @@ use super::auth::{
     SharedSessionStore,
 };
 use super::oauth::OAuthState;
+use crate::config::category_structure::MergedStructure;
+use crate::config::RoleDefinition;
 use crate::logging::SharedLogBuffer;
-use crate::managers::{SharedConfigManager, SharedChannelManager, SharedRoleManager};
+use crate::managers::drift_detector::DriftReport;
+use crate::managers::member_reconciler::MemberRoleReport;
+use crate::managers::{
+    reconcile_members, run_drift_check, DriftScope, GuildSnapshot, RoleSyncOutcome,
+    SharedChannelManager, SharedConfigManager, SharedDriftDetector, SharedMaintainersManager,
+    SharedRoleManager, SharedVerificationManager, SyncPlan,
+};
+use crate::state::{unified_diff, VerificationStatus};
 
 /// Extended app state for admin panel
 #[derive(Clone)]
@@ -41,8 +50,11 @@ pub struct AdminState {
     pub channel_manager: SharedChannelManager,
     pub role_manager: SharedRoleManager,
     pub user_database: crate::state::SharedUserDatabase,
+    pub verification_manager: SharedVerificationManager,
     pub session_store: SharedSessionStore,
     pub log_buffer: SharedLogBuffer,
+    pub drift_detector: SharedDriftDetector,
+    pub maintainers_manager: SharedMaintainersManager,
     pub serenity_http: Arc<serenity::Http>,
     pub guild_id: GuildId,
 }
@@ -56,15 +68,34 @@ pub fn admin_router(state: AdminState) -> Router {
         .route("/callback", get(oauth_callback))
         .route("/seasons", get(seasons_list))
         .route("/season/:id", get(season_detail))
+        .route("/season/:id/import-csv", axum::routing::post(import_season_csv))
         .route("/edit/global", get(edit_global).post(save_global))
         .route("/edit/season/:id/:file", get(edit_season_file).post(save_season_file))
         .route("/new-season", get(new_season_form).post(create_season))
         .route("/logs", get(logs_page))
         .route("/logs/stream", get(logs_stream))
+        .route("/history", get(history_list))
+        .route("/history/:id", get(history_detail))
+        .route("/history/:id/restore", axum::routing::post(restore_history))
+        .route("/staged", get(staged_config))
+        .route("/staged/commit", axum::routing::post(commit_staged_config))
+        .route("/staged/discard", axum::routing::post(discard_staged_config))
         .route("/restart", axum::routing::post(restart_bot))
+        .route("/plan/roles", get(plan_roles))
+        .route("/plan/season/:id", get(plan_season))
         .route("/sync/roles", axum::routing::post(sync_roles))
         .route("/sync/assignments", axum::routing::post(sync_assignments))
+        .route("/plan/members", get(plan_members))
+        .route("/sync/members", axum::routing::post(sync_members))
+        .route("/members/report.csv", get(member_report_csv))
         .route("/sync/season/:id", axum::routing::post(sync_season))
+        .route("/plan/prune", get(plan_prune))
+        .route("/sync/prune", axum::routing::post(sync_prune))
+        .route("/archive/season/:id", axum::routing::post(archive_season))
+        .route("/drift/check", axum::routing::post(check_drift))
+        .route("/drift/reconcile", axum::routing::post(reconcile_drift))
+        .route("/user/:discord_id/revoke", axum::routing::post(revoke_user))
+        .route("/user/:discord_id/unrevoke", axum::routing::post(unrevoke_user))
         .with_state(state)
 }
 
@@ -122,31 +153,18 @@ async fn oauth_callback(
     // Exchange code for token
     let redirect_uri = format!("{}/admin/callback", state.oauth.base_url);
 
-    let token_response = match state
+    let token = match state
         .oauth
-        .http_client
-        .post("https://discord.com/api/oauth2/token")
-        .form(&[
-            ("client_id", state.oauth.client_id.as_str()),
-            ("client_secret", state.oauth.client_secret.as_str()),
-            ("grant_type", "authorization_code"),
-            ("code", &params.code),
-            ("redirect_uri", &redirect_uri),
-        ])
-        .send()
+        .discord
+        .exchange_code(
+            &state.oauth.client_id,
+            &state.oauth.client_secret,
+            &params.code,
+            &redirect_uri,
+        )
         .await
     {
-        Ok(resp) => match resp.json::<serde_json::Value>().await {
-            Ok(json) => json,
-            Err(e) => {
-                error!("Failed to parse token response: {}", e);
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Html("Authentication failed".to_string()),
-                )
-                    .into_response();
-            }
-        },
+        Ok(token) => token,
         Err(e) => {
             error!("Failed to exchange OAuth code: {}", e);
             return (
@@ -157,38 +175,9 @@ async fn oauth_callback(
         }
     };
 
-    let access_token = match token_response.get("access_token").and_then(|v| v.as_str()) {
-        Some(t) => t,
-        None => {
-            error!("No access token in response: {:?}", token_response);
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Html("Authentication failed".to_string()),
-            )
-                .into_response();
-        }
-    };
-
     // Get user info
-    let user_response = match state
-        .oauth
-        .http_client
-        .get("https://discord.com/api/users/@me")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await
-    {
-        Ok(resp) => match resp.json::<serde_json::Value>().await {
-            Ok(json) => json,
-            Err(e) => {
-                error!("Failed to parse user response: {}", e);
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Html("Authentication failed".to_string()),
-                )
-                    .into_response();
-            }
-        },
+    let user = match state.oauth.discord.get_current_user(&token).await {
+        Ok(user) => user,
         Err(e) => {
             error!("Failed to get user info: {}", e);
             return (
@@ -199,24 +188,10 @@ async fn oauth_callback(
         }
     };
 
-    let discord_id = match user_response.get("id").and_then(|v| v.as_str()) {
-        Some(id) => id.to_string(),
-        None => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Html("Failed to get user ID".to_string()),
-            )
-                .into_response();
-        }
-    };
-
-    let username = user_response
-        .get("username")
-        .and_then(|v| v.as_str())
-        .unwrap_or("Unknown")
-        .to_string();
+    let discord_id = user.id;
+    let username = user.username;
 
-    let avatar_url = user_response.get("avatar").and_then(|v| v.as_str()).map(|hash| {
+    let avatar_url = user.avatar.as_deref().map(|hash| {
         format!(
             "https://cdn.discordapp.com/avatars/{}/{}.png",
             discord_id, hash
@@ -287,6 +262,7 @@ async fn dashboard(
     // Get global config status
     let roles_count = config.get_global_roles().map(|r| r.roles.len()).unwrap_or(0);
     let has_permissions = config.get_global_permissions().is_some();
+    let drift = drift_section(state.drift_detector.read().await.latest());
 
     let html = format!(
         r#"<!DOCTYPE html>
@@ -391,6 +367,8 @@ async fn dashboard(
             <a href="/admin">Dashboard</a>
             <a href="/admin/new-season">New Season</a>
             <a href="/admin/logs">Logs</a>
+            <a href="/admin/history">History</a>
+            <a href="/admin/plan/prune">Prune</a>
             <form method="POST" action="/admin/restart" style="display:inline;" onsubmit="return confirm('Are you sure you want to restart the bot?');">
                 <button type="submit" style="background:#e74c3c;color:#fff;padding:0.5rem 1rem;border-radius:8px;border:none;cursor:pointer;">Restart Bot</button>
             </form>
@@ -415,6 +393,7 @@ async fn dashboard(
                 </div>
             </div>
         </div>
+        {}
         <h2 style="margin-bottom: 1rem;">Seasons</h2>
         <table>
             <thead>
@@ -436,6 +415,7 @@ async fn dashboard(
         config.get_seasons().len(),
         roles_count,
         if has_permissions { "Loaded" } else { "Not loaded" },
+        drift,
         seasons.join("\n")
     );
 
@@ -484,6 +464,18 @@ async fn season_detail(
         }
     };
 
+    let category_names = config
+        .get_season_category_names(&season_id)
+        .unwrap_or_default();
+    let archived = state
+        .channel_manager
+        .read()
+        .await
+        .are_categories_archived(state.guild_id, &category_names)
+        .await;
+
+    let csv_columns = config.csv_columns_for(&season_id);
+
     // Get user database to check verification status
     let user_db = state.user_database.read().await;
 
@@ -494,26 +486,48 @@ async fn season_detail(
         .map(|(i, user)| {
             // Check if user is actually verified in the database
             let verified_user = user_db.find_by_verification_id(&user.id);
-            let is_verified = verified_user
-                .map(|u| u.verification_status == crate::state::user_database::VerificationStatus::Verified)
-                .unwrap_or(false);
 
-            let (status_text, status_color) = if is_verified {
-                ("Verified", "#2ecc71")
-            } else {
-                ("Not Verified", "#95a5a6")
+            let (status_text, status_color) = match verified_user.map(|u| &u.verification_status) {
+                Some(VerificationStatus::Verified) => ("Verified", "#2ecc71"),
+                Some(VerificationStatus::Revoked) => ("Revoked", "#e74c3c"),
+                Some(VerificationStatus::Expired) => ("Expired", "#f39c12"),
+                _ => ("Not Verified", "#95a5a6"),
             };
 
             let status_badge = format!(
                 "<span style='background:{};color:#fff;padding:0.25rem 0.5rem;border-radius:4px;font-size:0.875rem;'>{}</span>",
                 status_color, status_text
             );
+
+            let actions = match verified_user {
+                Some(u) if u.verification_status == VerificationStatus::Verified => format!(
+                    "<form method=\"POST\" action=\"/admin/user/{}/revoke\" style=\"display:flex;gap:0.5rem;\" onsubmit=\"return confirm('Revoke this user and remove their roles?');\">\
+                        <input type=\"hidden\" name=\"season\" value=\"{}\">\
+                        <input type=\"text\" name=\"reason\" placeholder=\"Reason\" required style=\"padding:0.25rem 0.5rem;border-radius:4px;border:1px solid rgba(255,255,255,0.2);background:rgba(255,255,255,0.05);color:#fff;\">\
+                        <button type=\"submit\" style=\"background:#e74c3c;color:#fff;padding:0.25rem 0.75rem;border-radius:4px;border:none;cursor:pointer;\">Revoke</button>\
+                    </form>",
+                    u.discord_id, html_escape(&season_id)
+                ),
+                Some(u) => format!(
+                    "<form method=\"POST\" action=\"/admin/user/{}/unrevoke\" style=\"display:flex;gap:0.5rem;\" title=\"{}\">\
+                        <input type=\"hidden\" name=\"season\" value=\"{}\">\
+                        <input type=\"text\" name=\"reason\" placeholder=\"Reason (optional)\" style=\"padding:0.25rem 0.5rem;border-radius:4px;border:1px solid rgba(255,255,255,0.2);background:rgba(255,255,255,0.05);color:#fff;\">\
+                        <button type=\"submit\" style=\"background:#2ecc71;color:#fff;padding:0.25rem 0.75rem;border-radius:4px;border:none;cursor:pointer;\">Un-revoke</button>\
+                    </form>",
+                    u.discord_id,
+                    html_escape(u.notes.as_deref().unwrap_or("")),
+                    html_escape(&season_id)
+                ),
+                None => String::new(),
+            };
+
             format!(
-                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
                 i + 1,
                 user.name,
                 &user.id[..8.min(user.id.len())],
-                status_badge
+                status_badge,
+                actions
             )
         })
         .collect();
@@ -596,14 +610,27 @@ async fn season_detail(
             <p>Name: <strong>{}</strong></p>
             <p>Users: <strong>{}</strong></p>
             <p>Active: <strong>{}</strong></p>
+            <p>Archived: <strong>{}</strong></p>
         </div>
         <div style="margin-bottom: 1.5rem;">
             <a href="/admin/edit/season/{}/users" class="btn btn-primary" style="background:#5865F2;color:#fff;padding:0.5rem 1rem;border-radius:6px;text-decoration:none;margin-right:0.5rem;">Edit users.json</a>
             <a href="/admin/edit/season/{}/season" class="btn btn-secondary" style="background:rgba(255,255,255,0.1);color:#fff;padding:0.5rem 1rem;border-radius:6px;text-decoration:none;margin-right:0.5rem;">Edit season.json</a>
-            <form method="POST" action="/admin/sync/season/{}" style="display:inline;">
-                <button type="submit" style="background:#2ecc71;color:#fff;padding:0.5rem 1rem;border-radius:6px;border:none;cursor:pointer;">Sync Category to Discord</button>
+            <a href="/admin/plan/season/{}" style="background:#2ecc71;color:#fff;padding:0.5rem 1rem;border-radius:6px;text-decoration:none;">Plan Sync to Discord</a>
+            <form method="POST" action="/admin/archive/season/{}" style="display:inline-flex;gap:0.75rem;align-items:center;margin-left:0.5rem;" onsubmit="return confirm('Archive this season? Its category becomes read-only for the member role.');">
+                <label><input type="checkbox" name="move_to_bottom"> Move to bottom</label>
+                <label><input type="checkbox" name="strip_member_role"> Remove member role from everyone</label>
+                <button type="submit" style="background:#e67e22;color:#fff;padding:0.5rem 1rem;border-radius:6px;border:none;cursor:pointer;">Archive Season</button>
             </form>
         </div>
+        <details style="margin-bottom: 1.5rem;">
+            <summary style="cursor:pointer;color:#a0a0a0;">Import users from CSV</summary>
+            <form method="POST" action="/admin/season/{}/import-csv" style="margin-top:0.75rem;">
+                <input type="file" accept=".csv,text/csv" onchange="loadCsv(this)" style="margin-bottom:0.5rem;">
+                <textarea name="csv" id="csv" rows="8" placeholder="Or paste the CSV here" style="width:100%;background:#0d0d1a;color:#fff;border:1px solid rgba(255,255,255,0.2);border-radius:8px;padding:0.75rem;font-family:monospace;"></textarea>
+                <p style="color:#888;font-size:0.85rem;margin:0.5rem 0;">Columns: <code>{}</code> (name), <code>{}</code> (ID) and optionally <code>{}</code> (email). Set <code>csv_columns</code> in season.json to change them. The import replaces users.json and is staged for review first.</p>
+                <button type="submit" style="background:#5865F2;color:#fff;padding:0.5rem 1rem;border-radius:6px;border:none;cursor:pointer;">Stage Import</button>
+            </form>
+        </details>
         <h3 style="margin-bottom: 1rem;">Users</h3>
         <div class="search">
             <input type="text" id="search" placeholder="Search users..." onkeyup="filterTable()">
@@ -615,6 +642,7 @@ async fn season_detail(
                     <th>Name</th>
                     <th>ID (partial)</th>
                     <th>Status</th>
+                    <th>Actions</th>
                 </tr>
             </thead>
             <tbody>
@@ -631,6 +659,13 @@ async fn season_detail(
                 row.style.display = text.includes(search) ? '' : 'none';
             }});
         }}
+
+        function loadCsv(input) {{
+            if (!input.files.length) return;
+            input.files[0].text().then(text => {{
+                document.getElementById('csv').value = text;
+            }});
+        }}
     </script>
 </body>
 </html>"#,
@@ -639,9 +674,15 @@ async fn season_detail(
         if season.name().is_empty() { &season_id } else { season.name() },
         season.user_count(),
         season.is_active(),
+        archived,
         season_id, // edit users link
         season_id, // edit season link
         season_id, // sync category form
+        season_id, // archive season form
+        season_id, // import csv form
+        html_escape(&csv_columns.name),
+        html_escape(&csv_columns.id),
+        html_escape(&csv_columns.email),
         users_html.join("\n")
     );
 
@@ -919,6 +960,12 @@ struct JsonEditorForm {
 struct NewSeasonForm {
     season_id: String,
     name: String,
+    /// Season ID to clone from, or "template"
+    #[serde(default)]
+    source: String,
+    /// Optional users.json content for the new season
+    #[serde(default)]
+    users: String,
 }
 
 /// Common CSS for editor pages
@@ -1014,7 +1061,7 @@ fn editor_css() -> &'static str {
             margin-bottom: 0.5rem;
             color: #a0a0a0;
         }
-        .form-group input {
+        .form-group input, .form-group select {
             width: 100%;
             max-width: 400px;
             padding: 0.75rem 1rem;
@@ -1141,10 +1188,8 @@ async fn edit_global(
             </form>
             <div style="margin-top:1.5rem;padding-top:1.5rem;border-top:1px solid rgba(255,255,255,0.1);">
                 <h3 style="margin-bottom:0.75rem;font-size:1rem;color:#a0a0a0;">Sync to Discord</h3>
-                <p style="margin-bottom:1rem;font-size:0.9rem;color:#808080;">Create or update roles in Discord to match this configuration.</p>
-                <form method="POST" action="/admin/sync/roles" style="display:inline;">
-                    <button type="submit" class="btn" style="background:#2ecc71;color:#fff;">Sync Roles to Discord</button>
-                </form>
+                <p style="margin-bottom:1rem;font-size:0.9rem;color:#808080;">Create or update roles in Discord to match this configuration. You'll see the planned changes before anything is applied.</p>
+                <a href="/admin/plan/roles" class="btn" style="background:#2ecc71;color:#fff;text-decoration:none;">Plan Role Sync</a>
             </div>
         </div>
 
@@ -1162,11 +1207,14 @@ async fn edit_global(
                 <form method="POST" action="/admin/sync/assignments" style="display:inline;">
                     <button type="submit" class="btn" style="background:#2ecc71;color:#fff;">Sync Assignments to Discord</button>
                 </form>
+                <p style="margin:1rem 0;font-size:0.9rem;color:#808080;">Reconcile every member's season member roles and special roles with the user database. Revoked users lose them all.</p>
+                <a href="/admin/plan/members" class="btn" style="background:#2ecc71;color:#fff;text-decoration:none;">Plan Member Roles</a>
+                <a href="/admin/members/report.csv" class="btn" style="text-decoration:none;">Download Report (CSV)</a>
             </div>
         </div>
 
         <div id="permissions-tab" class="tab-content {permissions_active}">
-            <p class="file-hint">File: global/permissions.json - Defines permission levels (read, readwrite, admin)</p>
+            <p class="file-hint">File: global/permissions.json - Defines permission levels (read, readwrite, admin, or any custom name)</p>
             <form method="POST" action="/admin/edit/global?file=permissions">
                 <div class="editor-container">
                     <textarea name="content" class="editor">{permissions_content}</textarea>
@@ -1225,7 +1273,7 @@ async fn save_global(
     Query(params): Query<SaveGlobalParams>,
     Form(form): Form<JsonEditorForm>,
 ) -> impl IntoResponse {
-    let _session = match require_auth(&headers, &state).await {
+    let session = match require_auth(&headers, &state).await {
         Ok(s) => s,
         Err(redirect) => return redirect,
     };
@@ -1238,49 +1286,27 @@ async fn save_global(
         return Redirect::to(&format!("/admin/edit/global?tab={}&msg={}", file_type, urlencoding::encode(&err_msg))).into_response();
     }
 
-    // Save to file
-    let config = state.config_manager.read().await;
-    let data_path = config.get_data_path().to_string();
-    drop(config);
-
     let file_path = match file_type {
-        "roles" => format!("{}/global/roles.json", data_path),
-        "assignments" => format!("{}/global/assignments.json", data_path),
-        "permissions" => format!("{}/global/permissions.json", data_path),
+        "roles" => "global/roles.json",
+        "assignments" => "global/assignments.json",
+        "permissions" => "global/permissions.json",
         _ => {
             let err_msg = "error:Unknown file type";
             return Redirect::to(&format!("/admin/edit/global?msg={}", urlencoding::encode(err_msg))).into_response();
         }
     };
 
-    // Ensure global directory exists
-    let global_dir = format!("{}/global", data_path);
-    if let Err(e) = tokio::fs::create_dir_all(&global_dir).await {
-        warn!("Failed to create global directory: {}", e);
-    }
-
-    // Save file with explicit sync to ensure data is flushed to disk
-    match tokio::fs::File::create(&file_path).await {
-        Ok(file) => {
-            use tokio::io::AsyncWriteExt;
-            let mut file = file;
-            if let Err(e) = file.write_all(form.content.as_bytes()).await {
-                let err_msg = format!("error:Failed to write: {}", e);
-                return Redirect::to(&format!("/admin/edit/global?tab={}&msg={}", file_type, urlencoding::encode(&err_msg))).into_response();
-            }
-            // Sync to disk to prevent race condition with reload
-            if let Err(e) = file.sync_all().await {
-                warn!("Failed to sync file to disk: {}", e);
-            }
-        }
-        Err(e) => {
-            let err_msg = format!("error:Failed to save: {}", e);
-            return Redirect::to(&format!("/admin/edit/global?tab={}&msg={}", file_type, urlencoding::encode(&err_msg))).into_response();
-        }
+    // Save to file and record the version in the config history
+    let mut config = state.config_manager.write().await;
+    if let Err(e) = config
+        .write_config_file(file_path, &form.content, &session.username, "admin edit")
+        .await
+    {
+        let err_msg = format!("error:Failed to save: {}", e);
+        return Redirect::to(&format!("/admin/edit/global?tab={}&msg={}", file_type, urlencoding::encode(&err_msg))).into_response();
     }
 
     // Reload config
-    let mut config = state.config_manager.write().await;
     if let Err(e) = config.load_all().await {
         warn!("Failed to reload config after save: {}", e);
     }
@@ -1408,7 +1434,7 @@ async fn save_season_file(
     State(state): State<AdminState>,
     Form(form): Form<JsonEditorForm>,
 ) -> impl IntoResponse {
-    let _session = match require_auth(&headers, &state).await {
+    let session = match require_auth(&headers, &state).await {
         Ok(s) => s,
         Err(redirect) => return redirect,
     };
@@ -1421,10 +1447,6 @@ async fn save_season_file(
         return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode(&err_msg))).into_response();
     }
 
-    let config = state.config_manager.read().await;
-    let data_path = config.get_data_path().to_string();
-    drop(config);
-
     // Determine file path
     let file_type = match get_season_file_type(&params.file) {
         Some(ft) => ft,
@@ -1433,37 +1455,23 @@ async fn save_season_file(
         }
     };
 
-    let dir_path = format!("{}/seasons/{}", data_path, params.id);
-    let file_path = format!("{}/{}", dir_path, file_type.file_name);
-
-    // Ensure directory exists
-    if let Err(e) = tokio::fs::create_dir_all(&dir_path).await {
-        let err_msg = format!("error:Failed to create directory: {}", e);
-        return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode(&err_msg))).into_response();
+    if !params.id.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode("error:Invalid season ID"))).into_response();
     }
 
-    // Save file with explicit sync to ensure data is flushed to disk
-    match tokio::fs::File::create(&file_path).await {
-        Ok(file) => {
-            use tokio::io::AsyncWriteExt;
-            let mut file = file;
-            if let Err(e) = file.write_all(form.content.as_bytes()).await {
-                let err_msg = format!("error:Failed to write: {}", e);
-                return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode(&err_msg))).into_response();
-            }
-            // Sync to disk to prevent race condition with reload
-            if let Err(e) = file.sync_all().await {
-                warn!("Failed to sync file to disk: {}", e);
-            }
-        }
-        Err(e) => {
-            let err_msg = format!("error:Failed to save: {}", e);
-            return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode(&err_msg))).into_response();
-        }
+    let file_path = format!("seasons/{}/{}", params.id, file_type.file_name);
+
+    // Save to file and record the version in the config history
+    let mut config = state.config_manager.write().await;
+    if let Err(e) = config
+        .write_config_file(&file_path, &form.content, &session.username, "admin edit")
+        .await
+    {
+        let err_msg = format!("error:Failed to save: {}", e);
+        return Redirect::to(&format!("{}?msg={}", redirect_url, urlencoding::encode(&err_msg))).into_response();
     }
 
     // Reload config
-    let mut config = state.config_manager.write().await;
     if let Err(e) = config.load_all().await {
         warn!("Failed to reload config after save: {}", e);
     }
@@ -1494,6 +1502,18 @@ async fn new_season_form(
         format!(r#"<div class="message {}">{}</div>"#, class, html_escape(text))
     }).unwrap_or_default();
 
+    // Offer every existing season as a source, newest first
+    let source_options = {
+        let config = state.config_manager.read().await;
+        let mut season_ids: Vec<&String> = config.get_seasons().keys().collect();
+        season_ids.sort_by(|a, b| b.cmp(a));
+        season_ids
+            .iter()
+            .map(|id| format!(r#"<option value="{0}">{0}</option>"#, html_escape(id)))
+            .collect::<Vec<_>>()
+            .join("")
+    };
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -1522,7 +1542,20 @@ async fn new_season_form(
                 <div class="form-group">
                     <label for="name">Display Name</label>
                     <input type="text" name="name" id="name" placeholder="e.g., Fall 2025 Season">
-                    <p class="hint">Optional human-readable name.</p>
+                    <p class="hint">Optional. Defaults to the source season's name with the new ID.</p>
+                </div>
+                <div class="form-group">
+                    <label for="source">Clone From</label>
+                    <select name="source" id="source">
+                        {}
+                        <option value="template">template</option>
+                    </select>
+                    <p class="hint">Channels are carried over; the member role and category name are rewritten for the new season ID.</p>
+                </div>
+                <div class="form-group">
+                    <label for="users">users.json (optional)</label>
+                    <textarea name="users" id="users" style="min-height:150px;" placeholder='[{{"Name": "John Doe", "DiscordId": "unique-user-id-123"}}]'></textarea>
+                    <p class="hint">Users from the source season who reappear here are listed after creation.</p>
                 </div>
             </div>
             <button type="submit" class="btn btn-primary">Create Season</button>
@@ -1532,19 +1565,20 @@ async fn new_season_form(
 </body>
 </html>"#,
         editor_css(),
-        message
+        message,
+        source_options
     );
 
     Html(html).into_response()
 }
 
-/// POST /admin/new-season - Create a new season by copying from template
+/// POST /admin/new-season - Create a new season by cloning a previous season or the template
 async fn create_season(
     headers: HeaderMap,
     State(state): State<AdminState>,
     Form(form): Form<NewSeasonForm>,
 ) -> impl IntoResponse {
-    let _session = match require_auth(&headers, &state).await {
+    let session = match require_auth(&headers, &state).await {
         Ok(s) => s,
         Err(redirect) => return redirect,
     };
@@ -1567,99 +1601,90 @@ async fn create_season(
         return Redirect::to(&format!("/admin/new-season?msg={}", msg)).into_response();
     }
 
-    let config = state.config_manager.read().await;
-    let data_path = config.get_data_path().to_string();
-
-    // Check if season already exists
-    if config.get_seasons().contains_key(season_id) {
-        return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode("error:Season already exists"))).into_response();
-    }
-    drop(config);
-
-    let template_dir = format!("{}/seasons/template", data_path);
-    let season_dir = format!("{}/seasons/{}", data_path, season_id);
-
-    // Create season directory
-    if let Err(e) = tokio::fs::create_dir_all(&season_dir).await {
-        let err_msg = format!("error:Failed to create directory: {}", e);
-        return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode(&err_msg))).into_response();
-    }
-
-    // Copy and modify template files
-    let files_to_copy = ["users.json", "category.json", "roles.json"];
-    for filename in files_to_copy {
-        let template_path = format!("{}/{}", template_dir, filename);
-        let dest_path = format!("{}/{}", season_dir, filename);
-
-        // Read template file
-        let content = match tokio::fs::read_to_string(&template_path).await {
-            Ok(c) => c,
+    let users = if form.users.trim().is_empty() {
+        vec![]
+    } else {
+        match serde_json::from_str::<Vec<crate::config::SeasonUser>>(&form.users) {
+            Ok(users) => users,
             Err(e) => {
-                warn!("Template file {} not found, skipping: {}", filename, e);
-                continue;
+                let err_msg = format!("error:Invalid users.json: {}", e);
+                return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode(&err_msg))).into_response();
             }
-        };
+        }
+    };
 
-        // Parse and modify JSON to replace TEMPLATE with actual season ID
-        let modified_content = match serde_json::from_str::<serde_json::Value>(&content) {
-            Ok(mut json) => {
-                // Replace season_id field if present
-                if let Some(obj) = json.as_object_mut() {
-                    if obj.contains_key("season_id") {
-                        obj.insert("season_id".to_string(), serde_json::json!(season_id));
-                    }
-                    // For users.json: update name and set active to true, clear users
-                    if filename == "users.json" {
-                        obj.insert("name".to_string(), serde_json::json!(form.name.trim()));
-                        obj.insert("active".to_string(), serde_json::json!(true));
-                        obj.insert("users".to_string(), serde_json::json!([]));
-                    }
-                    // For category.json: update category_name
-                    if filename == "category.json" {
-                        if let Some(name) = form.name.trim().chars().next() {
-                            // Only set if name is non-empty
-                            if !form.name.trim().is_empty() {
-                                obj.insert("category_name".to_string(), serde_json::json!(form.name.trim()));
-                            } else {
-                                obj.insert("category_name".to_string(), serde_json::json!(season_id));
-                            }
-                            let _ = name; // silence warning
-                        }
-                    }
-                }
-                serde_json::to_string_pretty(&json).unwrap_or(content)
-            }
-            Err(_) => content, // If JSON parse fails, copy as-is
-        };
+    let source = if form.source.trim().is_empty() { "template" } else { form.source.trim() };
 
-        // Write modified content
-        match tokio::fs::File::create(&dest_path).await {
-            Ok(file) => {
-                use tokio::io::AsyncWriteExt;
-                let mut file = file;
-                if let Err(e) = file.write_all(modified_content.as_bytes()).await {
-                    let err_msg = format!("error:Failed to write {}: {}", filename, e);
-                    return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode(&err_msg))).into_response();
-                }
-                if let Err(e) = file.sync_all().await {
-                    warn!("Failed to sync {} to disk: {}", filename, e);
-                }
-            }
-            Err(e) => {
-                let err_msg = format!("error:Failed to create {}: {}", filename, e);
-                return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode(&err_msg))).into_response();
-            }
+    let mut config = state.config_manager.write().await;
+    let report = match config
+        .rollover_season(
+            source,
+            season_id,
+            Some(form.name.as_str()),
+            users,
+            &session.username,
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            let err_msg = format!("error:{}", e);
+            return Redirect::to(&format!("/admin/new-season?msg={}", urlencoding::encode(&err_msg))).into_response();
         }
+    };
+    drop(config);
+
+    info!(
+        "Season {} created from '{}' by {} via admin panel",
+        season_id, source, session.username
+    );
+
+    let mut message = format!(
+        "<p><strong>Season:</strong> {} ({})</p>\
+         <p><strong>Cloned from:</strong> {}</p>\
+         <p><strong>Member role:</strong> {}</p>\
+         <p><strong>Channels carried over:</strong> {}</p>\
+         <p><strong>Users:</strong> {}</p>",
+        html_escape(&report.name),
+        html_escape(&report.season_id),
+        html_escape(&report.source),
+        html_escape(&report.member_role),
+        report.channels_copied,
+        report.user_count
+    );
+
+    if !report.files_copied.is_empty() {
+        message.push_str(&format!(
+            "<p><strong>Also copied:</strong> {}</p>",
+            html_escape(&report.files_copied.join(", "))
+        ));
     }
 
-    // Reload config
-    let mut config = state.config_manager.write().await;
-    if let Err(e) = config.load_all().await {
-        warn!("Failed to reload config after creating season: {}", e);
+    if report.source != "template" {
+        if report.returning_users.is_empty() {
+            message.push_str("<p>No users from the previous season reappear.</p>");
+        } else {
+            message.push_str(&format!(
+                "<p><strong>Returning from {} ({}):</strong></p><ul>{}</ul>",
+                html_escape(&report.source),
+                report.returning_users.len(),
+                report
+                    .returning_users
+                    .iter()
+                    .map(|u| format!("<li>{}</li>", html_escape(&u.name)))
+                    .collect::<Vec<_>>()
+                    .join("")
+            ));
+        }
     }
 
-    info!("Season {} created from template via admin panel", season_id);
-    Redirect::to(&format!("/admin/season/{}", season_id)).into_response()
+    Html(sync_result_page(
+        &format!("Create Season {}", season_id),
+        true,
+        &message,
+        &format!("/admin/season/{}", season_id),
+    ))
+    .into_response()
 }
 
 /// POST /admin/restart - Restart the bot
@@ -1810,150 +1835,610 @@ fn get_season_file_template(file_type: &str, season_id: &str) -> String {
     }
 }
 
-/// POST /admin/sync/roles - Sync roles to Discord
-async fn sync_roles(
+/// Render the latest drift report for the dashboard
+fn drift_section(report: Option<&DriftReport>) -> String {
+    let check_button = r#"<form method="POST" action="/admin/drift/check" style="display:inline;">
+                <button type="submit" style="background:rgba(255,255,255,0.1);color:#fff;padding:0.4rem 0.8rem;border-radius:6px;border:none;cursor:pointer;font-size:0.85rem;">Check now</button>
+            </form>"#;
+    let Some(report) = report else {
+        return format!(
+            r#"<h2 style="margin-bottom: 1rem;">Drift</h2>
+        <p style="margin-bottom: 2rem;color:#a0a0a0;">No drift check has run yet. {}</p>"#,
+            check_button
+        );
+    };
+
+    let checked_at = report.checked_at.format("%Y-%m-%d %H:%M UTC");
+    let mut rows = Vec::new();
+    for (group, items) in report.groups() {
+        for item in items {
+            let action = match &item.scope {
+                DriftScope::Season(id) => format!(
+                    "<a href=\"/admin/plan/season/{}\">Review plan</a>",
+                    urlencoding::encode(id)
+                ),
+                scope => format!(
+                    r#"<form method="POST" action="/admin/drift/reconcile" style="display:inline;">
+                        <input type="hidden" name="item" value="{}">
+                        <button type="submit" style="background:#5865F2;color:#fff;padding:0.3rem 0.7rem;border-radius:6px;border:none;cursor:pointer;">Reconcile</button>
+                    </form>"#,
+                    html_escape(&scope.key())
+                ),
+            };
+            let color = if item.line.starts_with('+') {
+                "#2ecc71"
+            } else if item.line.starts_with('-') {
+                "#e74c3c"
+            } else {
+                "#f39c12"
+            };
+            rows.push(format!(
+                "<tr><td>{}</td><td style=\"font-family:monospace;color:{};\">{}</td><td>{}</td></tr>",
+                html_escape(&group),
+                color,
+                html_escape(&item.line),
+                action
+            ));
+        }
+    }
+
+    let mut html = format!(
+        r#"<h2 style="margin-bottom: 1rem;">Drift</h2>
+        <p style="margin-bottom: 1rem;color:#a0a0a0;">Last checked {}: {} difference(s) between Discord and the config. {}</p>"#,
+        checked_at,
+        report.items.len(),
+        check_button
+    );
+    for warning in &report.warnings {
+        html.push_str(&format!(
+            "<p style=\"color:#f39c12;margin-bottom:0.5rem;\">{}</p>",
+            html_escape(warning)
+        ));
+    }
+    if !rows.is_empty() {
+        html.push_str(&format!(
+            r#"<table style="margin-bottom: 2rem;">
+            <thead><tr><th>Scope</th><th>Difference</th><th></th></tr></thead>
+            <tbody>{}</tbody>
+        </table>"#,
+            rows.join("\n")
+        ));
+    }
+    html
+}
+
+/// POST /admin/drift/check - Run a drift check now
+async fn check_drift(
     headers: HeaderMap,
     State(state): State<AdminState>,
 ) -> impl IntoResponse {
-    // Diagnostic logging - this should always appear
-    eprintln!("[SYNC_ROLES] Handler called - starting role sync");
-    info!("=== SYNC ROLES STARTED ===");
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
 
-    let _session = match require_auth(&headers, &state).await {
-        Ok(s) => {
-            info!("sync_roles: authenticated as {}", s.username);
-            s
-        }
-        Err(redirect) => {
-            warn!("sync_roles: authentication failed");
-            return redirect;
-        }
+    info!("Drift check requested by {} via admin panel", session.username);
+    if let Err(e) = run_drift_check(
+        &state.drift_detector,
+        &state.maintainers_manager,
+        state.serenity_http.as_ref(),
+        state.guild_id,
+    )
+    .await
+    {
+        error!("check_drift: drift check failed: {}", e);
+        return Html(sync_result_page(
+            "Drift Check",
+            false,
+            &format!("Drift check failed: {}", html_escape(&e.to_string())),
+            "/admin",
+        ))
+        .into_response();
+    }
+
+    Redirect::to("/admin").into_response()
+}
+
+/// Form data for reconciling one drifted item
+#[derive(Deserialize)]
+struct ReconcileDriftForm {
+    /// Item scope encoded by `DriftScope::key`
+    item: String,
+}
+
+/// POST /admin/drift/reconcile - Bring one drifted item back in line with the config
+async fn reconcile_drift(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Form(form): Form<ReconcileDriftForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
     };
 
-    info!("sync_roles: loading config...");
-    let config = state.config_manager.read().await;
-    let global_roles = match config.get_global_roles() {
-        Some(r) => {
-            info!("sync_roles: found {} roles in config", r.roles.len());
-            r.clone()
-        }
-        None => {
-            warn!("sync_roles: no global roles configured");
-            return Html(sync_result_page(
-                "Sync Roles",
-                false,
-                "No global roles configured. Add roles to `data/global/roles.json`.",
-                "/admin",
-            )).into_response();
-        }
+    let Some(scope) = DriftScope::from_key(&form.item) else {
+        return Html(sync_result_page(
+            "Reconcile Drift",
+            false,
+            &format!("Unknown drift item '{}'.", html_escape(&form.item)),
+            "/admin",
+        ))
+        .into_response();
     };
-    drop(config);
+    if let DriftScope::Season(id) = &scope {
+        return Redirect::to(&format!("/admin/plan/season/{}", urlencoding::encode(id))).into_response();
+    }
 
+    info!("{} reconciling drift item '{}' via admin panel", session.username, form.item);
     let http = state.serenity_http.as_ref();
-    let guild_id = state.guild_id;
-    info!("sync_roles: using guild_id {}", guild_id);
-
-    // Get existing roles
-    info!("sync_roles: fetching existing roles from Discord...");
-    let existing_roles = match guild_id.roles(http).await {
-        Ok(r) => {
-            info!("sync_roles: Discord has {} existing roles", r.len());
-            r
+    let result = state
+        .drift_detector
+        .read()
+        .await
+        .reconcile(http, state.guild_id, &scope)
+        .await;
+    match result {
+        Ok(message) => {
+            // Refresh the report so the dashboard no longer lists the item
+            if let Err(e) =
+                run_drift_check(&state.drift_detector, &state.maintainers_manager, http, state.guild_id).await
+            {
+                warn!("reconcile_drift: drift check after reconcile failed: {}", e);
+            }
+            Html(sync_result_page("Reconcile Drift", true, &html_escape(&message), "/admin")).into_response()
         }
         Err(e) => {
-            error!("sync_roles: failed to fetch roles from Discord: {}", e);
-            return Html(sync_result_page(
-                "Sync Roles",
+            error!("reconcile_drift: failed to reconcile '{}': {}", form.item, e);
+            Html(sync_result_page(
+                "Reconcile Drift",
                 false,
-                &format!("Failed to fetch roles from Discord: {}", e),
+                &format!("Failed to reconcile: {}", html_escape(&e.to_string())),
                 "/admin",
-            )).into_response();
+            ))
+            .into_response()
         }
-    };
+    }
+}
 
-    let mut created = Vec::new();
-    let mut updated = Vec::new();
-    let mut unchanged = Vec::new();
-    let mut errors = Vec::new();
+/// Form data for applying a reviewed sync plan
+#[derive(Deserialize)]
+struct ApplyPlanForm {
+    /// Fingerprint of the plan that was reviewed
+    #[serde(default)]
+    plan: Option<String>,
+}
 
-    for role_def in &global_roles.roles {
-        if let Some((role_id, existing_role)) = existing_roles.iter().find(|(_, r)| r.name == role_def.name) {
-            let target_color = role_def
-                .color
-                .as_ref()
-                .and_then(|c| {
-                    let hex = c.trim_start_matches('#');
-                    u32::from_str_radix(hex, 16).ok()
-                })
-                .unwrap_or(0);
-
-            let needs_update = existing_role.colour.0 != target_color
-                || existing_role.hoist != role_def.hoist
-                || existing_role.mentionable != role_def.mentionable;
-
-            if needs_update {
-                match guild_id
-                    .edit_role(
-                        http,
-                        *role_id,
-                        serenity::EditRole::new()
-                            .colour(target_color as u64)
-                            .hoist(role_def.hoist)
-                            .mentionable(role_def.mentionable),
-                    )
-                    .await
-                {
-                    Ok(_) => {
-                        info!("Updated role '{}' via admin panel", role_def.name);
-                        updated.push(role_def.name.clone());
-                    }
-                    Err(e) => {
-                        errors.push(handle_role_error(&role_def.name, &e));
-                    }
-                }
-            } else {
-                unchanged.push(role_def.name.clone());
-            }
-        } else {
-            // Create new role
-            let color = role_def
-                .color
-                .as_ref()
-                .and_then(|c| {
-                    let hex = c.trim_start_matches('#');
-                    u32::from_str_radix(hex, 16).ok().map(serenity::Colour::new)
-                })
-                .unwrap_or(serenity::Colour::default());
-
-            match guild_id
-                .create_role(
-                    http,
-                    serenity::EditRole::new()
-                        .name(&role_def.name)
-                        .colour(color)
-                        .hoist(role_def.hoist)
-                        .mentionable(role_def.mentionable),
-                )
-                .await
-            {
-                Ok(role) => {
-                    info!("Created role '{}' (ID: {}) via admin panel", role_def.name, role.id);
-                    created.push(role_def.name.clone());
-                }
-                Err(e) => {
-                    errors.push(handle_role_error(&role_def.name, &e));
-                }
-            }
-        }
-    }
+/// Shown when a plan changed between review and apply
+const STALE_PLAN_NOTICE: &str =
+    "Discord or the config changed since you reviewed the plan, so nothing was applied. Review the updated plan below.";
 
-    // Build result message
-    let mut message = String::new();
-    if !created.is_empty() {
-        message.push_str(&format!("<p><strong>Created ({}):</strong> {}</p>", created.len(), created.join(", ")));
+/// Plan a sync of role definitions against the current guild
+async fn build_roles_plan(
+    state: &AdminState,
+    roles: &[RoleDefinition],
+) -> crate::error::Result<SyncPlan> {
+    let snapshot = GuildSnapshot::fetch(state.serenity_http.as_ref(), state.guild_id).await?;
+    Ok(state.role_manager.read().await.plan_role_sync(&snapshot, roles).await)
+}
+
+/// Plan a season sync against the current guild
+async fn build_season_plan(
+    state: &AdminState,
+    structure: &MergedStructure,
+) -> crate::error::Result<SyncPlan> {
+    let snapshot = GuildSnapshot::fetch(state.serenity_http.as_ref(), state.guild_id).await?;
+    Ok(state
+        .channel_manager
+        .read()
+        .await
+        .plan_season_structure(&snapshot, structure)
+        .await)
+}
+
+/// Plan a prune of bot-created entities against the current guild
+async fn build_prune_plan(state: &AdminState, delete: bool) -> crate::error::Result<SyncPlan> {
+    let snapshot = GuildSnapshot::fetch(state.serenity_http.as_ref(), state.guild_id).await?;
+    Ok(state
+        .channel_manager
+        .read()
+        .await
+        .plan_prune(&snapshot, delete)
+        .await)
+}
+
+/// Render a sync plan with an "Apply this plan" button
+fn plan_page(
+    title: &str,
+    plan: &SyncPlan,
+    apply_url: &str,
+    back_url: &str,
+    notice: Option<&str>,
+) -> String {
+    let mut body = String::new();
+    if let Some(notice) = notice {
+        body.push_str(&format!(
+            "<p style=\"color:#f39c12;\">{}</p>",
+            html_escape(notice)
+        ));
     }
-    if !updated.is_empty() {
-        message.push_str(&format!("<p><strong>Updated ({}):</strong> {}</p>", updated.len(), updated.join(", ")));
+    body.push_str(&format!("<p><strong>{}</strong></p>", html_escape(&plan.headline())));
+    if !plan.is_empty() {
+        body.push_str(&render_diff(&plan.lines().join("\n")));
+    }
+    for warning in &plan.warnings {
+        body.push_str(&format!(
+            "<p style=\"color:#f39c12;\">{}</p>",
+            html_escape(warning)
+        ));
+    }
+    if !plan.is_empty() {
+        body.push_str(&format!(
+            r#"<form method="POST" action="{}" style="margin-top:1rem;">
+            <input type="hidden" name="plan" value="{}">
+            <button type="submit" class="btn btn-primary">Apply this plan</button>
+        </form>
+        <p class="hint">The plan is computed again before applying. If anything changed in the meantime you'll see the new plan instead.</p>"#,
+            apply_url,
+            plan.fingerprint()
+        ));
+    }
+
+    history_page(title, back_url, "Back", &body)
+}
+
+/// GET /admin/plan/roles - Show what a role sync would change
+async fn plan_roles(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let global_roles = state.config_manager.read().await.get_global_roles().cloned();
+    let Some(global_roles) = global_roles else {
+        return Html(sync_result_page(
+            "Role Sync Plan",
+            false,
+            "No global roles configured. Add roles to `data/global/roles.json`.",
+            "/admin",
+        ))
+        .into_response();
+    };
+
+    match build_roles_plan(&state, &global_roles.roles).await {
+        Ok(plan) => Html(plan_page(
+            "Role Sync Plan",
+            &plan,
+            "/admin/sync/roles",
+            "/admin/edit/global?tab=roles",
+            None,
+        ))
+        .into_response(),
+        Err(e) => {
+            error!("plan_roles: failed to plan role sync: {}", e);
+            Html(sync_result_page(
+                "Role Sync Plan",
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin/edit/global?tab=roles",
+            ))
+            .into_response()
+        }
+    }
+}
+
+/// GET /admin/plan/season/:id - Show what a season sync would change
+async fn plan_season(
+    headers: HeaderMap,
+    Path(season_id): Path<String>,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let title = format!("Season {} Sync Plan", season_id);
+    let back_url = format!("/admin/season/{}", season_id);
+    let structure = state.config_manager.read().await.get_season_structure(&season_id);
+    let Some(structure) = structure else {
+        return Html(sync_result_page(
+            &title,
+            false,
+            &format!("Season '{}' not found.", html_escape(&season_id)),
+            &back_url,
+        ))
+        .into_response();
+    };
+
+    match build_season_plan(&state, &structure).await {
+        Ok(plan) => Html(plan_page(
+            &title,
+            &plan,
+            &format!("/admin/sync/season/{}", season_id),
+            &back_url,
+            None,
+        ))
+        .into_response(),
+        Err(e) => {
+            error!("plan_season: failed to plan sync of '{}': {}", season_id, e);
+            Html(sync_result_page(
+                &title,
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                &back_url,
+            ))
+            .into_response()
+        }
+    }
+}
+
+/// Query params for the prune pages
+#[derive(Deserialize)]
+struct PruneParams {
+    /// Delete instead of hiding channels and categories (needed to prune roles)
+    #[serde(default)]
+    delete: bool,
+}
+
+/// Explain the prune mode above its plan
+fn prune_notice(delete: bool) -> &'static str {
+    if delete {
+        "Delete mode: the entities below are deleted from Discord and cannot be restored."
+    } else {
+        "Archive mode: channels and categories are hidden from members. Open /admin/plan/prune?delete=true to delete them, and bot-created roles, instead."
+    }
+}
+
+/// GET /admin/plan/prune - Show which bot-created entities a prune would archive or delete
+async fn plan_prune(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Query(params): Query<PruneParams>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    match build_prune_plan(&state, params.delete).await {
+        Ok(plan) => Html(plan_page(
+            "Prune Plan",
+            &plan,
+            &format!("/admin/sync/prune?delete={}", params.delete),
+            "/admin",
+            Some(prune_notice(params.delete)),
+        ))
+        .into_response(),
+        Err(e) => {
+            error!("plan_prune: failed to plan prune: {}", e);
+            Html(sync_result_page(
+                "Prune Plan",
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin",
+            ))
+            .into_response()
+        }
+    }
+}
+
+/// POST /admin/sync/prune - Apply a reviewed prune plan
+async fn sync_prune(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Query(params): Query<PruneParams>,
+    Form(form): Form<ApplyPlanForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    // Only apply a plan that was reviewed and is still current
+    match build_prune_plan(&state, params.delete).await {
+        Ok(plan) if form.plan.as_deref() != Some(plan.fingerprint().as_str()) => {
+            info!("sync_prune: plan missing or stale, showing the current plan");
+            return Html(plan_page(
+                "Prune Plan",
+                &plan,
+                &format!("/admin/sync/prune?delete={}", params.delete),
+                "/admin",
+                Some(if form.plan.is_some() {
+                    STALE_PLAN_NOTICE
+                } else {
+                    prune_notice(params.delete)
+                }),
+            ))
+            .into_response();
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("sync_prune: failed to plan prune: {}", e);
+            return Html(sync_result_page(
+                "Prune",
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin",
+            ))
+            .into_response();
+        }
+    }
+
+    info!("{} pruning (delete={}) via admin panel", session.username, params.delete);
+    let result = state
+        .channel_manager
+        .read()
+        .await
+        .apply_prune(state.serenity_http.as_ref(), state.guild_id, params.delete)
+        .await;
+    match result {
+        Ok(summary) => {
+            let mut message = String::new();
+            if !summary.archived.is_empty() {
+                message.push_str(&format!(
+                    "<p><strong>Archived ({}):</strong> {}</p>",
+                    summary.archived.len(),
+                    html_escape(&summary.archived.join(", "))
+                ));
+            }
+            if !summary.deleted.is_empty() {
+                message.push_str(&format!(
+                    "<p><strong>Deleted ({}):</strong> {}</p>",
+                    summary.deleted.len(),
+                    html_escape(&summary.deleted.join(", "))
+                ));
+            }
+            for warning in &summary.warnings {
+                message.push_str(&format!(
+                    "<p style=\"color:#f39c12;\">{}</p>",
+                    html_escape(warning)
+                ));
+            }
+            if message.is_empty() {
+                message = String::from("<p>Nothing was pruned.</p>");
+            }
+            Html(sync_result_page("Prune", true, &message, "/admin")).into_response()
+        }
+        Err(e) => {
+            error!("sync_prune: prune failed: {}", e);
+            Html(sync_result_page(
+                "Prune",
+                false,
+                &format!("Failed to prune: {}", html_escape(&e.to_string())),
+                "/admin",
+            ))
+            .into_response()
+        }
+    }
+}
+
+/// POST /admin/sync/roles - Apply a reviewed role sync plan
+async fn sync_roles(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Form(form): Form<ApplyPlanForm>,
+) -> impl IntoResponse {
+    // Diagnostic logging - this should always appear
+    eprintln!("[SYNC_ROLES] Handler called - starting role sync");
+    info!("=== SYNC ROLES STARTED ===");
+
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => {
+            info!("sync_roles: authenticated as {}", s.username);
+            s
+        }
+        Err(redirect) => {
+            warn!("sync_roles: authentication failed");
+            return redirect;
+        }
+    };
+
+    info!("sync_roles: loading config...");
+    let config = state.config_manager.read().await;
+    let global_roles = match config.get_global_roles() {
+        Some(r) => {
+            info!("sync_roles: found {} roles in config", r.roles.len());
+            r.clone()
+        }
+        None => {
+            warn!("sync_roles: no global roles configured");
+            return Html(sync_result_page(
+                "Sync Roles",
+                false,
+                "No global roles configured. Add roles to `data/global/roles.json`.",
+                "/admin",
+            )).into_response();
+        }
+    };
+    drop(config);
+
+    // Only apply a plan that was reviewed and is still current
+    match build_roles_plan(&state, &global_roles.roles).await {
+        Ok(plan) if form.plan.as_deref() != Some(plan.fingerprint().as_str()) => {
+            info!("sync_roles: plan missing or stale, showing the current plan");
+            return Html(plan_page(
+                "Role Sync Plan",
+                &plan,
+                "/admin/sync/roles",
+                "/admin/edit/global?tab=roles",
+                form.plan.is_some().then_some(STALE_PLAN_NOTICE),
+            ))
+            .into_response();
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("sync_roles: failed to plan role sync: {}", e);
+            return Html(sync_result_page(
+                "Sync Roles",
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin",
+            )).into_response();
+        }
+    }
+
+    let http = state.serenity_http.as_ref();
+    let guild_id = state.guild_id;
+    info!("sync_roles: using guild_id {}", guild_id);
+
+    let snapshot = match GuildSnapshot::fetch(http, guild_id).await {
+        Ok(snapshot) => {
+            info!("sync_roles: Discord has {} existing roles", snapshot.roles.len());
+            snapshot
+        }
+        Err(e) => {
+            error!("sync_roles: failed to fetch roles from Discord: {}", e);
+            return Html(sync_result_page(
+                "Sync Roles",
+                false,
+                &format!("Failed to fetch roles from Discord: {}", e),
+                "/admin",
+            )).into_response();
+        }
+    };
+
+    let mut created = Vec::new();
+    let mut updated = Vec::new();
+    let mut unchanged = Vec::new();
+    let mut errors = Vec::new();
+
+    let role_manager = state.role_manager.read().await;
+    for role_def in &global_roles.roles {
+        let existing = role_manager.find_role(&snapshot, role_def).await;
+        match role_manager.apply_role(http, guild_id, role_def, existing).await {
+            Ok(RoleSyncOutcome::Created) => created.push(role_def.name.clone()),
+            Ok(RoleSyncOutcome::Updated) => updated.push(role_def.name.clone()),
+            Ok(RoleSyncOutcome::Unchanged) => unchanged.push(role_def.name.clone()),
+            Err(e) => errors.push(handle_role_error(&role_def.name, &e)),
+        }
+    }
+    let mut reordered = Vec::new();
+    match role_manager.apply_role_order(http, guild_id, &global_roles.roles).await {
+        Ok(report) => {
+            reordered = report.moved;
+            errors.extend(report.above_bot);
+        }
+        Err(e) => {
+            error!("sync_roles: failed to reorder roles: {}", e);
+            errors.push(format!("Failed to reorder roles: {}", e));
+        }
+    }
+    drop(role_manager);
+
+    // Build result message
+    let mut message = String::new();
+    if !created.is_empty() {
+        message.push_str(&format!("<p><strong>Created ({}):</strong> {}</p>", created.len(), created.join(", ")));
+    }
+    if !updated.is_empty() {
+        message.push_str(&format!("<p><strong>Updated ({}):</strong> {}</p>", updated.len(), updated.join(", ")));
+    }
+    if !reordered.is_empty() {
+        message.push_str(&format!("<p><strong>Reordered ({}):</strong> {}</p>", reordered.len(), reordered.join(", ")));
     }
     if !unchanged.is_empty() {
         message.push_str(&format!("<p><strong>Unchanged ({}):</strong> {}</p>", unchanged.len(), unchanged.join(", ")));
@@ -2144,81 +2629,284 @@ async fn sync_assignments(
     )).into_response()
 }
 
-/// POST /admin/sync/season/:id - Sync season category to Discord
-async fn sync_season(
+/// Reconcile every member's managed roles, or only compute the changes
+async fn build_member_report(
+    state: &AdminState,
+    apply: bool,
+) -> crate::error::Result<MemberRoleReport> {
+    reconcile_members(
+        state.serenity_http.as_ref(),
+        state.guild_id,
+        &state.config_manager,
+        &state.channel_manager,
+        &state.user_database,
+        &state.role_manager,
+        apply,
+    )
+    .await
+}
+
+/// GET /admin/plan/members - Show what a member role reconciliation would change
+async fn plan_members(
     headers: HeaderMap,
-    Path(season_id): Path<String>,
     State(state): State<AdminState>,
 ) -> impl IntoResponse {
-    info!("=== SYNC SEASON STARTED for '{}' ===", season_id);
-
     let _session = match require_auth(&headers, &state).await {
-        Ok(s) => {
-            info!("sync_season: authenticated as {}", s.username);
-            s
-        }
-        Err(redirect) => {
-            warn!("sync_season: authentication failed");
-            return redirect;
-        }
+        Ok(s) => s,
+        Err(redirect) => return redirect,
     };
 
-    // Load season config
-    let config = state.config_manager.read().await;
-    let season = match config.get_season(&season_id) {
-        Some(s) => {
-            info!("sync_season: found season '{}' with {} channels", season_id, s.channels().len());
-            s.clone()
-        }
-        None => {
-            warn!("sync_season: season '{}' not found", season_id);
-            return Html(sync_result_page(
-                &format!("Sync Season {}", season_id),
+    match build_member_report(&state, false).await {
+        Ok(report) => Html(plan_page(
+            "Member Role Plan",
+            &report.plan(),
+            "/admin/sync/members",
+            "/admin/edit/global?tab=assignments",
+            None,
+        ))
+        .into_response(),
+        Err(e) => {
+            error!("plan_members: failed to plan member roles: {}", e);
+            Html(sync_result_page(
+                "Member Role Plan",
                 false,
-                &format!("Season '{}' not found.", season_id),
-                &format!("/admin/season/{}", season_id),
-            )).into_response();
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin/edit/global?tab=assignments",
+            ))
+            .into_response()
         }
-    };
-    drop(config);
-
-    if season.channels().is_empty() {
-        return Html(sync_result_page(
-            &format!("Sync Season {}", season_id),
-            false,
-            &format!("Season '{}' has no channels defined in season.json.", season_id),
-            &format!("/admin/season/{}", season_id),
-        )).into_response();
     }
+}
 
-    let http = state.serenity_http.as_ref();
-    let guild_id = state.guild_id;
-    let category_name = season.name().to_string();
-    let channels = season.channels().to_vec();
-
-    // Use channel_manager to sync
-    let channel_manager = state.channel_manager.read().await;
-    let summary = match channel_manager.sync_season_channels(http, guild_id, &category_name, &channels).await {
+/// POST /admin/sync/members - Apply a reviewed member role reconciliation
+async fn sync_members(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Form(form): Form<ApplyPlanForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
         Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    // Only apply a plan that was reviewed and is still current
+    match build_member_report(&state, false).await {
+        Ok(report) if form.plan.as_deref() != Some(report.plan().fingerprint().as_str()) => {
+            info!("sync_members: plan missing or stale, showing the current plan");
+            return Html(plan_page(
+                "Member Role Plan",
+                &report.plan(),
+                "/admin/sync/members",
+                "/admin/edit/global?tab=assignments",
+                form.plan.is_some().then_some(STALE_PLAN_NOTICE),
+            ))
+            .into_response();
+        }
+        Ok(_) => {}
         Err(e) => {
-            error!("Failed to sync season '{}': {}", season_id, e);
+            error!("sync_members: failed to plan member roles: {}", e);
             return Html(sync_result_page(
-                &format!("Sync Season {}", season_id),
+                "Member Roles",
                 false,
-                &format!("Failed to sync season: {}", e),
-                &format!("/admin/season/{}", season_id),
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                "/admin/edit/global?tab=assignments",
+            ))
+            .into_response();
+        }
+    }
+
+    info!("{} reconciling member roles via admin panel", session.username);
+    match build_member_report(&state, true).await {
+        Ok(report) => {
+            let mut message = format!("<p>{}</p>", html_escape(&report.summary()));
+            let failed: Vec<String> = report
+                .rows
+                .iter()
+                .filter(|r| !r.failed.is_empty())
+                .map(|r| format!("{}: {}", r.username, r.failed.join(", ")))
+                .collect();
+            if !failed.is_empty() {
+                message.push_str(&format!(
+                    "<p style=\"color:#e74c3c;\"><strong>Failed ({}):</strong></p><ul>{}</ul>",
+                    failed.len(),
+                    failed.iter().map(|f| format!("<li>{}</li>", html_escape(f))).collect::<Vec<_>>().join("")
+                ));
+            }
+            message.push_str(r#"<p><a href="/admin/members/report.csv">Download the current report (CSV)</a></p>"#);
+            Html(sync_result_page(
+                "Member Roles",
+                failed.is_empty(),
+                &message,
+                "/admin/edit/global?tab=assignments",
+            ))
+            .into_response()
+        }
+        Err(e) => {
+            error!("sync_members: failed to reconcile member roles: {}", e);
+            Html(sync_result_page(
+                "Member Roles",
+                false,
+                &format!("Failed to reconcile member roles: {}", html_escape(&e.to_string())),
+                "/admin/edit/global?tab=assignments",
+            ))
+            .into_response()
+        }
+    }
+}
+
+/// GET /admin/members/report.csv - Download the per-member role report
+///
+/// Computed without changing anything, so it shows what a reconciliation
+/// would do right now.
+async fn member_report_csv(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+) -> Response {
+    if let Err(redirect) = require_auth(&headers, &state).await {
+        return redirect;
+    }
+
+    match build_member_report(&state, false).await.and_then(|r| r.to_csv()) {
+        Ok(csv) => (
+            [
+                (axum::http::header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (
+                    axum::http::header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"member_roles.csv\"",
+                ),
+            ],
+            csv,
+        )
+            .into_response(),
+        Err(e) => {
+            error!("member_report_csv: failed to build report: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to build report: {}", e))
+                .into_response()
+        }
+    }
+}
+
+/// POST /admin/sync/season/:id - Apply a reviewed season sync plan
+async fn sync_season(
+    headers: HeaderMap,
+    Path(season_id): Path<String>,
+    State(state): State<AdminState>,
+    Form(form): Form<ApplyPlanForm>,
+) -> impl IntoResponse {
+    info!("=== SYNC SEASON STARTED for '{}' ===", season_id);
+
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => {
+            info!("sync_season: authenticated as {}", s.username);
+            s
+        }
+        Err(redirect) => {
+            warn!("sync_season: authentication failed");
+            return redirect;
+        }
+    };
+
+    // Resolve the season's merged layout
+    let config = state.config_manager.read().await;
+    let structure = match config.get_season_structure(&season_id) {
+        Some(s) => {
+            info!("sync_season: found season '{}' with {} channels", season_id, s.channels.len());
+            s
+        }
+        None => {
+            warn!("sync_season: season '{}' not found", season_id);
+            return Html(sync_result_page(
+                &format!("Sync Season {}", season_id),
+                false,
+                &format!("Season '{}' not found.", season_id),
+                &format!("/admin/season/{}", season_id),
+            )).into_response();
+        }
+    };
+    drop(config);
+
+    if structure.channels.is_empty() {
+        return Html(sync_result_page(
+            &format!("Sync Season {}", season_id),
+            false,
+            &format!(
+                "Season '{}' has no channels defined in season.json, category.json or global/structure.json.",
+                html_escape(&season_id)
+            ),
+            &format!("/admin/season/{}", season_id),
+        )).into_response();
+    }
+
+    // Only apply a plan that was reviewed and is still current
+    match build_season_plan(&state, &structure).await {
+        Ok(plan) if form.plan.as_deref() != Some(plan.fingerprint().as_str()) => {
+            info!("sync_season: plan missing or stale, showing the current plan");
+            return Html(plan_page(
+                &format!("Season {} Sync Plan", season_id),
+                &plan,
+                &format!("/admin/sync/season/{}", season_id),
+                &format!("/admin/season/{}", season_id),
+                form.plan.is_some().then_some(STALE_PLAN_NOTICE),
+            ))
+            .into_response();
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("sync_season: failed to plan sync of '{}': {}", season_id, e);
+            return Html(sync_result_page(
+                &format!("Sync Season {}", season_id),
+                false,
+                &format!("Failed to read the guild from Discord: {}", html_escape(&e.to_string())),
+                &format!("/admin/season/{}", season_id),
+            )).into_response();
+        }
+    }
+
+    let http = state.serenity_http.as_ref();
+    let guild_id = state.guild_id;
+
+    // Roles from the structure must exist before channel permissions can reference them
+    let mut role_error = None;
+    if !structure.roles.is_empty() {
+        let role_manager = state.role_manager.read().await;
+        if let Err(e) = role_manager.ensure_roles_exist(http, guild_id, &structure.roles).await {
+            error!("Failed to create structure roles for season '{}': {}", season_id, e);
+            role_error = Some(format!("Failed to create structure roles: {}", e));
+        }
+    }
+
+    // Use channel_manager to sync
+    let channel_manager = state.channel_manager.read().await;
+    let mut summary = match channel_manager
+        .sync_season_structure(http, guild_id, &season_id, &structure)
+        .await
+    {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to sync season '{}': {}", season_id, e);
+            return Html(sync_result_page(
+                &format!("Sync Season {}", season_id),
+                false,
+                &format!("Failed to sync season: {}", e),
+                &format!("/admin/season/{}", season_id),
             )).into_response();
         }
     };
     drop(channel_manager);
+    summary.warnings.extend(role_error);
 
     // Build result message from summary
     let mut message = String::new();
 
-    if let Some(cat) = &summary.category_created {
-        message.push_str(&format!("<p><strong>Category created:</strong> {}</p>", cat));
-    } else if let Some(cat) = &summary.category_existing {
-        message.push_str(&format!("<p><strong>Category:</strong> {}</p>", cat));
+    if !summary.categories_created.is_empty() {
+        message.push_str(&format!("<p><strong>Categories created:</strong> {}</p>",
+            html_escape(&summary.categories_created.join(", "))
+        ));
+    }
+    if !summary.categories_existing.is_empty() {
+        message.push_str(&format!("<p><strong>Categories:</strong> {}</p>",
+            html_escape(&summary.categories_existing.join(", "))
+        ));
     }
 
     if !summary.channels_created.is_empty() {
@@ -2235,6 +2923,12 @@ async fn sync_season(
         ));
     }
 
+    if !summary.attributes_changed.is_empty() {
+        message.push_str(&format!("<p><strong>Channel settings updated:</strong> {}</p>",
+            html_escape(&summary.format_attributes())
+        ));
+    }
+
     let has_missing_roles = !summary.missing_roles.is_empty();
     if has_missing_roles {
         warn!("Missing roles for season '{}': {}", season_id, summary.missing_roles.join(", "));
@@ -2260,6 +2954,736 @@ async fn sync_season(
     )).into_response()
 }
 
+/// Form data for archiving a season (checkboxes are only sent when ticked)
+#[derive(Deserialize)]
+struct ArchiveSeasonForm {
+    #[serde(default)]
+    move_to_bottom: Option<String>,
+    #[serde(default)]
+    strip_member_role: Option<String>,
+}
+
+/// POST /admin/archive/season/:id - Archive a season's category
+async fn archive_season(
+    headers: HeaderMap,
+    Path(season_id): Path<String>,
+    State(state): State<AdminState>,
+    Form(form): Form<ArchiveSeasonForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let title = format!("Archive Season {}", season_id);
+    let back_url = format!("/admin/season/{}", season_id);
+
+    let (category_names, member_role, was_active) = {
+        let config = state.config_manager.read().await;
+        match config.get_season(&season_id) {
+            Some(season) => (
+                config
+                    .get_season_category_names(&season_id)
+                    .unwrap_or_default(),
+                season.member_role(),
+                season.is_active(),
+            ),
+            None => {
+                return Html(sync_result_page(
+                    &title,
+                    false,
+                    &format!("Season '{}' not found.", html_escape(&season_id)),
+                    "/admin",
+                ))
+                .into_response();
+            }
+        }
+    };
+
+    let http = state.serenity_http.as_ref();
+    let guild_id = state.guild_id;
+
+    let channel_manager = state.channel_manager.read().await;
+    let mut summary = match channel_manager
+        .archive_season_categories(
+            http,
+            guild_id,
+            &season_id,
+            &category_names,
+            &member_role,
+            form.move_to_bottom.is_some(),
+        )
+        .await
+    {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to archive season '{}': {}", season_id, e);
+            return Html(sync_result_page(
+                &title,
+                false,
+                &format!("Failed to archive season: {}", html_escape(&e.to_string())),
+                &back_url,
+            ))
+            .into_response();
+        }
+    };
+    drop(channel_manager);
+
+    if form.strip_member_role.is_some() {
+        let role_manager = state.role_manager.read().await;
+        match role_manager
+            .remove_role_from_all_members(http, guild_id, &member_role)
+            .await
+        {
+            Ok((removed, failed)) => {
+                summary.members_stripped = Some(removed);
+                if !failed.is_empty() {
+                    summary.warnings.push(format!(
+                        "Could not remove {} from: {}",
+                        member_role,
+                        failed.join(", ")
+                    ));
+                }
+            }
+            Err(e) => summary
+                .warnings
+                .push(format!("Failed to remove {}: {}", member_role, e)),
+        }
+    }
+
+    // An archived season no longer accepts verification
+    if was_active {
+        let mut config = state.config_manager.write().await;
+        match config
+            .set_season_active(&season_id, false, &session.username)
+            .await
+        {
+            Ok(()) => summary.season_deactivated = true,
+            Err(e) => summary
+                .warnings
+                .push(format!("Failed to set season inactive: {}", e)),
+        }
+    }
+
+    info!("Admin {} archived season '{}'", session.username, season_id);
+
+    let mut message = format!(
+        "<p><strong>Categories archived:</strong> {}</p>",
+        html_escape(&summary.categories.join(", "))
+    );
+    if summary.channels_made_read_only.is_empty() {
+        message.push_str(&format!(
+            "<p>No overwrites for {} needed changing.</p>",
+            html_escape(&member_role)
+        ));
+    } else {
+        message.push_str(&format!(
+            "<p><strong>Read-only for {}:</strong> {}</p>",
+            html_escape(&member_role),
+            summary.channels_made_read_only.iter().map(|c| html_escape(c)).collect::<Vec<_>>().join(", ")
+        ));
+    }
+    if summary.moved_to_bottom {
+        message.push_str("<p><strong>Moved to bottom.</strong></p>");
+    }
+    if let Some(count) = summary.members_stripped {
+        message.push_str(&format!(
+            "<p><strong>{} removed from:</strong> {} members</p>",
+            html_escape(&member_role),
+            count
+        ));
+    }
+    if summary.season_deactivated {
+        message.push_str("<p><strong>Season set inactive.</strong></p>");
+    }
+    if !summary.warnings.is_empty() {
+        message.push_str(&format!(
+            "<p style=\"color:#f39c12;\"><strong>Warnings:</strong></p><ul>{}</ul>",
+            summary.warnings.iter().map(|w| format!("<li>{}</li>", html_escape(w))).collect::<Vec<_>>().join("")
+        ));
+    }
+
+    Html(sync_result_page(&title, summary.warnings.is_empty(), &message, &back_url)).into_response()
+}
+
+/// Form data for revoking or reinstating a user
+#[derive(Deserialize)]
+struct UserStatusForm {
+    #[serde(default)]
+    reason: String,
+    /// Season page to return to
+    #[serde(default)]
+    season: String,
+}
+
+impl UserStatusForm {
+    fn back_url(&self) -> String {
+        if self.season.is_empty() {
+            "/admin".to_string()
+        } else {
+            format!("/admin/season/{}", urlencoding::encode(&self.season))
+        }
+    }
+}
+
+/// POST /admin/user/:discord_id/revoke - Revoke a user's verification
+async fn revoke_user(
+    headers: HeaderMap,
+    Path(discord_id): Path<String>,
+    State(state): State<AdminState>,
+    Form(form): Form<UserStatusForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let back_url = form.back_url();
+    let user_id = match discord_id.parse::<u64>() {
+        Ok(id) => UserId::new(id),
+        Err(_) => {
+            return Html(sync_result_page("Revoke User", false, "Invalid Discord ID.", &back_url))
+                .into_response();
+        }
+    };
+
+    let reason = form.reason.trim();
+    if reason.is_empty() {
+        return Html(sync_result_page(
+            "Revoke User",
+            false,
+            "A reason is required to revoke a user.",
+            &back_url,
+        ))
+        .into_response();
+    }
+
+    let tracked_user = match state
+        .verification_manager
+        .revoke_user(user_id, reason, &session.username)
+        .await
+    {
+        Ok(u) => u,
+        Err(e) => {
+            return Html(sync_result_page(
+                "Revoke User",
+                false,
+                &html_escape(&e.to_string()),
+                &back_url,
+            ))
+            .into_response();
+        }
+    };
+
+    if let Err(e) = state
+        .verification_manager
+        .save_database(&crate::state::user_database_path())
+        .await
+    {
+        error!("Failed to save user database: {}", e);
+    }
+
+    let roles = state.verification_manager.get_roles_for_user(&tracked_user).await;
+    let (removed, failed) = state
+        .role_manager
+        .read()
+        .await
+        .remove_roles_from_user(&state.serenity_http, state.guild_id, user_id, &roles)
+        .await;
+
+    info!(
+        "Admin {} revoked verification for {} ({}): {}",
+        session.username, tracked_user.display_name, discord_id, reason
+    );
+
+    let mut message = format!(
+        "<p>Revoked verification for <strong>{}</strong>.</p><p>Reason: {}</p>",
+        html_escape(&tracked_user.display_name),
+        html_escape(reason)
+    );
+    if !removed.is_empty() {
+        message.push_str(&format!("<p><strong>Removed roles:</strong> {}</p>", removed.join(", ")));
+    }
+    if !failed.is_empty() {
+        message.push_str(&format!(
+            "<p style=\"color:#f39c12;\"><strong>Failed to remove:</strong> {}</p>",
+            failed.join(", ")
+        ));
+    }
+
+    Html(sync_result_page("Revoke User", failed.is_empty(), &message, &back_url)).into_response()
+}
+
+/// POST /admin/user/:discord_id/unrevoke - Reinstate a revoked or expired user
+async fn unrevoke_user(
+    headers: HeaderMap,
+    Path(discord_id): Path<String>,
+    State(state): State<AdminState>,
+    Form(form): Form<UserStatusForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let back_url = form.back_url();
+    let user_id = match discord_id.parse::<u64>() {
+        Ok(id) => UserId::new(id),
+        Err(_) => {
+            return Html(sync_result_page("Reinstate User", false, "Invalid Discord ID.", &back_url))
+                .into_response();
+        }
+    };
+
+    let reason = Some(form.reason.trim()).filter(|r| !r.is_empty());
+    let tracked_user = match state
+        .verification_manager
+        .unrevoke_user(user_id, reason, &session.username)
+        .await
+    {
+        Ok(u) => u,
+        Err(e) => {
+            return Html(sync_result_page(
+                "Reinstate User",
+                false,
+                &html_escape(&e.to_string()),
+                &back_url,
+            ))
+            .into_response();
+        }
+    };
+
+    if let Err(e) = state
+        .verification_manager
+        .save_database(&crate::state::user_database_path())
+        .await
+    {
+        error!("Failed to save user database: {}", e);
+    }
+
+    let roles = state.verification_manager.get_roles_for_user(&tracked_user).await;
+    let username = match state.serenity_http.get_user(user_id).await {
+        Ok(u) => u.name,
+        Err(_) => tracked_user.display_name.clone(),
+    };
+    let (added, failed) = state
+        .role_manager
+        .read()
+        .await
+        .sync_assignments_for_user(&state.serenity_http, state.guild_id, user_id, &username, &roles)
+        .await;
+
+    info!(
+        "Admin {} reinstated verification for {} ({})",
+        session.username, tracked_user.display_name, discord_id
+    );
+
+    let mut message = format!(
+        "<p>Reinstated <strong>{}</strong>.</p>",
+        html_escape(&tracked_user.display_name)
+    );
+    if !added.is_empty() {
+        message.push_str(&format!("<p><strong>Restored roles:</strong> {}</p>", added.join(", ")));
+    }
+    if !failed.is_empty() {
+        message.push_str(&format!(
+            "<p style=\"color:#f39c12;\"><strong>Failed to restore:</strong> {}</p>",
+            failed.join(", ")
+        ));
+    }
+
+    Html(sync_result_page("Reinstate User", failed.is_empty(), &message, &back_url)).into_response()
+}
+
+/// Form data for importing a season's users from CSV
+#[derive(Deserialize)]
+struct ImportCsvForm {
+    csv: String,
+}
+
+/// POST /admin/season/:id/import-csv - Stage a season's users from a CSV export
+async fn import_season_csv(
+    headers: HeaderMap,
+    Path(season_id): Path<String>,
+    State(state): State<AdminState>,
+    Form(form): Form<ImportCsvForm>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let title = format!("Import CSV for {}", season_id);
+    let back_url = format!("/admin/season/{}", season_id);
+
+    let mut config = state.config_manager.write().await;
+    if let Err(e) = config.stage_season_from_bytes_with_id(
+        form.csv.as_bytes(),
+        "upload.csv",
+        Some(&season_id),
+        Some(session.username.clone()),
+    ) {
+        return Html(sync_result_page(
+            &title,
+            false,
+            &format!("<pre>{}</pre>", html_escape(&e.to_string())),
+            &back_url,
+        ))
+        .into_response();
+    }
+    drop(config);
+
+    info!(
+        "{} staged a CSV import for season {} via admin panel",
+        session.username, season_id
+    );
+    Redirect::to("/admin/staged").into_response()
+}
+
+/// Query params for the config history page
+#[derive(Deserialize)]
+struct HistoryParams {
+    file: Option<String>,
+}
+
+/// Format a unix timestamp for display
+fn format_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|d| d.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Render a unified diff with added and removed lines highlighted
+fn render_diff(diff: &str) -> String {
+    if diff.is_empty() {
+        return "<p class=\"hint\">No differences.</p>".to_string();
+    }
+    let lines: Vec<String> = diff
+        .lines()
+        .map(|line| {
+            let class = if line.starts_with("+++") || line.starts_with("---") || line.starts_with("@@") {
+                "meta"
+            } else if line.starts_with('+') {
+                "add"
+            } else if line.starts_with('-') {
+                "del"
+            } else {
+                ""
+            };
+            format!("<span class=\"{}\">{}</span>", class, html_escape(line))
+        })
+        .collect();
+    format!("<pre class=\"diff\">{}</pre>", lines.join("\n"))
+}
+
+/// CSS for the history and staged config pages
+fn history_css() -> &'static str {
+    r#"
+        table { width: 100%; border-collapse: collapse; background: rgba(255,255,255,0.05); border-radius: 12px; overflow: hidden; margin-bottom: 1.5rem; }
+        th, td { padding: 0.75rem 1rem; text-align: left; border-bottom: 1px solid rgba(255,255,255,0.1); }
+        th { color: #a0a0a0; font-weight: 500; }
+        a { color: #5865F2; text-decoration: none; }
+        h3 { margin: 1.5rem 0 0.75rem; font-size: 1rem; color: #a0a0a0; }
+        .diff { background: #0d0d1a; border: 1px solid rgba(255,255,255,0.2); border-radius: 8px; padding: 1rem; overflow-x: auto; font-family: 'SF Mono', 'Fira Code', 'Consolas', monospace; font-size: 13px; line-height: 1.4; }
+        .diff .add { color: #2ecc71; }
+        .diff .del { color: #e74c3c; }
+        .diff .meta { color: #5865F2; }
+    "#
+}
+
+/// Wrap history page content in the admin page layout
+fn history_page(title: &str, back_url: &str, back_label: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{title} - Eventy Admin</title>
+    <style>{editor_css}{history_css}</style>
+</head>
+<body>
+    <nav class="navbar">
+        <h1>Eventy Admin</h1>
+        <a href="/admin/logout">Logout</a>
+    </nav>
+    <div class="container">
+        <div class="back"><a href="{back_url}">← {back_label}</a></div>
+        <h2>{title}</h2>
+        {body}
+    </div>
+</body>
+</html>"#,
+        title = html_escape(title),
+        editor_css = editor_css(),
+        history_css = history_css(),
+        back_url = back_url,
+        back_label = back_label,
+        body = body,
+    )
+}
+
+/// GET /admin/history - List saved config versions
+async fn history_list(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+    Query(params): Query<HistoryParams>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let history = state.config_manager.read().await.history().clone();
+    let snapshots = match params.file.as_deref().filter(|f| !f.is_empty()) {
+        Some(file) => history.list_for_file(file).await,
+        None => history.list().await,
+    };
+
+    let rows: Vec<String> = snapshots
+        .iter()
+        .map(|s| {
+            format!(
+                "<tr><td><a href=\"/admin/history/{id}\">{time}</a></td>\
+                 <td><a href=\"/admin/history?file={file_url}\">{file}</a></td>\
+                 <td>{author}</td><td>{action}</td></tr>",
+                id = s.id,
+                time = format_timestamp(s.timestamp),
+                file_url = urlencoding::encode(&s.file),
+                file = html_escape(&s.file),
+                author = html_escape(&s.author),
+                action = html_escape(&s.action),
+            )
+        })
+        .collect();
+
+    let body = if rows.is_empty() {
+        "<p class=\"hint\">No config changes have been recorded yet.</p>".to_string()
+    } else {
+        format!(
+            "<table><thead><tr><th>Saved</th><th>File</th><th>By</th><th>Action</th></tr></thead>\
+             <tbody>{}</tbody></table>",
+            rows.join("\n")
+        )
+    };
+
+    let title = match params.file.as_deref().filter(|f| !f.is_empty()) {
+        Some(file) => format!("History of {}", file),
+        None => "Config History".to_string(),
+    };
+
+    Html(history_page(&title, "/admin", "Back to Dashboard", &body)).into_response()
+}
+
+/// GET /admin/history/:id - Show one saved version and what it changed
+async fn history_detail(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let config = state.config_manager.read().await;
+    let history = config.history().clone();
+    let data_path = config.get_data_path().to_string();
+    drop(config);
+
+    let Some(snapshot) = history.get(&id).await else {
+        return Html(sync_result_page(
+            "Config History",
+            false,
+            &format!("<p>No saved version with ID {}</p>", html_escape(&id)),
+            "/admin/history",
+        ))
+        .into_response();
+    };
+
+    let previous = history.previous_version(&snapshot).await;
+    let previous_diff = match &previous {
+        Some(prev) => unified_diff(
+            &prev.content,
+            &snapshot.content,
+            &format_timestamp(prev.timestamp),
+            &format_timestamp(snapshot.timestamp),
+        ),
+        None => unified_diff("", &snapshot.content, "(none)", &format_timestamp(snapshot.timestamp)),
+    };
+
+    let current = tokio::fs::read_to_string(format!("{}/{}", data_path, snapshot.file))
+        .await
+        .unwrap_or_default();
+    let current_diff = unified_diff(
+        &current,
+        &snapshot.content,
+        "current",
+        &format_timestamp(snapshot.timestamp),
+    );
+
+    let body = format!(
+        r#"<p><strong>File:</strong> <a href="/admin/history?file={file_url}">{file}</a></p>
+        <p><strong>Saved:</strong> {time}</p>
+        <p><strong>By:</strong> {author} ({action})</p>
+        <h3>Changes from the previous version</h3>
+        {previous_diff}
+        <h3>Restoring this version would change the current file</h3>
+        {current_diff}
+        <form method="POST" action="/admin/history/{id}/restore" style="margin-top:1rem;">
+            <button type="submit" class="btn btn-primary">Restore this version</button>
+        </form>
+        <p class="hint">Restoring stages this version. Review it on the next page and commit to apply it.</p>"#,
+        file_url = urlencoding::encode(&snapshot.file),
+        file = html_escape(&snapshot.file),
+        time = format_timestamp(snapshot.timestamp),
+        author = html_escape(&snapshot.author),
+        action = html_escape(&snapshot.action),
+        previous_diff = render_diff(&previous_diff),
+        current_diff = render_diff(&current_diff),
+        id = snapshot.id,
+    );
+
+    Html(history_page(
+        &format!("Version of {}", snapshot.file),
+        "/admin/history",
+        "Back to History",
+        &body,
+    ))
+    .into_response()
+}
+
+/// POST /admin/history/:id/restore - Stage a saved version for commit
+async fn restore_history(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let mut config = state.config_manager.write().await;
+    let Some(snapshot) = config.history().get(&id).await else {
+        return Html(sync_result_page(
+            "Restore Version",
+            false,
+            &format!("<p>No saved version with ID {}</p>", html_escape(&id)),
+            "/admin/history",
+        ))
+        .into_response();
+    };
+
+    if let Err(e) = config.stage_file(
+        &snapshot.file,
+        snapshot.content.as_bytes(),
+        Some(session.username.clone()),
+    ) {
+        return Html(sync_result_page(
+            "Restore Version",
+            false,
+            &format!("<p>{}</p>", html_escape(&e.to_string())),
+            &format!("/admin/history/{}", id),
+        ))
+        .into_response();
+    }
+
+    info!(
+        "{} staged {} from version {} via admin panel",
+        session.username, snapshot.file, snapshot.id
+    );
+    Redirect::to("/admin/staged").into_response()
+}
+
+/// GET /admin/staged - Review staged config before committing
+async fn staged_config(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let _session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let config = state.config_manager.read().await;
+    let user_db = state.user_database.read().await;
+    let body = if config.has_staged() {
+        format!(
+            r#"<pre class="diff">{summary}</pre>
+            <h3>Changes</h3>
+            <pre class="diff">{diff}</pre>
+            <form method="POST" action="/admin/staged/commit" style="display:inline;">
+                <button type="submit" class="btn btn-primary">Commit</button>
+            </form>
+            <form method="POST" action="/admin/staged/discard" style="display:inline;">
+                <button type="submit" class="btn btn-secondary">Discard</button>
+            </form>"#,
+            summary = html_escape(&config.get_staged_summary()),
+            diff = html_escape(&config.get_staged_diff(&user_db).format_for_display()),
+        )
+    } else {
+        "<p class=\"hint\">Nothing is staged.</p>".to_string()
+    };
+    drop(user_db);
+    drop(config);
+
+    Html(history_page("Staged Config", "/admin/history", "Back to History", &body)).into_response()
+}
+
+/// POST /admin/staged/commit - Commit the staged config
+async fn commit_staged_config(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    let mut config = state.config_manager.write().await;
+    match config.commit_staged().await {
+        Ok(changes) => {
+            info!("Staged config committed by {} via admin panel", session.username);
+            let message = changes
+                .iter()
+                .map(|c| {
+                    format!(
+                        "<p>{} ({}): {}</p>",
+                        html_escape(&c.entity_type),
+                        html_escape(&c.entity_name),
+                        html_escape(&c.details)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("");
+            Html(sync_result_page("Commit Config", true, &message, "/admin/history")).into_response()
+        }
+        Err(e) => Html(sync_result_page(
+            "Commit Config",
+            false,
+            &format!("<pre>{}</pre>", html_escape(&e.to_string())),
+            "/admin/staged",
+        ))
+        .into_response(),
+    }
+}
+
+/// POST /admin/staged/discard - Discard the staged config
+async fn discard_staged_config(
+    headers: HeaderMap,
+    State(state): State<AdminState>,
+) -> impl IntoResponse {
+    let session = match require_auth(&headers, &state).await {
+        Ok(s) => s,
+        Err(redirect) => return redirect,
+    };
+
+    state.config_manager.write().await.clear_staged();
+    info!("Staged config discarded by {} via admin panel", session.username);
+    Redirect::to("/admin/history").into_response()
+}
+
 /// Generate a sync result page
 fn sync_result_page(title: &str, success: bool, message: &str, back_url: &str) -> String {
     let status_color = if success { "#2ecc71" } else { "#e74c3c" };
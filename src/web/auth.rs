@@ -225,13 +225,10 @@ pub fn create_logout_cookie() -> String {
 /// Generate the OAuth URL for admin login
 pub fn admin_oauth_url(oauth: &OAuthState) -> String {
     let redirect_uri = format!("{}/admin/callback", oauth.base_url);
-    let redirect_uri_encoded = urlencoding::encode(&redirect_uri);
 
-    format!(
-        "https://discord.com/api/oauth2/authorize?client_id={}&redirect_uri={}&response_type=code&scope=identify&state=admin_login",
-        oauth.client_id,
-        redirect_uri_encoded
-    )
+    oauth
+        .discord
+        .authorize_url(&oauth.client_id, &redirect_uri, "identify", "admin_login")
 }
 
 /// Admin login page HTML
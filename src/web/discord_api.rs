@@ -0,0 +1,200 @@
+//! Minimal Discord REST client for the OAuth flows
+//!
+//! All OAuth token exchanges and user lookups go through this client so the
+//! API base URL can be pointed at a local stand-in server for testing.
+//! This file is also included by `bin/oauth_test.rs`, so it must not depend
+//! on other crate modules.
+
+use serde::Deserialize;
+
+/// Default Discord REST API base URL
+pub const DEFAULT_API_BASE_URL: &str = "https://discord.com/api";
+
+/// Errors returned by the Discord REST client
+#[derive(Debug, thiserror::Error)]
+pub enum DiscordApiError {
+    #[error("Request to Discord failed: {0}")]
+    Request(#[source] reqwest::Error),
+
+    #[error("Discord returned {status}: {body}")]
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+
+    #[error("Failed to parse Discord response: {0}")]
+    Parse(#[source] reqwest::Error),
+}
+
+/// Discord OAuth token response
+#[derive(Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+}
+
+/// Discord user info from /users/@me
+///
+/// Not every binary including this file reads every field.
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct DiscordUser {
+    pub id: String,
+    pub username: String,
+    pub global_name: Option<String>,
+    pub discriminator: String,
+    #[serde(default)]
+    pub avatar: Option<String>,
+}
+
+/// Discord REST client with a configurable base URL
+#[derive(Clone)]
+pub struct DiscordRestClient {
+    api_base_url: String,
+    http: reqwest::Client,
+}
+
+impl DiscordRestClient {
+    /// Create a client for the given API base URL (e.g. `https://discord.com/api`)
+    pub fn new(api_base_url: impl Into<String>) -> Self {
+        Self {
+            api_base_url: api_base_url.into().trim_end_matches('/').to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Create a client using DISCORD_API_BASE_URL, falling back to the real API
+    pub fn from_env() -> Self {
+        let api_base_url = std::env::var("DISCORD_API_BASE_URL")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| DEFAULT_API_BASE_URL.to_string());
+        Self::new(api_base_url)
+    }
+
+    pub fn api_base_url(&self) -> &str {
+        &self.api_base_url
+    }
+
+    /// Build the OAuth2 authorization URL users are sent to
+    pub fn authorize_url(&self, client_id: &str, redirect_uri: &str, scope: &str, state: &str) -> String {
+        format!(
+            "{}/oauth2/authorize?client_id={}&redirect_uri={}&response_type=code&scope={}&state={}",
+            self.api_base_url,
+            client_id,
+            urlencoding::encode(redirect_uri),
+            urlencoding::encode(scope),
+            urlencoding::encode(state)
+        )
+    }
+
+    /// Exchange an authorization code for an access token
+    pub async fn exchange_code(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<TokenResponse, DiscordApiError> {
+        let response = self
+            .http
+            .post(format!("{}/oauth2/token", self.api_base_url))
+            .form(&[
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", redirect_uri),
+            ])
+            .send()
+            .await
+            .map_err(DiscordApiError::Request)?;
+
+        let response = Self::check_status(response).await?;
+        response.json().await.map_err(DiscordApiError::Parse)
+    }
+
+    /// Fetch the user the access token belongs to
+    pub async fn get_current_user(&self, token: &TokenResponse) -> Result<DiscordUser, DiscordApiError> {
+        let response = self
+            .http
+            .get(format!("{}/users/@me", self.api_base_url))
+            .header(
+                "Authorization",
+                format!("{} {}", token.token_type, token.access_token),
+            )
+            .send()
+            .await
+            .map_err(DiscordApiError::Request)?;
+
+        let response = Self::check_status(response).await?;
+        response.json().await.map_err(DiscordApiError::Parse)
+    }
+
+    /// Add a user to a guild using their OAuth access token (`guilds.join` scope)
+    ///
+    /// `nick` and `role_ids` are applied as the user joins. Returns 201 if the
+    /// user was added and 204 if they were already a member, in which case
+    /// Discord ignores the nickname and roles.
+    pub async fn add_guild_member(
+        &self,
+        bot_token: &str,
+        guild_id: &str,
+        user_id: &str,
+        access_token: &str,
+        nick: Option<&str>,
+        role_ids: &[String],
+    ) -> Result<reqwest::StatusCode, DiscordApiError> {
+        let mut body = serde_json::json!({
+            "access_token": access_token
+        });
+        if let Some(nick) = nick {
+            body["nick"] = serde_json::Value::from(nick);
+        }
+        if !role_ids.is_empty() {
+            body["roles"] = serde_json::Value::from(role_ids.to_vec());
+        }
+
+        let response = self
+            .http
+            .put(format!(
+                "{}/guilds/{}/members/{}",
+                self.api_base_url, guild_id, user_id
+            ))
+            .header("Authorization", format!("Bot {}", bot_token))
+            .json(&body)
+            .send()
+            .await
+            .map_err(DiscordApiError::Request)?;
+
+        let response = Self::check_status(response).await?;
+        Ok(response.status())
+    }
+
+    async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, DiscordApiError> {
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(DiscordApiError::Status { status, body })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize_url_uses_base_url() {
+        let client = DiscordRestClient::new("http://127.0.0.1:9000/api/");
+        assert_eq!(client.api_base_url(), "http://127.0.0.1:9000/api");
+
+        let url = client.authorize_url("123", "https://example.com/callback", "identify guilds.join", "a.b");
+        assert_eq!(
+            url,
+            "http://127.0.0.1:9000/api/oauth2/authorize?client_id=123&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&response_type=code&scope=identify%20guilds.join&state=a.b"
+        );
+    }
+}
@@ -5,8 +5,10 @@
 
 mod admin;
 mod auth;
+mod discord_api;
 mod oauth;
 mod server;
+mod verify_state;
 
 pub use auth::create_session_store;
 pub use oauth::OAuthState;
@@ -1,6 +1,8 @@
 //! OAuth state and Discord API interactions
 
-use serde::Deserialize;
+use tracing::warn;
+
+use super::discord_api::DiscordRestClient;
 
 /// OAuth configuration
 #[derive(Clone)]
@@ -9,7 +11,10 @@ pub struct OAuthState {
     pub client_secret: String,
     pub bot_token: String,
     pub base_url: String,
-    pub http_client: reqwest::Client,
+    /// Key used to sign the OAuth `state` parameter
+    pub state_secret: Vec<u8>,
+    /// Discord REST client (base URL from DISCORD_API_BASE_URL)
+    pub discord: DiscordRestClient,
 }
 
 impl OAuthState {
@@ -21,13 +26,23 @@ impl OAuthState {
             .ok()?;
         let base_url = std::env::var("WEB_BASE_URL")
             .unwrap_or_else(|_| "http://localhost:3000".to_string());
+        let state_secret = match std::env::var("OAUTH_STATE_SECRET") {
+            Ok(secret) if !secret.is_empty() => secret.into_bytes(),
+            _ => {
+                warn!("OAUTH_STATE_SECRET not set, using a random key (in-flight verifications will fail after a restart)");
+                let mut key = uuid::Uuid::new_v4().as_bytes().to_vec();
+                key.extend_from_slice(uuid::Uuid::new_v4().as_bytes());
+                key
+            }
+        };
 
         Some(Self {
             client_id,
             client_secret,
             bot_token,
             base_url,
-            http_client: reqwest::Client::new(),
+            state_secret,
+            discord: DiscordRestClient::from_env(),
         })
     }
 
@@ -35,19 +50,3 @@ impl OAuthState {
         format!("{}/callback", self.base_url)
     }
 }
-
-/// Discord OAuth token response
-#[derive(Deserialize)]
-pub struct TokenResponse {
-    pub access_token: String,
-    pub token_type: String,
-}
-
-/// Discord user info from /users/@me
-#[derive(Deserialize, Debug)]
-pub struct DiscordUser {
-    pub id: String,
-    pub username: String,
-    pub global_name: Option<String>,
-    pub discriminator: String,
-}
@@ -3,8 +3,8 @@
 use axum::{
     extract::{Host, Path, Query, State},
     handler::HandlerWithoutStateExt,
-    http::{StatusCode, Uri},
-    response::{Html, Redirect},
+    http::{header::SET_COOKIE, HeaderMap, StatusCode, Uri},
+    response::{Html, IntoResponse, Redirect, Response},
     routing::get,
     BoxError, Router,
 };
@@ -16,10 +16,17 @@ use tracing::{debug, error, info, warn};
 
 use super::admin::{admin_router, AdminState};
 use super::auth::SharedSessionStore;
-use super::oauth::{DiscordUser, OAuthState, TokenResponse};
+use super::oauth::OAuthState;
+use super::verify_state::{
+    create_verify_nonce_cookie, generate_nonce, get_verify_nonce, now_secs, sign_state,
+    verify_state,
+};
 use crate::logging::SharedLogBuffer;
-use crate::managers::{SharedChannelManager, SharedConfigManager, SharedRoleManager, SharedVerificationManager};
-use crate::state::{SharedUserDatabase, TrackedUser};
+use crate::managers::{
+    SharedChannelManager, SharedConfigManager, SharedDriftDetector, SharedMaintainersManager,
+    SharedRoleManager, SharedVerificationManager,
+};
+use crate::state::{user_database_path, SharedUserDatabase, VerificationStatus};
 
 /// Web server configuration
 pub struct WebServerConfig {
@@ -87,7 +94,7 @@ pub struct AppState {
 #[derive(Deserialize)]
 pub struct CallbackParams {
     code: String,
-    state: String, // This contains the UUID
+    state: String, // Signed token carrying the verification ID
 }
 
 /// Start the web server for OAuth verification and admin panel
@@ -102,6 +109,8 @@ pub async fn start_web_server(
     serenity_http: Arc<serenity::Http>,
     session_store: SharedSessionStore,
     log_buffer: SharedLogBuffer,
+    drift_detector: SharedDriftDetector,
+    maintainers_manager: SharedMaintainersManager,
     guild_id: GuildId,
 ) -> anyhow::Result<()> {
     let state = AppState {
@@ -114,6 +123,7 @@ pub async fn start_web_server(
 
     // Capture base_url before moving oauth into admin_state
     let base_url = oauth.base_url.clone();
+    let api_base_url = oauth.discord.api_base_url().to_string();
 
     // Create admin state
     let admin_state = AdminState {
@@ -122,8 +132,11 @@ pub async fn start_web_server(
         channel_manager,
         role_manager: state.role_manager.clone(),
         user_database,
+        verification_manager: state.verification_manager.clone(),
         session_store,
         log_buffer,
+        drift_detector,
+        maintainers_manager,
         serenity_http,
         guild_id,
     };
@@ -181,6 +194,7 @@ pub async fn start_web_server(
     info!("  1. {}/callback        (for user verification)", base_url);
     info!("  2. {}/admin/callback  (for admin login)", base_url);
     info!("Portal: https://discord.com/developers/applications -> OAuth2 -> Redirects");
+    info!("Discord API base URL: {}", api_base_url);
 
     axum_server::bind_rustls(https_addr, tls_config)
         .serve(app.into_make_service())
@@ -253,37 +267,41 @@ async fn health() -> &'static str {
 }
 
 /// GET /verify/{uuid} - Show verification page
-async fn verify_page(State(state): State<AppState>, Path(uuid): Path<String>) -> Html<String> {
+async fn verify_page(State(state): State<AppState>, Path(uuid): Path<String>) -> Response {
     info!("Verification page requested for UUID: {}", uuid);
 
-    // Check if the UUID exists in our config
-    let user_exists = {
+    // Check if the UUID exists in a season that is open for verification
+    let lookup = {
         let config = state.config_manager.read().await;
-        config.find_user_by_verification_id(&uuid).is_some()
+        if config.find_user_by_verification_id(&uuid).is_some() {
+            Ok(())
+        } else {
+            Err(config.verification_closed_reason(&uuid))
+        }
     };
 
-    if !user_exists {
-        return Html(error_page(
-            "Invalid verification link. This ID was not found in our records.",
-        ));
+    if let Err(closed_reason) = lookup {
+        let message = closed_reason.unwrap_or_else(|| {
+            "Invalid verification link. This ID was not found in our records.".to_string()
+        });
+        return Html(error_page(&message)).into_response();
     }
 
     // Build redirect URI
     let redirect_uri = state.oauth.redirect_uri();
 
-    let oauth_url = format!(
-        "https://discord.com/oauth2/authorize\
-        ?client_id={}\
-        &redirect_uri={}\
-        &response_type=code\
-        &scope=identify%20guilds.join\
-        &state={}",
-        state.oauth.client_id,
-        urlencoding::encode(&redirect_uri),
-        urlencoding::encode(&uuid)
+    // Bind the OAuth state to this browser and sign it
+    let nonce = generate_nonce();
+    let signed_state = sign_state(&state.oauth.state_secret, &uuid, &nonce, now_secs());
+
+    let oauth_url = state.oauth.discord.authorize_url(
+        &state.oauth.client_id,
+        &redirect_uri,
+        "identify guilds.join",
+        &signed_state,
     );
 
-    Html(format!(
+    let page = Html(format!(
         r#"<!DOCTYPE html>
 <html lang="en">
 <head>
@@ -354,298 +372,186 @@ async fn verify_page(State(state): State<AppState>, Path(uuid): Path<String>) ->
 </html>"#,
         uuid = uuid,
         oauth_url = oauth_url
-    ))
+    ));
+
+    ([(SET_COOKIE, create_verify_nonce_cookie(&nonce))], page).into_response()
 }
 
 /// GET /callback - OAuth callback handler
 async fn oauth_callback(
+    headers: HeaderMap,
     State(state): State<AppState>,
     Query(params): Query<CallbackParams>,
 ) -> Result<Html<String>, Html<String>> {
-    info!("OAuth callback received for UUID: {}", params.state);
+    // Validate the signed state before talking to Discord
+    let cookie_nonce = get_verify_nonce(&headers);
+    let verification_id = verify_state(
+        &state.oauth.state_secret,
+        &params.state,
+        cookie_nonce.as_deref(),
+        now_secs(),
+    )
+    .map_err(|e| {
+        warn!("Rejected OAuth state: {:?}", e);
+        Html(error_page(&e.to_string()))
+    })?;
+    let verification_id = verification_id.as_str();
+
+    info!("OAuth callback received for UUID: {}", verification_id);
 
     // Exchange code for access token
-    let token_response = state
+    let token = state
         .oauth
-        .http_client
-        .post("https://discord.com/api/oauth2/token")
-        .form(&[
-            ("client_id", state.oauth.client_id.as_str()),
-            ("client_secret", state.oauth.client_secret.as_str()),
-            ("grant_type", "authorization_code"),
-            ("code", params.code.as_str()),
-            ("redirect_uri", &state.oauth.redirect_uri()),
-        ])
-        .send()
+        .discord
+        .exchange_code(
+            &state.oauth.client_id,
+            &state.oauth.client_secret,
+            &params.code,
+            &state.oauth.redirect_uri(),
+        )
         .await
         .map_err(|e| {
-            error!("Failed to exchange code: {}", e);
+            error!("Token exchange failed: {}", e);
             Html(error_page("Failed to exchange authorization code"))
         })?;
 
-    if !token_response.status().is_success() {
-        let error_text = token_response.text().await.unwrap_or_default();
-        error!("Token exchange failed: {}", error_text);
-        return Err(Html(error_page("Token exchange failed")));
-    }
-
-    let token: TokenResponse = token_response.json().await.map_err(|e| {
-        error!("Failed to parse token response: {}", e);
-        Html(error_page("Failed to parse token response"))
-    })?;
-
     info!("Got access token, fetching user info...");
 
     // Get user info
-    let user_response = state
+    let discord_user = state
         .oauth
-        .http_client
-        .get("https://discord.com/api/users/@me")
-        .header(
-            "Authorization",
-            format!("{} {}", token.token_type, token.access_token),
-        )
-        .send()
+        .discord
+        .get_current_user(&token)
         .await
         .map_err(|e| {
             error!("Failed to get user info: {}", e);
             Html(error_page("Failed to get user info"))
         })?;
 
-    if !user_response.status().is_success() {
-        let error_text = user_response.text().await.unwrap_or_default();
-        error!("User info request failed: {}", error_text);
-        return Err(Html(error_page("Failed to get user info")));
-    }
-
-    let discord_user: DiscordUser = user_response.json().await.map_err(|e| {
-        error!("Failed to parse user info: {}", e);
-        Html(error_page("Failed to parse user info"))
-    })?;
-
     info!(
         "User authenticated: {} ({})",
         discord_user.username, discord_user.id
     );
 
     // Verify the user using our verification system
-    let verification_id = &params.state;
     let discord_user_id: u64 = discord_user
         .id
         .parse()
         .map_err(|_| Html(error_page("Invalid Discord user ID")))?;
     let user_id = UserId::new(discord_user_id);
 
-    // Look up the user in config FIRST to get season info
-    let verification_result = {
+    let guild_id = std::env::var("DISCORD_GUILD_ID")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(GuildId::new);
+
+    // Look up the season this verification ID belongs to
+    let season_id = {
         let config = state.config_manager.read().await;
         debug!("finding user...");
-        match config.find_user_by_verification_id(verification_id) {
-            Some((season, season_user)) => {
-                // Special roles are looked up by Discord username
-                info!(
-                    "Looking up special roles for Discord username: '{}'",
-                    discord_user.username
-                );
-                let special_roles = config.get_special_roles_for_user(&discord_user.username);
-
-                // Use the season's member_role (e.g., Medlem2025E) instead of global default
-                let member_role = season.member_role();
-                info!(
-                    "Using season member role '{}' for season '{}'",
-                    member_role, season.season_id
-                );
-
-                let mut roles_to_assign = vec![member_role];
-                roles_to_assign.extend(special_roles.clone());
-
-                Some((
-                    season_user.name.clone(),
-                    season.season_id.clone(),
-                    roles_to_assign,
-                    special_roles,
-                ))
-            }
-            None => None,
-        }
+        config
+            .find_user_by_verification_id(verification_id)
+            .map(|(season, _)| season.season_id.clone())
+            .ok_or_else(|| config.verification_closed_reason(verification_id))
     };
 
-    let (display_name, season_id, roles_to_assign, special_roles) = match verification_result {
-        Some(result) => result,
-        None => {
-            return Err(Html(error_page("Verification ID not found in our records")));
+    let season_id = match season_id {
+        Ok(id) => id,
+        Err(closed_reason) => {
+            let message = closed_reason
+                .unwrap_or_else(|| "Verification ID not found in our records".to_string());
+            return Err(Html(error_page(&message)));
         }
     };
 
-    // Check if already verified
-
     // Get existing user from DB
     let existing_user = state.verification_manager.get_verified_user(user_id).await;
 
-    if let Some(ref user) = existing_user {
-        // Check if verified for THIS season
-        if user.verification_ids.contains_key(&season_id) {
+    let (display_name, roles_to_assign) = match existing_user {
+        Some(user) if user.verification_status == VerificationStatus::Revoked => {
+            return Err(Html(error_page(
+                "Your verification has been revoked. Please contact an administrator.",
+            )));
+        }
+        Some(user)
+            if user.verification_ids.contains_key(&season_id)
+                && user.verification_status == VerificationStatus::Verified =>
+        {
             // User is verified for this season. Check if they are still in the guild.
-            let guild_id_str = std::env::var("DISCORD_GUILD_ID").ok();
-            if let Some(guild_id_str) = guild_id_str {
-                if let Ok(guild_id) = guild_id_str.parse::<u64>() {
-                    let guild_id = serenity::GuildId::new(guild_id);
-                    let member = guild_id.member(&state.serenity_http, user_id).await;
-
-                    if member.is_ok() {
-                        // They are in the guild AND verified for this season.
-                        return Ok(Html(already_verified_page(&discord_user.username)));
-                    }
-                    // If not in guild, we continue to re-verify (re-add roles, etc)
-                    warn!(
-                        "User {} is verified for season {} but not in guild. Re-verifying.",
-                        user_id, season_id
-                    );
+            if let Some(guild_id) = guild_id {
+                if guild_id.member(&state.serenity_http, user_id).await.is_ok() {
+                    return Ok(Html(already_verified_page(&discord_user.username)));
                 }
             }
-        }
-    }
 
-    // Check if this verification ID was already used by someone else
-    {
-        let db = state.verification_manager.user_db().read().await;
-        if let Some(existing) = db.find_by_verification_id(verification_id) {
-            if existing.discord_id != discord_user.id {
-                return Err(Html(error_page(
-                    "This verification ID has already been used by another account.",
-                )));
-            }
-        }
-    }
-
-    // Save/Update the verified user to database
-    {
-        let mut user_db = state.verification_manager.user_db().write().await;
-
-        if let Some(existing) = user_db.find_by_discord_id(&discord_user.id) {
-            let mut updated_user = existing.clone();
-            updated_user.add_verification_id(&season_id, verification_id);
-            // Merge special roles
-            for role in &special_roles {
-                if !updated_user.special_roles.contains(role) {
-                    updated_user.special_roles.push(role.clone());
+            // Check if this verification ID was already used by someone else
+            {
+                let db = state.verification_manager.user_db().read().await;
+                if let Some(existing) = db.find_by_verification_id(verification_id) {
+                    if existing.discord_id != discord_user.id {
+                        return Err(Html(error_page(
+                            "This verification ID has already been used by another account.",
+                        )));
+                    }
                 }
             }
-            // Update display name
-            updated_user.display_name = display_name.clone();
-            updated_user.update_last_seen();
 
-            user_db.upsert_user(updated_user);
-        } else {
-            let tracked_user = TrackedUser::new(
-                discord_user.id.clone(),
-                verification_id.to_string(),
-                season_id.clone(),
-                display_name.clone(),
-                special_roles,
+            // If not in guild, we continue to re-add them with their roles
+            warn!(
+                "User {} is verified for season {} but not in guild. Re-adding.",
+                user_id, season_id
             );
-            user_db.upsert_user(tracked_user);
+            let roles = state
+                .verification_manager
+                .get_roles_for_season(&season_id, &discord_user.username)
+                .await;
+            (user.display_name, roles)
         }
-    }
-
-    // Save database to disk
-    if let Err(e) = state
-        .verification_manager
-        .save_database("state/user_database.json")
-        .await
-    {
-        error!("Failed to save user database: {}", e);
-    }
-
-    // Get all guilds and apply roles/nickname
-    let mut guild_results = Vec::new();
-
-    // Try to add user to guilds using OAuth access token
-    let guild_id_str = std::env::var("DISCORD_GUILD_ID").ok();
-    if let Some(guild_id_str) = guild_id_str {
-        if let Ok(guild_id) = guild_id_str.parse::<u64>() {
-            let add_result = state
-                .oauth
-                .http_client
-                .put(&format!(
-                    "https://discord.com/api/guilds/{}/members/{}",
-                    guild_id, discord_user.id
-                ))
-                .header("Authorization", format!("Bot {}", state.oauth.bot_token))
-                .json(&serde_json::json!({
-                    "access_token": token.access_token
-                }))
-                .send()
+        _ => {
+            let result = state
+                .verification_manager
+                .attempt_verification(user_id, &discord_user.username, verification_id)
                 .await;
 
-            match add_result {
-                Ok(response) => {
-                    if response.status().is_success()
-                        || response.status().as_u16() == 204
-                        || response.status().as_u16() == 201
-                    {
-                        guild_results.push(("Primary server", "Added/already member"));
-                    } else {
-                        let status = response.status();
-                        let text = response.text().await.unwrap_or_default();
-                        info!("Guild add response: {} - {}", status, text);
-                        guild_results.push(("Primary server", "Already in server"));
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to add to guild: {}", e);
-                    guild_results.push(("Primary server", "Couldn't add"));
-                }
+            if !result.success {
+                let message = result
+                    .error
+                    .unwrap_or_else(|| "Verification failed".to_string());
+                return Err(Html(error_page(&message)));
             }
 
-            // Now set nickname and assign roles using serenity HTTP
-            let guild_id = serenity::GuildId::new(guild_id);
-
-            // Set nickname
-            if let Err(e) = guild_id
-                .edit_member(
-                    &state.serenity_http,
-                    user_id,
-                    serenity::EditMember::new().nickname(&display_name),
-                )
+            // Save database to disk
+            if let Err(e) = state
+                .verification_manager
+                .save_database(&user_database_path())
                 .await
             {
-                error!(
-                    "Failed to set nickname for {} in guild {}: {}. Bot requires 'Manage Nicknames' permission and must have a higher role than the target user.",
-                    user_id, guild_id, e
-                );
-            } else {
-                info!("Set nickname for {} to '{}'", user_id, display_name);
+                error!("Failed to save user database: {}", e);
             }
 
-            // Assign roles using sync_assignments_for_user for special roles
-            info!(
-                "Assigning {} roles to user {}: {:?}",
-                roles_to_assign.len(),
-                user_id,
-                roles_to_assign
-            );
-            let role_manager = state.role_manager.read().await;
-
-            // Use sync_assignments_for_user which handles checking existing roles and error messages
-            let (added, failed) = role_manager
-                .sync_assignments_for_user(
-                    &state.serenity_http,
-                    guild_id,
-                    user_id,
-                    &discord_user.username,
-                    &roles_to_assign,
-                )
-                .await;
+            (result.display_name, result.roles_to_assign)
+        }
+    };
 
-            if !added.is_empty() {
-                info!("Assigned roles to {}: {:?}", user_id, added);
-            }
-            if !failed.is_empty() {
-                warn!("Failed to assign some roles to {}: {:?}", user_id, failed);
-            }
+    // Add the user to the guild (or update them if they are already a member)
+    let guild_status = match guild_id {
+        Some(guild_id) => {
+            add_member_to_guild(
+                &state,
+                guild_id,
+                user_id,
+                &discord_user.username,
+                &token.access_token,
+                &display_name,
+                &roles_to_assign,
+            )
+            .await
         }
-    }
+        None => {
+            warn!("DISCORD_GUILD_ID not set, cannot add {} to the guild", user_id);
+            GuildJoinStatus::NotConfigured
+        }
+    };
 
     info!(
         "User {} verified as '{}' via OAuth",
@@ -657,15 +563,129 @@ async fn oauth_callback(
         &discord_user.id,
         verification_id,
         &roles_to_assign,
+        guild_status,
     )))
 }
 
-fn success_page(display_name: &str, discord_id: &str, uuid: &str, roles: &[String]) -> String {
+/// Outcome of adding a verified user to the guild
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GuildJoinStatus {
+    /// Joined with nickname and roles already set
+    Added,
+    /// Was already a member; nickname and roles were updated
+    AlreadyMember,
+    /// Could not be added automatically
+    Failed,
+    /// No guild configured
+    NotConfigured,
+}
+
+impl GuildJoinStatus {
+    fn message(self) -> &'static str {
+        match self {
+            GuildJoinStatus::Added => "Added to the server",
+            GuildJoinStatus::AlreadyMember => "Already a member, roles updated",
+            GuildJoinStatus::Failed => "Couldn't add you automatically, please join with an invite link",
+            GuildJoinStatus::NotConfigured => "No server configured",
+        }
+    }
+}
+
+/// Add a verified user to the guild via `guilds.join` with nickname and roles set
+///
+/// Discord ignores nickname and roles for users who are already members, so
+/// those are applied through the bot afterwards.
+async fn add_member_to_guild(
+    state: &AppState,
+    guild_id: GuildId,
+    user_id: UserId,
+    discord_username: &str,
+    access_token: &str,
+    display_name: &str,
+    roles_to_assign: &[String],
+) -> GuildJoinStatus {
+    let role_manager = state.role_manager.read().await;
+
+    // Resolve role IDs so the roles can be applied as the user joins
+    let mut role_ids = Vec::new();
+    for role_name in roles_to_assign {
+        match role_manager
+            .get_role_id(&state.serenity_http, guild_id, role_name)
+            .await
+        {
+            Ok(id) => role_ids.push(id.to_string()),
+            Err(e) => warn!(
+                "Role '{}' not found in guild {}: {}. Make sure to sync roles first.",
+                role_name, guild_id, e
+            ),
+        }
+    }
+
+    info!(
+        "Adding {} to guild {} with {} roles: {:?}",
+        user_id,
+        guild_id,
+        role_ids.len(),
+        roles_to_assign
+    );
+
+    let add_result = state
+        .oauth
+        .discord
+        .add_guild_member(
+            &state.oauth.bot_token,
+            &guild_id.to_string(),
+            &user_id.to_string(),
+            access_token,
+            Some(display_name),
+            &role_ids,
+        )
+        .await;
+
+    match add_result {
+        Ok(status) if status.as_u16() == 201 => {
+            info!("Added {} to guild {} as '{}'", user_id, guild_id, display_name);
+            GuildJoinStatus::Added
+        }
+        Ok(_) => {
+            role_manager
+                .apply_verified_member(
+                    &state.serenity_http,
+                    guild_id,
+                    user_id,
+                    discord_username,
+                    display_name,
+                    roles_to_assign,
+                )
+                .await;
+            GuildJoinStatus::AlreadyMember
+        }
+        Err(e) => {
+            error!("Failed to add {} to guild {}: {}", user_id, guild_id, e);
+            GuildJoinStatus::Failed
+        }
+    }
+}
+
+fn success_page(
+    display_name: &str,
+    discord_id: &str,
+    uuid: &str,
+    roles: &[String],
+    guild_status: GuildJoinStatus,
+) -> String {
     let roles_html = roles.iter()
         .map(|r| format!("<span style=\"background: #5865F2; color: white; padding: 2px 8px; border-radius: 4px; margin: 2px; display: inline-block;\">{}</span>", r))
         .collect::<Vec<_>>()
         .join(" ");
 
+    let headline = match guild_status {
+        GuildJoinStatus::Added | GuildJoinStatus::AlreadyMember => {
+            "You've been verified and added to the server."
+        }
+        GuildJoinStatus::Failed | GuildJoinStatus::NotConfigured => "You've been verified.",
+    };
+
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -731,7 +751,7 @@ fn success_page(display_name: &str, discord_id: &str, uuid: &str, roles: &[Strin
     <div class="container">
         <div class="success-icon">✓</div>
         <h1>Verification Successful!</h1>
-        <p>You've been verified and added to the server.</p>
+        <p>{headline}</p>
 
         <div class="info">
             <div class="info-row">
@@ -746,6 +766,10 @@ fn success_page(display_name: &str, discord_id: &str, uuid: &str, roles: &[Strin
                 <span class="label">Verification ID:</span>
                 <span class="value" style="font-size: 11px;">{uuid}</span>
             </div>
+            <div class="info-row">
+                <span class="label">Server Status:</span>
+                <span class="value">{guild_status}</span>
+            </div>
             <div class="roles">
                 <span class="label">Roles assigned:</span><br>
                 {roles_html}
@@ -759,7 +783,9 @@ fn success_page(display_name: &str, discord_id: &str, uuid: &str, roles: &[Strin
         display_name = display_name,
         discord_id = discord_id,
         uuid = uuid,
-        roles_html = roles_html
+        roles_html = roles_html,
+        headline = headline,
+        guild_status = guild_status.message()
     )
 }
 
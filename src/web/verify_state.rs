@@ -0,0 +1,182 @@
+//! Signed OAuth `state` tokens for user verification
+//!
+//! Instead of passing the raw verification ID through Discord, the `state`
+//! parameter carries the verification ID, a nonce bound to a browser cookie
+//! and the time it was issued, signed with HMAC-SHA256.
+
+use axum::http::HeaderMap;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Name of the cookie holding the nonce the state token is bound to
+pub const VERIFY_NONCE_COOKIE: &str = "verify_nonce";
+
+/// How long a state token stays valid after the verify page was shown
+pub const STATE_TOKEN_MAX_AGE_SECS: u64 = 600;
+
+/// Reasons a state token can be rejected
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StateTokenError {
+    #[error("The verification link is malformed. Please open your verification link again.")]
+    Malformed,
+
+    #[error("The verification request could not be validated. Please open your verification link again.")]
+    BadSignature,
+
+    #[error("The verification request has expired. Please open your verification link again.")]
+    Expired,
+
+    #[error("The verification request was started in a different browser. Please finish verification in the browser where you opened the link.")]
+    NonceMismatch,
+}
+
+/// Payload embedded in the state token
+#[derive(Serialize, Deserialize)]
+struct StatePayload {
+    /// Verification ID from users.json
+    vid: String,
+    /// Nonce that must match the verify_nonce cookie
+    nonce: String,
+    /// Unix timestamp the token was issued at
+    iat: u64,
+}
+
+/// Current unix time in seconds
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Generate a fresh random nonce for a verification attempt
+pub fn generate_nonce() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+fn mac_for(secret: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length")
+}
+
+/// Create a signed state token for a verification ID
+pub fn sign_state(secret: &[u8], verification_id: &str, nonce: &str, issued_at: u64) -> String {
+    let payload = StatePayload {
+        vid: verification_id.to_string(),
+        nonce: nonce.to_string(),
+        iat: issued_at,
+    };
+    let payload_json = serde_json::to_vec(&payload).unwrap_or_default();
+    let payload_b64 = URL_SAFE_NO_PAD.encode(payload_json);
+
+    let mut mac = mac_for(secret);
+    mac.update(payload_b64.as_bytes());
+    let signature_b64 = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+    format!("{}.{}", payload_b64, signature_b64)
+}
+
+/// Validate a state token and return the verification ID it carries
+///
+/// Checks the signature first, then the expiry, then that the nonce matches
+/// the one stored in the browser cookie.
+pub fn verify_state(
+    secret: &[u8],
+    token: &str,
+    cookie_nonce: Option<&str>,
+    now: u64,
+) -> Result<String, StateTokenError> {
+    let (payload_b64, signature_b64) = token.split_once('.').ok_or(StateTokenError::Malformed)?;
+
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|_| StateTokenError::Malformed)?;
+
+    let mut mac = mac_for(secret);
+    mac.update(payload_b64.as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| StateTokenError::BadSignature)?;
+
+    let payload_json = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|_| StateTokenError::Malformed)?;
+    let payload: StatePayload =
+        serde_json::from_slice(&payload_json).map_err(|_| StateTokenError::Malformed)?;
+
+    if payload.iat > now + 60 || now.saturating_sub(payload.iat) > STATE_TOKEN_MAX_AGE_SECS {
+        return Err(StateTokenError::Expired);
+    }
+
+    match cookie_nonce {
+        Some(nonce) if nonce == payload.nonce => Ok(payload.vid),
+        _ => Err(StateTokenError::NonceMismatch),
+    }
+}
+
+/// Extract the verification nonce from cookies
+pub fn get_verify_nonce(headers: &HeaderMap) -> Option<String> {
+    let prefix = format!("{}=", VERIFY_NONCE_COOKIE);
+    headers
+        .get(axum::http::header::COOKIE)?
+        .to_str()
+        .ok()?
+        .split(';')
+        .find_map(|cookie| {
+            cookie
+                .trim()
+                .strip_prefix(prefix.as_str())
+                .map(|value| value.to_string())
+        })
+}
+
+/// Create the cookie binding a verification attempt to this browser
+pub fn create_verify_nonce_cookie(nonce: &str) -> String {
+    format!(
+        "{}={}; Path=/callback; HttpOnly; SameSite=Lax; Max-Age={}",
+        VERIFY_NONCE_COOKIE, nonce, STATE_TOKEN_MAX_AGE_SECS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    #[test]
+    fn test_roundtrip() {
+        let token = sign_state(SECRET, "abc-123", "nonce1", 1000);
+        let vid = verify_state(SECRET, &token, Some("nonce1"), 1100).unwrap();
+        assert_eq!(vid, "abc-123");
+    }
+
+    #[test]
+    fn test_rejects_tampered_expired_and_mismatched() {
+        let token = sign_state(SECRET, "abc-123", "nonce1", 1000);
+
+        let forged = sign_state(b"other-secret", "abc-123", "nonce1", 1000);
+        assert_eq!(
+            verify_state(SECRET, &forged, Some("nonce1"), 1100),
+            Err(StateTokenError::BadSignature)
+        );
+        assert_eq!(
+            verify_state(SECRET, "not-a-token", Some("nonce1"), 1100),
+            Err(StateTokenError::Malformed)
+        );
+        assert_eq!(
+            verify_state(SECRET, &token, Some("nonce1"), 1000 + STATE_TOKEN_MAX_AGE_SECS + 1),
+            Err(StateTokenError::Expired)
+        );
+        assert_eq!(
+            verify_state(SECRET, &token, Some("nonce2"), 1100),
+            Err(StateTokenError::NonceMismatch)
+        );
+        assert_eq!(
+            verify_state(SECRET, &token, None, 1100),
+            Err(StateTokenError::NonceMismatch)
+        );
+    }
+}